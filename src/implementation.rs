@@ -1,9 +1,16 @@
+pub mod alignment;
 pub mod alphabets;
 pub mod array_kmer;
+pub mod bisulfite;
 pub mod bit_array_kmer;
 pub mod bit_vec_sequence;
 pub mod bit_vec_sequence_store;
+pub mod count_min_sketch;
+pub mod direct_k_mer_counter;
+pub mod dynamic_bit_array_kmer;
 pub mod handle_sequence_store;
+pub mod hash_map_k_mer_counter;
+pub mod sketch;
 pub mod vec_sequence;
 pub mod vec_sequence_store;
 
@@ -0,0 +1,607 @@
+//! Pairwise alignment of genome sequences.
+//!
+//! A [PairwiseAligner] aligns a query sequence to a reference sequence of the same alphabet.
+//! It supports global ([Needleman–Wunsch](https://doi.org/10.1016/0022-2836(70)90057-4)), local ([Smith–Waterman](https://doi.org/10.1016/0022-2836(81)90087-5)) and semi-global alignments.
+//! Gaps are scored with affine costs, using the algorithm of [Gotoh](https://doi.org/10.1016/0022-2836(82)90398-9).
+//!
+//! ```rust
+//! use compact_genome::implementation::alignment::{AlignmentMode, PairwiseAligner};
+//! use compact_genome::implementation::alignment::substitution_matrix::SubstitutionMatrix;
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+//! use compact_genome::interface::sequence::OwnedGenomeSequence;
+//!
+//! let reference = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTTTACGT").unwrap();
+//! let query = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTACGT").unwrap();
+//! let aligner = PairwiseAligner::new(
+//!     SubstitutionMatrix::match_mismatch(1, -1),
+//!     2,
+//!     1,
+//!     AlignmentMode::Global,
+//! );
+//! let alignment = aligner.align(&reference, &query).unwrap();
+//! assert_eq!(alignment.score(), 4);
+//! assert_eq!(alignment.to_string(), "3=2D5=");
+//! ```
+
+use crate::implementation::alignment::substitution_matrix::SubstitutionMatrix;
+use crate::interface::alphabet::Alphabet;
+use crate::interface::sequence::GenomeSequence;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+pub mod substitution_matrix;
+pub mod wavefront;
+
+/// The kind of pairwise alignment.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentMode {
+    /// Both sequences are aligned entirely.
+    Global,
+    /// A substring of the query is aligned to a substring of the reference.
+    Local,
+    /// The query is aligned entirely to a substring of the reference.
+    /// Gaps before and after the query are free.
+    SemiGlobal,
+}
+
+/// An operation of an alignment.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentOperation {
+    /// A query character is aligned to an equal reference character.
+    Match,
+    /// A query character is aligned to a different reference character.
+    Substitution,
+    /// A query character is not aligned to any reference character.
+    Insertion,
+    /// A reference character is not aligned to any query character.
+    Deletion,
+}
+
+impl AlignmentOperation {
+    /// The symbol of this operation in an extended CIGAR string.
+    pub fn cigar_symbol(&self) -> char {
+        match self {
+            AlignmentOperation::Match => '=',
+            AlignmentOperation::Substitution => 'X',
+            AlignmentOperation::Insertion => 'I',
+            AlignmentOperation::Deletion => 'D',
+        }
+    }
+}
+
+/// A pairwise alignment with its score.
+///
+/// The alignment is displayed as extended CIGAR string.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alignment {
+    score: i32,
+    reference_range: Range<usize>,
+    query_range: Range<usize>,
+    cigar: Vec<(AlignmentOperation, usize)>,
+}
+
+impl Alignment {
+    /// Creates an alignment from its run-length encoded operations.
+    /// Consecutive runs of the same operation are merged and empty runs are removed.
+    pub fn new(
+        score: i32,
+        reference_range: Range<usize>,
+        query_range: Range<usize>,
+        operations: impl IntoIterator<Item = (AlignmentOperation, usize)>,
+    ) -> Self {
+        let mut cigar: Vec<(AlignmentOperation, usize)> = Vec::new();
+        for (operation, count) in operations {
+            if count == 0 {
+                continue;
+            }
+            match cigar.last_mut() {
+                Some((last_operation, last_count)) if *last_operation == operation => {
+                    *last_count += count
+                }
+                _ => cigar.push((operation, count)),
+            }
+        }
+
+        Self {
+            score,
+            reference_range,
+            query_range,
+            cigar,
+        }
+    }
+
+    /// The score of the alignment.
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    /// The range of the reference that is aligned.
+    pub fn reference_range(&self) -> Range<usize> {
+        self.reference_range.clone()
+    }
+
+    /// The range of the query that is aligned.
+    pub fn query_range(&self) -> Range<usize> {
+        self.query_range.clone()
+    }
+
+    /// The run-length encoded operations of the alignment.
+    pub fn cigar(&self) -> &[(AlignmentOperation, usize)] {
+        &self.cigar
+    }
+
+    /// Returns an iterator over the operations of the alignment, one per aligned column.
+    pub fn operations(&self) -> impl Iterator<Item = AlignmentOperation> + '_ {
+        self.cigar
+            .iter()
+            .flat_map(|&(operation, count)| std::iter::repeat(operation).take(count))
+    }
+
+    /// The edit distance of the aligned sequence ranges according to this alignment.
+    pub fn edit_count(&self) -> usize {
+        self.cigar
+            .iter()
+            .filter(|(operation, _)| *operation != AlignmentOperation::Match)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (operation, count) in &self.cigar {
+            write!(f, "{count}{}", operation.cigar_symbol())?;
+        }
+        Ok(())
+    }
+}
+
+/// An aligner for pairs of genome sequences.
+#[derive(Debug, Clone)]
+pub struct PairwiseAligner<AlphabetType: Alphabet> {
+    substitution_matrix: SubstitutionMatrix<AlphabetType>,
+    gap_open: i32,
+    gap_extend: i32,
+    mode: AlignmentMode,
+    band: Option<usize>,
+}
+
+/// The score of unreachable cells, chosen such that adding penalties does not overflow.
+const UNREACHABLE: i64 = i64::MIN / 4;
+
+/// Traceback flags of a cell.
+const H_FROM_DIAGONAL: u8 = 0;
+const H_FROM_DELETION: u8 = 1;
+const H_FROM_INSERTION: u8 = 2;
+const H_START: u8 = 3;
+const H_MASK: u8 = 3;
+const DELETION_EXTENDED: u8 = 4;
+const INSERTION_EXTENDED: u8 = 8;
+
+impl<AlphabetType: Alphabet> PairwiseAligner<AlphabetType> {
+    /// Creates a new aligner.
+    ///
+    /// A gap of length `l` is scored as `-(gap_open + l * gap_extend)`.
+    /// For linear gap costs, set `gap_open` to zero.
+    pub fn new(
+        substitution_matrix: SubstitutionMatrix<AlphabetType>,
+        gap_open: i32,
+        gap_extend: i32,
+        mode: AlignmentMode,
+    ) -> Self {
+        Self {
+            substitution_matrix,
+            gap_open,
+            gap_extend,
+            mode,
+            band: None,
+        }
+    }
+
+    /// Restricts the alignment to the band of cells whose diagonal differs by at most `band` from the main diagonal.
+    ///
+    /// In other words, a query character at offset `i` can only be aligned to reference characters at offsets `i - band..=i + band`.
+    pub fn with_band(mut self, band: usize) -> Self {
+        self.band = Some(band);
+        self
+    }
+
+    /// The substitution matrix of this aligner.
+    pub fn substitution_matrix(&self) -> &SubstitutionMatrix<AlphabetType> {
+        &self.substitution_matrix
+    }
+
+    /// The mode of this aligner.
+    pub fn mode(&self) -> AlignmentMode {
+        self.mode
+    }
+
+    fn in_band(&self, query_offset: usize, reference_offset: usize) -> bool {
+        self.band
+            .map_or(true, |band| query_offset.abs_diff(reference_offset) <= band)
+    }
+
+    /// Aligns the query to the reference.
+    ///
+    /// Returns `None` if no alignment fits into the band.
+    /// This runs in time and space proportional to the product of the sequence lengths, or to the product of the query length and the band width if a band is set.
+    pub fn align<
+        ReferenceSequence: GenomeSequence<AlphabetType, ReferenceSubsequence> + ?Sized,
+        ReferenceSubsequence: GenomeSequence<AlphabetType, ReferenceSubsequence> + ?Sized,
+        QuerySequence: GenomeSequence<AlphabetType, QuerySubsequence> + ?Sized,
+        QuerySubsequence: GenomeSequence<AlphabetType, QuerySubsequence> + ?Sized,
+    >(
+        &self,
+        reference: &ReferenceSequence,
+        query: &QuerySequence,
+    ) -> Option<Alignment> {
+        let reference: Vec<_> = reference.iter().cloned().collect();
+        let query: Vec<_> = query.iter().cloned().collect();
+        let columns = reference.len() + 1;
+        let rows = query.len() + 1;
+        let gap_open = i64::from(self.gap_open);
+        let gap_extend = i64::from(self.gap_extend);
+        let local = self.mode == AlignmentMode::Local;
+        let free_reference_ends = self.mode != AlignmentMode::Global;
+
+        // Only the cells within the band are stored, each row starting at its first column in the band.
+        let row_start = |row: usize| {
+            self.band
+                .map(|band| row.saturating_sub(band).min(columns))
+                .unwrap_or(0)
+        };
+        let row_end = |row: usize| {
+            self.band
+                .map(|band| row.saturating_add(band).saturating_add(1).min(columns))
+                .unwrap_or(columns)
+        };
+        let mut row_offsets = Vec::with_capacity(rows + 1);
+        row_offsets.push(0);
+        for row in 0..rows {
+            let len = row_end(row).saturating_sub(row_start(row));
+            row_offsets.push(row_offsets[row] + len);
+        }
+        let cell = |row: usize, column: usize| {
+            debug_assert!(self.in_band(row, column));
+            row_offsets[row] + column - row_start(row)
+        };
+        let cells = row_offsets[rows];
+
+        // Best score ending in any state, ending with a deletion and ending with an insertion.
+        let mut best = vec![UNREACHABLE; cells];
+        let mut deletion = vec![UNREACHABLE; cells];
+        let mut insertion = vec![UNREACHABLE; cells];
+        let mut traceback = vec![0u8; cells];
+
+        for row in 0..rows {
+            for column in row_start(row)..row_end(row) {
+                let index = cell(row, column);
+
+                if column > 0 && self.in_band(row, column - 1) {
+                    let left = cell(row, column - 1);
+                    let open = best[left] - gap_open - gap_extend;
+                    let extend = deletion[left] - gap_extend;
+                    if extend > open {
+                        deletion[index] = extend;
+                        traceback[index] |= DELETION_EXTENDED;
+                    } else {
+                        deletion[index] = open;
+                    }
+                }
+
+                if row > 0 && self.in_band(row - 1, column) {
+                    let up = cell(row - 1, column);
+                    let open = best[up] - gap_open - gap_extend;
+                    let extend = insertion[up] - gap_extend;
+                    if extend > open {
+                        insertion[index] = extend;
+                        traceback[index] |= INSERTION_EXTENDED;
+                    } else {
+                        insertion[index] = open;
+                    }
+                }
+
+                // Alignments start in the top left corner, or anywhere in the first row or column if the respective ends are free.
+                let is_start =
+                    (row == 0 && (column == 0 || free_reference_ends)) || (column == 0 && local);
+                let (mut score, mut source) = if is_start {
+                    (0, H_START)
+                } else {
+                    (UNREACHABLE, H_START)
+                };
+
+                if row > 0 && column > 0 && self.in_band(row - 1, column - 1) {
+                    let diagonal = best[cell(row - 1, column - 1)]
+                        + i64::from(
+                            self.substitution_matrix
+                                .score(&query[row - 1], &reference[column - 1]),
+                        );
+                    if diagonal > score {
+                        (score, source) = (diagonal, H_FROM_DIAGONAL);
+                    }
+                }
+                if deletion[index] > score {
+                    (score, source) = (deletion[index], H_FROM_DELETION);
+                }
+                if insertion[index] > score {
+                    (score, source) = (insertion[index], H_FROM_INSERTION);
+                }
+                if local && score <= 0 {
+                    (score, source) = (0, H_START);
+                }
+
+                best[index] = score;
+                traceback[index] |= source;
+            }
+        }
+
+        // Find the end of the alignment.
+        let (end_row, end_column) = match self.mode {
+            AlignmentMode::Global => (rows - 1, columns - 1),
+            AlignmentMode::SemiGlobal => {
+                let row = rows - 1;
+                let column = (row_start(row)..row_end(row))
+                    .rev()
+                    .max_by_key(|&column| best[cell(row, column)])?;
+                (row, column)
+            }
+            AlignmentMode::Local => (0..rows)
+                .flat_map(|row| (row_start(row)..row_end(row)).map(move |column| (row, column)))
+                .rev()
+                .max_by_key(|&(row, column)| best[cell(row, column)])?,
+        };
+        if !self.in_band(end_row, end_column) || best[cell(end_row, end_column)] <= UNREACHABLE / 2
+        {
+            return None;
+        }
+        let score = best[cell(end_row, end_column)];
+
+        // Trace back.
+        #[derive(Clone, Copy, Eq, PartialEq)]
+        enum State {
+            Best,
+            Deletion,
+            Insertion,
+        }
+        let mut operations = Vec::new();
+        let (mut row, mut column) = (end_row, end_column);
+        let mut state = State::Best;
+        loop {
+            let flags = traceback[cell(row, column)];
+            match state {
+                State::Best => match flags & H_MASK {
+                    H_FROM_DIAGONAL => {
+                        operations.push(if query[row - 1] == reference[column - 1] {
+                            AlignmentOperation::Match
+                        } else {
+                            AlignmentOperation::Substitution
+                        });
+                        row -= 1;
+                        column -= 1;
+                    }
+                    H_FROM_DELETION => state = State::Deletion,
+                    H_FROM_INSERTION => state = State::Insertion,
+                    _ => break,
+                },
+                State::Deletion => {
+                    operations.push(AlignmentOperation::Deletion);
+                    if flags & DELETION_EXTENDED == 0 {
+                        state = State::Best;
+                    }
+                    column -= 1;
+                }
+                State::Insertion => {
+                    operations.push(AlignmentOperation::Insertion);
+                    if flags & INSERTION_EXTENDED == 0 {
+                        state = State::Best;
+                    }
+                    row -= 1;
+                }
+            }
+        }
+        operations.reverse();
+
+        Some(Alignment::new(
+            i32::try_from(score).expect("alignment score overflows i32"),
+            column..end_column,
+            row..end_row,
+            operations.into_iter().map(|operation| (operation, 1)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alignment::substitution_matrix::SubstitutionMatrix;
+    use crate::implementation::alignment::{
+        Alignment, AlignmentMode, AlignmentOperation, PairwiseAligner,
+    };
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::iupac_amino_acid_alphabet::IupacAminoAcidAlphabet;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::Alphabet;
+    use crate::interface::sequence::OwnedGenomeSequence;
+
+    /// Recomputes the score of the alignment from its operations.
+    fn rescore<AlphabetType: Alphabet>(
+        aligner: &PairwiseAligner<AlphabetType>,
+        reference: &[u8],
+        query: &[u8],
+        alignment: &Alignment,
+    ) -> i32 {
+        let character = |ascii| AlphabetType::ascii_to_character(ascii).unwrap();
+        let mut reference_offset = alignment.reference_range().start;
+        let mut query_offset = alignment.query_range().start;
+        let mut score = 0;
+        for (operation, count) in alignment.cigar() {
+            let count = *count;
+            match operation {
+                AlignmentOperation::Match | AlignmentOperation::Substitution => {
+                    for _ in 0..count {
+                        let (r, q) = (reference[reference_offset], query[query_offset]);
+                        assert_eq!(*operation == AlignmentOperation::Match, r == q);
+                        score += aligner
+                            .substitution_matrix()
+                            .score(&character(q), &character(r));
+                        reference_offset += 1;
+                        query_offset += 1;
+                    }
+                }
+                AlignmentOperation::Insertion => {
+                    score -= aligner.gap_open + aligner.gap_extend * count as i32;
+                    query_offset += count;
+                }
+                AlignmentOperation::Deletion => {
+                    score -= aligner.gap_open + aligner.gap_extend * count as i32;
+                    reference_offset += count;
+                }
+            }
+        }
+        assert_eq!(reference_offset, alignment.reference_range().end);
+        assert_eq!(query_offset, alignment.query_range().end);
+        score
+    }
+
+    fn align(
+        aligner: &PairwiseAligner<DnaAlphabet>,
+        reference: &[u8],
+        query: &[u8],
+    ) -> Option<Alignment> {
+        let alignment = aligner.align(
+            &VectorGenome::<DnaAlphabet>::from_slice_u8(reference).unwrap(),
+            &VectorGenome::<DnaAlphabet>::from_slice_u8(query).unwrap(),
+        )?;
+        assert_eq!(
+            rescore(aligner, reference, query, &alignment),
+            alignment.score()
+        );
+        Some(alignment)
+    }
+
+    #[test]
+    fn test_global() {
+        let aligner = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(2, -3),
+            0,
+            2,
+            AlignmentMode::Global,
+        );
+        let alignment = align(&aligner, b"ACGTACGT", b"ACGAACGT").unwrap();
+        assert_eq!(alignment.to_string(), "3=1X4=");
+        assert_eq!(alignment.score(), 11);
+        assert_eq!(alignment.edit_count(), 1);
+
+        let alignment = align(&aligner, b"ACGTACGT", b"ACGACGT").unwrap();
+        assert_eq!(alignment.to_string(), "3=1D4=");
+        assert_eq!(alignment.reference_range(), 0..8);
+        assert_eq!(alignment.query_range(), 0..7);
+
+        let alignment = align(&aligner, b"", b"AC").unwrap();
+        assert_eq!(alignment.to_string(), "2I");
+        assert_eq!(alignment.score(), -4);
+    }
+
+    #[test]
+    fn test_affine() {
+        // With affine costs, one long gap is better than two short ones.
+        let reference = b"AAAACCCCGGGGTTTT";
+        let query = b"AAAAGGGGTTTT";
+        let linear = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(1, -1),
+            0,
+            1,
+            AlignmentMode::Global,
+        );
+        let affine = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(1, -1),
+            5,
+            1,
+            AlignmentMode::Global,
+        );
+        assert_eq!(align(&linear, reference, query).unwrap().score(), 8);
+        let alignment = align(&affine, reference, query).unwrap();
+        assert_eq!(alignment.score(), 3);
+        assert_eq!(alignment.to_string(), "4=4D8=");
+
+        let alignment = align(&affine, b"ACGTTTTTTACGT", b"ACGTTACGT").unwrap();
+        assert_eq!(
+            alignment
+                .cigar()
+                .iter()
+                .filter(|(operation, _)| *operation == AlignmentOperation::Deletion)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_local() {
+        let aligner = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(2, -3),
+            3,
+            1,
+            AlignmentMode::Local,
+        );
+        let alignment = align(&aligner, b"TTTTACGTACGTTTTT", b"GGACGTCCGTGG").unwrap();
+        assert_eq!(alignment.to_string(), "4=1X3=");
+        assert_eq!(alignment.reference_range(), 4..12);
+        assert_eq!(alignment.query_range(), 2..10);
+        assert_eq!(alignment.score(), 11);
+
+        let alignment = align(&aligner, b"AAAA", b"CCCC").unwrap();
+        assert_eq!(alignment.score(), 0);
+        assert!(alignment.cigar().is_empty());
+    }
+
+    #[test]
+    fn test_semi_global() {
+        let aligner = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(1, -2),
+            2,
+            1,
+            AlignmentMode::SemiGlobal,
+        );
+        let alignment = align(&aligner, b"TTTTACGTACGTTTTT", b"CGTACC").unwrap();
+        assert_eq!(alignment.to_string(), "5=1X");
+        assert_eq!(alignment.reference_range(), 5..11);
+        assert_eq!(alignment.query_range(), 0..6);
+        assert_eq!(alignment.score(), 3);
+    }
+
+    #[test]
+    fn test_band() {
+        let reference = b"ACGTACGTACGTAAAACCCCGGGG";
+        let query = b"ACGTACGTAAAACCCCGGGG";
+        let aligner = PairwiseAligner::new(
+            SubstitutionMatrix::match_mismatch(1, -1),
+            1,
+            1,
+            AlignmentMode::Global,
+        );
+        let unbanded = align(&aligner, reference, query).unwrap();
+        let banded = align(&aligner.clone().with_band(4), reference, query).unwrap();
+        assert_eq!(unbanded, banded);
+        assert!(align(&aligner.clone().with_band(3), reference, query).is_none());
+
+        let narrow = align(&aligner.clone().with_band(1), b"ACGTTACGT", b"ACGTACGTA").unwrap();
+        assert!(narrow.score() <= align(&aligner, b"ACGTTACGT", b"ACGTACGTA").unwrap().score());
+    }
+
+    #[test]
+    fn test_protein() {
+        let aligner =
+            PairwiseAligner::new(SubstitutionMatrix::blosum62(), 10, 1, AlignmentMode::Global);
+        let alignment = aligner
+            .align(
+                &VectorGenome::<IupacAminoAcidAlphabet>::from_slice_u8(b"HEAGAWGHEE").unwrap(),
+                &VectorGenome::<IupacAminoAcidAlphabet>::from_slice_u8(b"HEAGAWGHEE").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(alignment.to_string(), "10=");
+        assert_eq!(alignment.score(), 8 + 5 + 4 + 6 + 4 + 11 + 6 + 8 + 5 + 5);
+    }
+}
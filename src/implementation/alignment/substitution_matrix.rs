@@ -0,0 +1,169 @@
+//! Substitution matrices for scoring pairs of aligned characters.
+
+use crate::implementation::alphabets::iupac_amino_acid_alphabet::IupacAminoAcidAlphabet;
+use crate::interface::alphabet::{Alphabet, AlphabetCharacter};
+use std::marker::PhantomData;
+
+/// A matrix of scores for aligning each pair of characters of an alphabet.
+///
+/// The matrix is indexed by [`AlphabetCharacter::index`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubstitutionMatrix<AlphabetType: Alphabet> {
+    scores: Vec<i32>,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+impl<AlphabetType: Alphabet> SubstitutionMatrix<AlphabetType> {
+    /// Creates a substitution matrix that scores equal characters with `match_score` and different characters with `mismatch_score`.
+    pub fn match_mismatch(match_score: i32, mismatch_score: i32) -> Self {
+        Self::from_fn(|a, b| if a == b { match_score } else { mismatch_score })
+    }
+
+    /// Creates a substitution matrix by evaluating the given function for each pair of characters.
+    pub fn from_fn(
+        mut score: impl FnMut(&AlphabetType::CharacterType, &AlphabetType::CharacterType) -> i32,
+    ) -> Self {
+        let mut scores = Vec::with_capacity(usize::from(AlphabetType::SIZE).pow(2));
+        for a in AlphabetType::iter() {
+            for b in AlphabetType::iter() {
+                scores.push(score(&a, &b));
+            }
+        }
+        Self {
+            scores,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Creates a substitution matrix from a table in the usual text layout, where `ascii_order` gives the ASCII character of each row and column.
+    ///
+    /// Rows and columns of characters that are not part of the alphabet are ignored.
+    /// Panics if a character of the alphabet has no row and column in the table.
+    pub fn from_ascii_table<const N: usize>(ascii_order: &[u8; N], table: &[[i32; N]; N]) -> Self {
+        let position = |character: &AlphabetType::CharacterType| {
+            let ascii = AlphabetType::character_to_ascii(character.clone());
+            ascii_order
+                .iter()
+                .position(|&other| other == ascii)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "character {} is missing in the substitution table",
+                        char::from(ascii)
+                    )
+                })
+        };
+        Self::from_fn(|a, b| table[position(a)][position(b)])
+    }
+
+    /// Returns the score for aligning the given characters.
+    pub fn score(&self, a: &AlphabetType::CharacterType, b: &AlphabetType::CharacterType) -> i32 {
+        self.scores
+            [usize::from(a.index()) * usize::from(AlphabetType::SIZE) + usize::from(b.index())]
+    }
+
+    /// Returns true if the matrix is symmetric.
+    pub fn is_symmetric(&self) -> bool {
+        AlphabetType::iter()
+            .all(|a| AlphabetType::iter().all(|b| self.score(&a, &b) == self.score(&b, &a)))
+    }
+}
+
+/// The order of rows and columns of the amino acid tables.
+const AMINO_ACID_ORDER: &[u8; 21] = b"ARNDCQEGHILKMFPSTWYVX";
+
+#[rustfmt::skip]
+const BLOSUM62: [[i32; 21]; 21] = [
+    //A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   X
+    [ 4, -1, -2, -2,  0, -1, -1,  0, -2, -1, -1, -1, -1, -2, -1,  1,  0, -3, -2,  0,  0], // A
+    [-1,  5,  0, -2, -3,  1,  0, -2,  0, -3, -2,  2, -1, -3, -2, -1, -1, -3, -2, -3, -1], // R
+    [-2,  0,  6,  1, -3,  0,  0,  0,  1, -3, -3,  0, -2, -3, -2,  1,  0, -4, -2, -3, -1], // N
+    [-2, -2,  1,  6, -3,  0,  2, -1, -1, -3, -4, -1, -3, -3, -1,  0, -1, -4, -3, -3, -1], // D
+    [ 0, -3, -3, -3,  9, -3, -4, -3, -3, -1, -1, -3, -1, -2, -3, -1, -1, -2, -2, -1, -2], // C
+    [-1,  1,  0,  0, -3,  5,  2, -2,  0, -3, -2,  1,  0, -3, -1,  0, -1, -2, -1, -2, -1], // Q
+    [-1,  0,  0,  2, -4,  2,  5, -2,  0, -3, -3,  1, -2, -3, -1,  0, -1, -3, -2, -2, -1], // E
+    [ 0, -2,  0, -1, -3, -2, -2,  6, -2, -4, -4, -2, -3, -3, -2,  0, -2, -2, -3, -3, -1], // G
+    [-2,  0,  1, -1, -3,  0,  0, -2,  8, -3, -3, -1, -2, -1, -2, -1, -2, -2,  2, -3, -1], // H
+    [-1, -3, -3, -3, -1, -3, -3, -4, -3,  4,  2, -3,  1,  0, -3, -2, -1, -3, -1,  3, -1], // I
+    [-1, -2, -3, -4, -1, -2, -3, -4, -3,  2,  4, -2,  2,  0, -3, -2, -1, -2, -1,  1, -1], // L
+    [-1,  2,  0, -1, -3,  1,  1, -2, -1, -3, -2,  5, -1, -3, -1,  0, -1, -3, -2, -2, -1], // K
+    [-1, -1, -2, -3, -1,  0, -2, -3, -2,  1,  2, -1,  5,  0, -2, -1, -1, -1, -1,  1, -1], // M
+    [-2, -3, -3, -3, -2, -3, -3, -3, -1,  0,  0, -3,  0,  6, -4, -2, -2,  1,  3, -1, -1], // F
+    [-1, -2, -2, -1, -3, -1, -1, -2, -2, -3, -3, -1, -2, -4,  7, -1, -1, -4, -3, -2, -2], // P
+    [ 1, -1,  1,  0, -1,  0,  0,  0, -1, -2, -2,  0, -1, -2, -1,  4,  1, -3, -2, -2,  0], // S
+    [ 0, -1,  0, -1, -1, -1, -1, -2, -2, -1, -1, -1, -1, -2, -1,  1,  5, -2, -2,  0,  0], // T
+    [-3, -3, -4, -4, -2, -2, -3, -2, -2, -3, -2, -3, -1,  1, -4, -3, -2, 11,  2, -3, -2], // W
+    [-2, -2, -2, -3, -2, -1, -2, -3,  2, -1, -1, -2, -1,  3, -3, -2, -2,  2,  7, -1, -1], // Y
+    [ 0, -3, -3, -3, -1, -2, -2, -3, -3,  3,  1, -2,  1, -1, -2, -2,  0, -3, -1,  4, -1], // V
+    [ 0, -1, -1, -1, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2,  0,  0, -2, -1, -1, -1], // X
+];
+
+#[rustfmt::skip]
+const PAM250: [[i32; 21]; 21] = [
+    //A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   X
+    [ 2, -2,  0,  0, -2,  0,  0,  1, -1, -1, -2, -1, -1, -3,  1,  1,  1, -6, -3,  0,  0], // A
+    [-2,  6,  0, -1, -4,  1, -1, -3,  2, -2, -3,  3,  0, -4,  0,  0, -1,  2, -4, -2, -1], // R
+    [ 0,  0,  2,  2, -4,  1,  1,  0,  2, -2, -3,  1, -2, -3,  0,  1,  0, -4, -2, -2,  0], // N
+    [ 0, -1,  2,  4, -5,  2,  3,  1,  1, -2, -4,  0, -3, -6, -1,  0,  0, -7, -4, -2, -1], // D
+    [-2, -4, -4, -5, 12, -5, -5, -3, -3, -2, -6, -5, -5, -4, -3,  0, -2, -8,  0, -2, -3], // C
+    [ 0,  1,  1,  2, -5,  4,  2, -1,  3, -2, -2,  1, -1, -5,  0, -1, -1, -5, -4, -2, -1], // Q
+    [ 0, -1,  1,  3, -5,  2,  4,  0,  1, -2, -3,  0, -2, -5, -1,  0,  0, -7, -4, -2, -1], // E
+    [ 1, -3,  0,  1, -3, -1,  0,  5, -2, -3, -4, -2, -3, -5,  0,  1,  0, -7, -5, -1, -1], // G
+    [-1,  2,  2,  1, -3,  3,  1, -2,  6, -2, -2,  0, -2, -2,  0, -1, -1, -3,  0, -2, -1], // H
+    [-1, -2, -2, -2, -2, -2, -2, -3, -2,  5,  2, -2,  2,  1, -2, -1,  0, -5, -1,  4, -1], // I
+    [-2, -3, -3, -4, -6, -2, -3, -4, -2,  2,  6, -3,  4,  2, -3, -3, -2, -2, -1,  2, -1], // L
+    [-1,  3,  1,  0, -5,  1,  0, -2,  0, -2, -3,  5,  0, -5, -1,  0,  0, -3, -4, -2, -1], // K
+    [-1,  0, -2, -3, -5, -1, -2, -3, -2,  2,  4,  0,  6,  0, -2, -2, -1, -4, -2,  2, -1], // M
+    [-3, -4, -3, -6, -4, -5, -5, -5, -2,  1,  2, -5,  0,  9, -5, -3, -3,  0,  7, -1, -2], // F
+    [ 1,  0,  0, -1, -3,  0, -1,  0,  0, -2, -3, -1, -2, -5,  6,  1,  0, -6, -5, -1, -1], // P
+    [ 1,  0,  1,  0,  0, -1,  0,  1, -1, -1, -3,  0, -2, -3,  1,  2,  1, -2, -3, -1,  0], // S
+    [ 1, -1,  0,  0, -2, -1,  0,  0, -1,  0, -2,  0, -1, -3,  0,  1,  3, -5, -3,  0,  0], // T
+    [-6,  2, -4, -7, -8, -5, -7, -7, -3, -5, -2, -3, -4,  0, -6, -2, -5, 17,  0, -6, -4], // W
+    [-3, -4, -2, -4,  0, -4, -4, -5,  0, -1, -1, -4, -2,  7, -5, -3, -3,  0, 10, -2, -2], // Y
+    [ 0, -2, -2, -2, -2, -2, -2, -1, -2,  4,  2, -2,  2, -1, -1, -1,  0, -6, -2,  4, -1], // V
+    [ 0, -1,  0, -1, -3, -1, -1, -1, -1, -1, -1, -1, -1, -2, -1,  0,  0, -4, -2, -1, -1], // X
+];
+
+impl SubstitutionMatrix<IupacAminoAcidAlphabet> {
+    /// The [BLOSUM62](https://doi.org/10.1073/pnas.89.22.10915) matrix, as distributed with NCBI BLAST.
+    pub fn blosum62() -> Self {
+        Self::from_ascii_table(AMINO_ACID_ORDER, &BLOSUM62)
+    }
+
+    /// The [PAM250](https://doi.org/10.1016/B978-0-12-398060-8.50010-4) matrix, as distributed with NCBI BLAST.
+    pub fn pam250() -> Self {
+        Self::from_ascii_table(AMINO_ACID_ORDER, &PAM250)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alignment::substitution_matrix::SubstitutionMatrix;
+    use crate::implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter};
+    use crate::implementation::alphabets::iupac_amino_acid_alphabet::{
+        IupacAminoAcidAlphabet, IupacAminoAcidCharacter,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_amino_acid_matrices() {
+        let blosum62 = SubstitutionMatrix::<IupacAminoAcidAlphabet>::blosum62();
+        let pam250 = SubstitutionMatrix::<IupacAminoAcidAlphabet>::pam250();
+        assert!(blosum62.is_symmetric());
+        assert!(pam250.is_symmetric());
+
+        let character = |ascii| IupacAminoAcidCharacter::try_from(ascii).unwrap();
+        assert_eq!(blosum62.score(&character(b'W'), &character(b'W')), 11);
+        assert_eq!(blosum62.score(&character(b'I'), &character(b'V')), 3);
+        assert_eq!(blosum62.score(&character(b'X'), &character(b'A')), 0);
+        assert_eq!(pam250.score(&character(b'W'), &character(b'W')), 17);
+        assert_eq!(pam250.score(&character(b'C'), &character(b'W')), -8);
+    }
+
+    #[test]
+    fn test_match_mismatch() {
+        let matrix = SubstitutionMatrix::<DnaAlphabet>::match_mismatch(2, -3);
+        let character = |ascii| DnaCharacter::try_from(ascii).unwrap();
+        assert_eq!(matrix.score(&character(b'A'), &character(b'A')), 2);
+        assert_eq!(matrix.score(&character(b'A'), &character(b'T')), -3);
+    }
+}
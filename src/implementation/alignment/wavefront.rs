@@ -0,0 +1,426 @@
+//! Gap-affine wavefront alignment.
+//!
+//! The [wavefront alignment algorithm](https://doi.org/10.1093/bioinformatics/btaa777) computes an optimal global alignment in time proportional to the product of the sequence length and the alignment cost, and in space proportional to the square of the alignment cost.
+//! Hence, it is much faster than the dynamic programming of [PairwiseAligner](super::PairwiseAligner) for long and similar sequences.
+//!
+//! Stretches of matching characters are skipped using [GenomeSequence::common_prefix_len], which compares packed words for bit vector genomes.
+//!
+//! ```rust
+//! use compact_genome::implementation::alignment::wavefront::WavefrontAligner;
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+//! use compact_genome::interface::sequence::OwnedGenomeSequence;
+//!
+//! let reference = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTACGTTTACGT").unwrap();
+//! let query = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTAGGTACGT").unwrap();
+//! let aligner = WavefrontAligner::new(4, 6, 2);
+//! let alignment = aligner.align(&reference, &query);
+//! assert_eq!(alignment.score(), -14);
+//! assert_eq!(alignment.to_string(), "5=1X2=2D4=");
+//! ```
+
+use crate::implementation::alignment::{Alignment, AlignmentOperation};
+use crate::interface::alphabet::Alphabet;
+use crate::interface::sequence::GenomeSequence;
+
+/// An aligner that computes optimal global alignments with gap-affine costs using the wavefront alignment algorithm.
+///
+/// Matches cost nothing, substitutions cost `mismatch` and a gap of length `l` costs `gap_open + l * gap_extend`.
+/// The [score](Alignment::score) of the resulting alignment is the negated cost.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WavefrontAligner {
+    mismatch: usize,
+    gap_open: usize,
+    gap_extend: usize,
+}
+
+/// The offset of diagonals that cannot be reached with a score.
+const NONE: i64 = i64::MIN / 4;
+
+/// The furthest reaching offsets of one score, for a range of diagonals.
+#[derive(Debug, Clone)]
+struct Wavefront {
+    lowest_diagonal: i64,
+    offsets: Vec<i64>,
+}
+
+impl Wavefront {
+    fn get(&self, diagonal: i64) -> i64 {
+        usize::try_from(diagonal - self.lowest_diagonal)
+            .ok()
+            .and_then(|index| self.offsets.get(index))
+            .copied()
+            .unwrap_or(NONE)
+    }
+
+    fn highest_diagonal(&self) -> i64 {
+        self.lowest_diagonal + self.offsets.len() as i64 - 1
+    }
+}
+
+/// The wavefronts of one score for alignments ending with a match or substitution, with a deletion and with an insertion.
+#[derive(Debug, Clone)]
+struct WavefrontSet {
+    matches: Wavefront,
+    deletions: Wavefront,
+    insertions: Wavefront,
+}
+
+impl WavefrontAligner {
+    /// Creates a new wavefront aligner with the given costs.
+    ///
+    /// Panics if `mismatch` or `gap_extend` is zero.
+    pub fn new(mismatch: usize, gap_open: usize, gap_extend: usize) -> Self {
+        assert!(mismatch > 0, "the mismatch cost must be positive");
+        assert!(gap_extend > 0, "the gap extension cost must be positive");
+        Self {
+            mismatch,
+            gap_open,
+            gap_extend,
+        }
+    }
+
+    /// Aligns the query globally to the reference.
+    pub fn align<
+        ReferenceSequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        QuerySequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &self,
+        reference: &ReferenceSequence,
+        query: &QuerySequence,
+    ) -> Alignment {
+        let reference = reference.as_genome_subsequence();
+        let query = query.as_genome_subsequence();
+        let reference_len = reference.len() as i64;
+        let query_len = query.len() as i64;
+
+        // Diagonals are identified by reference offset minus query offset, and wavefronts store reference offsets.
+        let is_valid = |diagonal: i64, offset: i64| {
+            offset >= 0
+                && offset <= reference_len
+                && offset - diagonal <= query_len
+                && offset >= diagonal
+        };
+        let extend = |diagonal: i64, offset: i64| {
+            if offset == NONE {
+                return NONE;
+            }
+            let reference_offset = offset as usize;
+            let query_offset = (offset - diagonal) as usize;
+            offset
+                + reference[reference_offset..reference.len()]
+                    .common_prefix_len(&query[query_offset..query.len()]) as i64
+        };
+
+        let end_diagonal = reference_len - query_len;
+        let mut wavefronts: Vec<Option<WavefrontSet>> = vec![Some(WavefrontSet {
+            matches: Wavefront {
+                lowest_diagonal: 0,
+                offsets: vec![extend(0, 0)],
+            },
+            deletions: Wavefront {
+                lowest_diagonal: 0,
+                offsets: vec![NONE],
+            },
+            insertions: Wavefront {
+                lowest_diagonal: 0,
+                offsets: vec![NONE],
+            },
+        })];
+
+        let mut score = 0;
+        while !wavefronts[score]
+            .as_ref()
+            .is_some_and(|wavefront| wavefront.matches.get(end_diagonal) >= reference_len)
+        {
+            score += 1;
+            let previous = |distance: usize| {
+                score
+                    .checked_sub(distance)
+                    .and_then(|score| wavefronts[score].as_ref())
+            };
+            let mismatch = previous(self.mismatch);
+            let open = previous(self.gap_open + self.gap_extend);
+            let extension = previous(self.gap_extend);
+            if mismatch.is_none() && open.is_none() && extension.is_none() {
+                wavefronts.push(None);
+                continue;
+            }
+
+            let mut lowest_diagonal = i64::MAX;
+            let mut highest_diagonal = i64::MIN;
+            for wavefront in [mismatch, open, extension].into_iter().flatten() {
+                for component in [
+                    &wavefront.matches,
+                    &wavefront.deletions,
+                    &wavefront.insertions,
+                ] {
+                    lowest_diagonal = lowest_diagonal.min(component.lowest_diagonal - 1);
+                    highest_diagonal = highest_diagonal.max(component.highest_diagonal() + 1);
+                }
+            }
+            let lowest_diagonal = lowest_diagonal.max(-query_len);
+            let highest_diagonal = highest_diagonal.min(reference_len);
+
+            let get = |wavefront: Option<&WavefrontSet>,
+                       component: fn(&WavefrontSet) -> &Wavefront,
+                       diagonal: i64| {
+                wavefront.map_or(NONE, |wavefront| component(wavefront).get(diagonal))
+            };
+            let mut matches = Vec::new();
+            let mut deletions = Vec::new();
+            let mut insertions = Vec::new();
+            for diagonal in lowest_diagonal..=highest_diagonal {
+                let mut deletion = get(open, |w| &w.matches, diagonal - 1).max(get(
+                    extension,
+                    |w| &w.deletions,
+                    diagonal - 1,
+                )) + 1;
+                if !is_valid(diagonal, deletion) {
+                    deletion = NONE;
+                }
+                let mut insertion = get(open, |w| &w.matches, diagonal + 1).max(get(
+                    extension,
+                    |w| &w.insertions,
+                    diagonal + 1,
+                ));
+                if !is_valid(diagonal, insertion) {
+                    insertion = NONE;
+                }
+                let mut substitution = get(mismatch, |w| &w.matches, diagonal) + 1;
+                if !is_valid(diagonal, substitution) {
+                    substitution = NONE;
+                }
+
+                matches.push(extend(diagonal, substitution.max(deletion).max(insertion)));
+                deletions.push(deletion);
+                insertions.push(insertion);
+            }
+
+            wavefronts.push(Some(WavefrontSet {
+                matches: Wavefront {
+                    lowest_diagonal,
+                    offsets: matches,
+                },
+                deletions: Wavefront {
+                    lowest_diagonal,
+                    offsets: deletions,
+                },
+                insertions: Wavefront {
+                    lowest_diagonal,
+                    offsets: insertions,
+                },
+            }));
+        }
+
+        // Trace back.
+        #[derive(Clone, Copy, Eq, PartialEq)]
+        enum Component {
+            Matches,
+            Deletions,
+            Insertions,
+        }
+        let get = |score: Option<usize>, component: Component, diagonal: i64| {
+            score
+                .and_then(|score| wavefronts[score].as_ref())
+                .map_or(NONE, |wavefront| match component {
+                    Component::Matches => wavefront.matches.get(diagonal),
+                    Component::Deletions => wavefront.deletions.get(diagonal),
+                    Component::Insertions => wavefront.insertions.get(diagonal),
+                })
+        };
+
+        let cost = score;
+        let mut operations = Vec::new();
+        let mut component = Component::Matches;
+        let mut diagonal = end_diagonal;
+        let mut offset = reference_len;
+        loop {
+            match component {
+                Component::Matches => {
+                    if score == 0 {
+                        operations.push((AlignmentOperation::Match, offset as usize));
+                        break;
+                    }
+
+                    let mut substitution = get(
+                        score.checked_sub(self.mismatch),
+                        Component::Matches,
+                        diagonal,
+                    ) + 1;
+                    if !is_valid(diagonal, substitution) {
+                        substitution = NONE;
+                    }
+                    let deletion = get(Some(score), Component::Deletions, diagonal);
+                    let insertion = get(Some(score), Component::Insertions, diagonal);
+                    let source = substitution.max(deletion).max(insertion);
+                    operations.push((AlignmentOperation::Match, (offset - source) as usize));
+                    offset = source;
+
+                    if source == substitution {
+                        operations.push((AlignmentOperation::Substitution, 1));
+                        offset -= 1;
+                        score -= self.mismatch;
+                    } else if source == deletion {
+                        component = Component::Deletions;
+                    } else {
+                        component = Component::Insertions;
+                    }
+                }
+                Component::Deletions => {
+                    operations.push((AlignmentOperation::Deletion, 1));
+                    offset -= 1;
+                    diagonal -= 1;
+                    let open = score.checked_sub(self.gap_open + self.gap_extend);
+                    if get(open, Component::Matches, diagonal) == offset {
+                        score = open.unwrap();
+                        component = Component::Matches;
+                    } else {
+                        score -= self.gap_extend;
+                    }
+                }
+                Component::Insertions => {
+                    operations.push((AlignmentOperation::Insertion, 1));
+                    diagonal += 1;
+                    let open = score.checked_sub(self.gap_open + self.gap_extend);
+                    if get(open, Component::Matches, diagonal) == offset {
+                        score = open.unwrap();
+                        component = Component::Matches;
+                    } else {
+                        score -= self.gap_extend;
+                    }
+                }
+            }
+        }
+        operations.reverse();
+
+        Alignment::new(
+            -i32::try_from(cost).expect("alignment cost overflows i32"),
+            0..reference.len(),
+            0..query.len(),
+            operations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alignment::substitution_matrix::SubstitutionMatrix;
+    use crate::implementation::alignment::wavefront::WavefrontAligner;
+    use crate::implementation::alignment::{
+        Alignment, AlignmentMode, AlignmentOperation, PairwiseAligner,
+    };
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::OwnedGenomeSequence;
+    use crate::test_util::Random;
+
+    /// Returns a pseudo-random sequence and a mutated copy of it.
+    fn mutated_pair(len: usize, mutations: usize, seed: u64) -> (Vec<u8>, Vec<u8>) {
+        let mut random = Random::new(seed);
+        let reference = random.ascii(len, b"ACGT");
+        let mut query = reference.clone();
+        for _ in 0..mutations {
+            let position = random.below(query.len() + 1);
+            match random.below(3) {
+                0 if position < query.len() => query[position] = random.character(b"ACGT"),
+                1 if position < query.len() => {
+                    query.remove(position);
+                }
+                _ => query.insert(position, random.character(b"ACGT")),
+            }
+        }
+        (reference, query)
+    }
+
+    /// Checks that the alignment is consistent with the sequences and returns its cost.
+    fn cost(
+        aligner: &WavefrontAligner,
+        reference: &[u8],
+        query: &[u8],
+        alignment: &Alignment,
+    ) -> usize {
+        let (mut reference_offset, mut query_offset, mut cost) = (0, 0, 0);
+        for &(operation, count) in alignment.cigar() {
+            match operation {
+                AlignmentOperation::Match | AlignmentOperation::Substitution => {
+                    for _ in 0..count {
+                        assert_eq!(
+                            operation == AlignmentOperation::Match,
+                            reference[reference_offset] == query[query_offset]
+                        );
+                        if operation == AlignmentOperation::Substitution {
+                            cost += aligner.mismatch;
+                        }
+                        reference_offset += 1;
+                        query_offset += 1;
+                    }
+                }
+                AlignmentOperation::Insertion => {
+                    cost += aligner.gap_open + count * aligner.gap_extend;
+                    query_offset += count;
+                }
+                AlignmentOperation::Deletion => {
+                    cost += aligner.gap_open + count * aligner.gap_extend;
+                    reference_offset += count;
+                }
+            }
+        }
+        assert_eq!(reference_offset, reference.len());
+        assert_eq!(query_offset, query.len());
+        cost
+    }
+
+    #[test]
+    fn test_against_dynamic_programming() {
+        for (index, (len, mutations)) in [
+            (0, 0),
+            (1, 1),
+            (10, 0),
+            (20, 3),
+            (100, 10),
+            (150, 40),
+            (300, 5),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for (mismatch, gap_open, gap_extend) in [(1, 0, 1), (4, 6, 2), (2, 3, 1)] {
+                let (reference, query) = mutated_pair(len, mutations, index as u64);
+                let aligner = WavefrontAligner::new(mismatch, gap_open, gap_extend);
+                let dynamic_programming = PairwiseAligner::new(
+                    SubstitutionMatrix::<DnaAlphabet>::match_mismatch(0, -(mismatch as i32)),
+                    gap_open as i32,
+                    gap_extend as i32,
+                    AlignmentMode::Global,
+                );
+
+                let expected = dynamic_programming
+                    .align(
+                        &VectorGenome::<DnaAlphabet>::from_slice_u8(&reference).unwrap(),
+                        &VectorGenome::<DnaAlphabet>::from_slice_u8(&query).unwrap(),
+                    )
+                    .unwrap()
+                    .score();
+                let vector_alignment = aligner.align(
+                    &VectorGenome::<DnaAlphabet>::from_slice_u8(&reference).unwrap(),
+                    &VectorGenome::<DnaAlphabet>::from_slice_u8(&query).unwrap(),
+                );
+                let bit_vector_alignment = aligner.align(
+                    &BitVectorGenome::<DnaAlphabet>::from_slice_u8(&reference).unwrap(),
+                    &BitVectorGenome::<DnaAlphabet>::from_slice_u8(&query).unwrap(),
+                );
+
+                assert_eq!(vector_alignment.score(), expected);
+                assert_eq!(bit_vector_alignment.score(), expected);
+                assert_eq!(
+                    cost(&aligner, &reference, &query, &bit_vector_alignment) as i32,
+                    -expected
+                );
+            }
+        }
+    }
+}
@@ -3,10 +3,13 @@
 pub mod ascii_alphabet;
 pub mod dna_alphabet;
 pub mod dna_alphabet_or_n;
+pub mod dna_alphabet_soft_masked;
 pub mod dna_iupac_nucleic_acid_alphabet;
+pub mod dna_methylation_alphabet;
 pub mod famsa_amino_acid_alphabet;
 pub mod generic_alphabet;
 pub mod iupac_amino_acid_alphabet;
+pub mod phred_quality_alphabet;
 pub mod rna_alphabet;
 pub mod rna_alphabet_or_n;
 pub mod rna_iupac_nucleic_acid_alphabet;
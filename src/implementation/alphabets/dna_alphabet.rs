@@ -1,13 +1,30 @@
 //! The DNA alphabet, consisting of characters A, C, G and T.
 
 use crate::impl_generic_alphabet;
+use crate::interface::alphabet::NucleotideAlphabet;
 
-impl_generic_alphabet!("DNA alphabet", DnaAlphabet, DnaCharacter, b"ACGT", b"TGCA");
+impl_generic_alphabet!(
+    "DNA alphabet",
+    DnaAlphabet,
+    DnaCharacter,
+    b"ACGT",
+    b"TGCA",
+    PURINE_CHARACTERS = b"AG",
+    PYRIMIDINE_CHARACTERS = b"CT",
+    STRONG_CHARACTERS = b"CG",
+    WEAK_CHARACTERS = b"AT",
+);
+
+impl NucleotideAlphabet for DnaAlphabet {}
 
 #[cfg(test)]
 mod tests {
-    use crate::implementation::alphabets::dna_alphabet::DnaCharacter;
+    use crate::implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter};
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::ComplementableAlphabetCharacter;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
     use std::convert::TryFrom;
+    use traitsequence::interface::Sequence;
 
     #[test]
     fn test_dna_alphabet_conversion() {
@@ -32,4 +49,22 @@ mod tests {
             assert_eq!(format!("{character}"), format!("{dna_character}"));
         }
     }
+
+    #[test]
+    fn test_complement() {
+        for (ascii, complement_ascii) in b"ACGT".iter().zip(b"TGCA") {
+            let character = DnaCharacter::try_from(*ascii).unwrap();
+            assert_eq!(u8::from(character.complement()), *complement_ascii);
+        }
+
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"TTGCA").unwrap();
+        let reverse_complement: VectorGenome<DnaAlphabet> =
+            genome.convert_with_reverse_complement();
+        assert_eq!(reverse_complement.as_string(), "TGCAA");
+        assert!(genome
+            .reverse_complement_iter()
+            .eq(reverse_complement.iter().cloned()));
+        assert!(!genome.is_canonical());
+        assert!(reverse_complement.is_canonical());
+    }
 }
@@ -1,6 +1,9 @@
 //! The DNA alphabet including N, consisting of characters A, C, G, T and N.
+//!
+//! The [canonical order](crate::interface::alphabet::AlphabetCharacter::canonical_rank) is A, C, G, T, N, i.e. N is ordered last.
 
 use crate::impl_generic_alphabet;
+use crate::interface::alphabet::NucleotideAlphabet;
 
 impl_generic_alphabet!(
     "DNA alphabet including N",
@@ -8,12 +11,26 @@ impl_generic_alphabet!(
     DnaCharacterOrN,
     b"ACGNT",
     b"TGCNA",
+    AMBIGUOUS_CHARACTERS = b"N",
+    PURINE_CHARACTERS = b"AG",
+    PYRIMIDINE_CHARACTERS = b"CT",
+    STRONG_CHARACTERS = b"CG",
+    WEAK_CHARACTERS = b"AT",
+    CANONICAL_ORDER = b"ACGTN",
 );
 
+impl NucleotideAlphabet for DnaAlphabetOrN {}
+
 #[cfg(test)]
 mod tests {
-    use crate::implementation::alphabets::dna_alphabet_or_n::DnaCharacterOrN;
+    use crate::implementation::alphabets::dna_alphabet_or_n::{DnaAlphabetOrN, DnaCharacterOrN};
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::{
+        Alphabet, AlphabetCharacter, ComplementableAlphabetCharacter,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
     use std::convert::TryFrom;
+    use traitsequence::interface::Sequence;
 
     #[test]
     fn test_dna_alphabet_conversion() {
@@ -38,4 +55,56 @@ mod tests {
             assert_eq!(format!("{character}"), format!("{dna_character}"));
         }
     }
+
+    #[test]
+    fn test_character_classes() {
+        for character in DnaAlphabetOrN::iter() {
+            let ascii = u8::from(character);
+            assert_eq!(character.is_ambiguous(), ascii == b'N');
+            assert_eq!(character.is_purine(), b"AG".contains(&ascii));
+            assert_eq!(character.is_pyrimidine(), b"CT".contains(&ascii));
+            assert_eq!(character.is_strong(), b"CG".contains(&ascii));
+            assert_eq!(character.is_weak(), b"AT".contains(&ascii));
+            assert!(!character.is_gap());
+            assert!(!character.is_stop());
+        }
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        let ranks: Vec<_> = b"ACGNT"
+            .iter()
+            .map(|&ascii| DnaCharacterOrN::try_from(ascii).unwrap().canonical_rank())
+            .collect();
+        assert_eq!(ranks, [0, 1, 2, 4, 3]);
+        assert!(
+            DnaAlphabetOrN::iter().any(|character| character.canonical_rank() != character.index())
+        );
+
+        // The reverse complement of NA is TN, which is smaller since N is ordered last.
+        assert!(!VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"NA")
+            .unwrap()
+            .is_canonical());
+        assert!(VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"TN")
+            .unwrap()
+            .is_canonical());
+    }
+
+    #[test]
+    fn test_complement() {
+        for (ascii, complement_ascii) in b"ACGNT".iter().zip(b"TGCNA") {
+            let character = DnaCharacterOrN::try_from(*ascii).unwrap();
+            assert_eq!(u8::from(character.complement()), *complement_ascii);
+        }
+
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"TNGCA").unwrap();
+        let reverse_complement: VectorGenome<DnaAlphabetOrN> =
+            genome.convert_with_reverse_complement();
+        assert_eq!(reverse_complement.as_string(), "TGCNA");
+        assert!(genome
+            .reverse_complement_iter()
+            .eq(reverse_complement.iter().cloned()));
+        assert!(!genome.is_canonical());
+        assert!(reverse_complement.is_canonical());
+    }
 }
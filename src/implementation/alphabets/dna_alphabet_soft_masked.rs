@@ -0,0 +1,156 @@
+//! The soft-masked DNA alphabet, consisting of characters A, C, G, T and their soft-masked (lower-case) variants a, c, g and t.
+//!
+//! The mask is stored as the highest bit of the character index, such that a sequence over this alphabet takes three bits per character in a [`BitVectorGenome`](crate::implementation::bit_vec_sequence::BitVectorGenome).
+//! The complement of a character preserves its case.
+
+use crate::impl_generic_alphabet;
+use crate::implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter};
+use crate::interface::alphabet::{AlphabetCharacter, NucleotideAlphabet, SoftMaskedAlphabet};
+
+impl_generic_alphabet!(
+    "soft-masked DNA alphabet",
+    DnaAlphabetSoftMasked,
+    DnaCharacterSoftMasked,
+    b"ACGTacgt",
+    b"TGCAtgca",
+    PURINE_CHARACTERS = b"AGag",
+    PYRIMIDINE_CHARACTERS = b"CTct",
+    STRONG_CHARACTERS = b"CGcg",
+    WEAK_CHARACTERS = b"ATat",
+);
+
+impl NucleotideAlphabet for DnaAlphabetSoftMasked {}
+
+impl SoftMaskedAlphabet for DnaAlphabetSoftMasked {
+    type UnmaskedAlphabet = DnaAlphabet;
+
+    fn is_masked(character: &Self::CharacterType) -> bool {
+        character.index() >= DnaCharacter::ALPHABET_SIZE
+    }
+
+    fn unmask(character: &Self::CharacterType) -> DnaCharacter {
+        DnaCharacter::from_index(character.index() % DnaCharacter::ALPHABET_SIZE).unwrap()
+    }
+
+    fn mask(character: &DnaCharacter, masked: bool) -> Self::CharacterType {
+        let offset = if masked {
+            DnaCharacter::ALPHABET_SIZE
+        } else {
+            0
+        };
+        DnaCharacterSoftMasked::from_index(character.index() + offset).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter};
+    use crate::implementation::alphabets::dna_alphabet_soft_masked::{
+        DnaAlphabetSoftMasked, DnaCharacterSoftMasked,
+    };
+    use crate::implementation::array_kmer::ArrayKmer;
+    use crate::implementation::bit_vec_sequence::{alphabet_character_bit_width, BitVectorGenome};
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::{
+        Alphabet, ComplementableAlphabetCharacter, SoftMaskedAlphabet,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_dna_alphabet_conversion() {
+        for ascii in 0u8..=255u8 {
+            if b"ACGTacgt".contains(&ascii) {
+                assert_eq!(
+                    u8::from(
+                        DnaCharacterSoftMasked::try_from(ascii).unwrap_or_else(|_| panic!(
+                            "character {ascii} was expected to be valid, but is not"
+                        ))
+                    ),
+                    ascii
+                );
+            } else {
+                assert!(DnaCharacterSoftMasked::try_from(ascii).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        for character in ['A', 'C', 'G', 'T', 'a', 'c', 'g', 't'] {
+            let dna_character = DnaCharacterSoftMasked::try_from(character).unwrap();
+            assert_eq!(format!("{character}"), format!("{dna_character}"));
+        }
+    }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(alphabet_character_bit_width(DnaAlphabetSoftMasked::SIZE), 3);
+
+        for character in DnaAlphabetSoftMasked::iter() {
+            let ascii = u8::from(character);
+            let unmasked = DnaAlphabetSoftMasked::unmask(&character);
+            assert_eq!(
+                DnaAlphabetSoftMasked::is_masked(&character),
+                ascii.is_ascii_lowercase()
+            );
+            assert_eq!(u8::from(unmasked), ascii.to_ascii_uppercase());
+            assert_eq!(
+                DnaAlphabetSoftMasked::mask(
+                    &unmasked,
+                    DnaAlphabetSoftMasked::is_masked(&character)
+                ),
+                character
+            );
+            assert_eq!(
+                DnaAlphabetSoftMasked::is_masked(&character.complement()),
+                DnaAlphabetSoftMasked::is_masked(&character)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignoring_mask() {
+        let genome = BitVectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"ACgtaC").unwrap();
+        assert_eq!(genome.as_string(), "ACgtaC");
+        assert_eq!(genome.clone_as_reverse_complement().as_string(), "GtacGT");
+        assert_eq!(
+            genome.unmasked_iter().collect::<Vec<_>>(),
+            VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTAC")
+                .unwrap()
+                .into_inner()
+        );
+
+        let upper = BitVectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"ACGTAC").unwrap();
+        assert_ne!(genome, upper);
+        assert!(genome.eq_ignoring_mask(&upper));
+        assert_eq!(genome.cmp_ignoring_mask(&upper), std::cmp::Ordering::Equal);
+        let other = BitVectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"ACGTAg").unwrap();
+        assert!(!genome.eq_ignoring_mask(&other));
+        assert_eq!(genome.cmp_ignoring_mask(&other), std::cmp::Ordering::Less);
+
+        // With the mask, the lower-case a compares greater than the upper-case C.
+        let genome = BitVectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"aCGG").unwrap();
+        assert!(!genome.is_canonical());
+        assert!(genome.is_canonical_ignoring_mask());
+        let genome = BitVectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"aCgT").unwrap();
+        assert!(!genome.is_self_complemental());
+        assert!(genome.is_self_complemental_ignoring_mask());
+
+        let k_mers: Vec<VectorGenome<DnaAlphabet>> = genome
+            .cloned_unmasked_k_mer_iter::<3, ArrayKmer<3, DnaAlphabet>, _>()
+            .map(|k_mer| k_mer.convert())
+            .collect();
+        assert_eq!(
+            k_mers,
+            vec![
+                VectorGenome::from_slice_u8(b"ACG").unwrap(),
+                VectorGenome::from_slice_u8(b"CGT").unwrap()
+            ]
+        );
+        assert_eq!(
+            DnaAlphabetSoftMasked::unmask(&genome[2]),
+            DnaCharacter::try_from(b'G').unwrap()
+        );
+    }
+}
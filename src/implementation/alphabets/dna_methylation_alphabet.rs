@@ -0,0 +1,52 @@
+//! The methylation-aware DNA alphabet, consisting of characters A, C, G, T, m and h.
+//!
+//! The character `m` denotes 5-methylcytosine (5mC) and `h` denotes 5-hydroxymethylcytosine (5hmC).
+//! Since methylation is specific to a strand, the reverse strand of a methylated cytosine cannot be expressed in this alphabet.
+//! Hence, the alphabet has no complement, and operations involving reverse complements, like canonical k-mers, are not available for it.
+//! They are available for the [bisulfite converted](crate::implementation::bisulfite) views of a sequence, which use the plain DNA alphabet.
+//!
+//! ```compile_fail
+//! use compact_genome::implementation::alphabets::dna_methylation_alphabet::DnaMethylationAlphabet;
+//! use compact_genome::implementation::vec_sequence::VectorGenome;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//!
+//! let genome = VectorGenome::<DnaMethylationAlphabet>::from_slice_u8(b"ACmG").unwrap();
+//! genome.is_canonical();
+//! ```
+
+use crate::impl_generic_alphabet;
+
+impl_generic_alphabet!(
+    "methylation-aware DNA alphabet",
+    DnaMethylationAlphabet,
+    DnaMethylationCharacter,
+    b"ACGTmh",
+    PURINE_CHARACTERS = b"AG",
+    PYRIMIDINE_CHARACTERS = b"CTmh",
+    STRONG_CHARACTERS = b"CGmh",
+    WEAK_CHARACTERS = b"AT",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_methylation_alphabet::DnaMethylationCharacter;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_dna_methylation_alphabet_conversion() {
+        for ascii in 0u8..=255u8 {
+            if b"ACGTmh".contains(&ascii) {
+                assert_eq!(
+                    u8::from(
+                        DnaMethylationCharacter::try_from(ascii).unwrap_or_else(|_| panic!(
+                            "character {ascii} was expected to be valid, but is not"
+                        ))
+                    ),
+                    ascii
+                );
+            } else {
+                assert!(DnaMethylationCharacter::try_from(ascii).is_err());
+            }
+        }
+    }
+}
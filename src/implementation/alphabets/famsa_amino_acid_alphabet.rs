@@ -20,7 +20,7 @@ impl_generic_alphabet!(
     FamsaAminoAcidAlphabet,
     FamsaAminoAcidCharacter,
     b"ARNDCQEGHILKMFPSTWYVBZX*",
-    b"ARNDCQEGHILKMFPSTWYVBZX*",
+    AMBIGUOUS_CHARACTERS = b"BZX*",
 );
 
 #[cfg(test)]
@@ -2,7 +2,9 @@
 
 use ref_cast::RefCast;
 
-use crate::interface::alphabet::{Alphabet, AlphabetCharacter, AlphabetError};
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, AlphabetError, ComplementableAlphabetCharacter,
+};
 
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
@@ -43,7 +45,66 @@ const fn generate_character_to_complement_character_lookup_table(
     result
 }
 
-const U8_TABLE: [u8; 256] = const {
+const fn generate_character_class_lookup_table(
+    ascii_to_character: &[u8; 256],
+    classes: &[&[u8]],
+) -> [u8; 256] {
+    assert!(classes.len() <= u8::BITS as usize);
+    let mut result = [0; 256];
+
+    let mut class = 0;
+    while class < classes.len() {
+        let mut index = 0;
+        while index < classes[class].len() {
+            let character = ascii_to_character[classes[class][index] as usize];
+            assert!(character != u8::MAX);
+            result[character as usize] |= 1 << class;
+            index += 1;
+        }
+        class += 1;
+    }
+
+    result
+}
+
+const fn generate_character_to_canonical_rank_lookup_table(
+    character_to_ascii: &[u8],
+    ascii_to_character: &[u8; 256],
+    canonical_order: &[u8],
+) -> [u8; 256] {
+    let mut result = [u8::MAX; 256];
+
+    if canonical_order.is_empty() {
+        // Order by index.
+        let mut character = 0;
+        while character < character_to_ascii.len() {
+            result[character] = character as u8;
+            character += 1;
+        }
+    } else {
+        assert!(canonical_order.len() == character_to_ascii.len());
+        let mut rank = 0;
+        while rank < canonical_order.len() {
+            let character = ascii_to_character[canonical_order[rank] as usize];
+            assert!(character != u8::MAX);
+            assert!(result[character as usize] == u8::MAX);
+            result[character as usize] = rank as u8;
+            rank += 1;
+        }
+    }
+
+    result
+}
+
+const AMBIGUOUS_CLASS: u8 = 1 << 0;
+const GAP_CLASS: u8 = 1 << 1;
+const STOP_CLASS: u8 = 1 << 2;
+const PURINE_CLASS: u8 = 1 << 3;
+const PYRIMIDINE_CLASS: u8 = 1 << 4;
+const STRONG_CLASS: u8 = 1 << 5;
+const WEAK_CLASS: u8 = 1 << 6;
+
+pub(crate) const U8_TABLE: [u8; 256] = const {
     let mut result = [0; 256];
 
     let mut index = 0;
@@ -61,23 +122,41 @@ const U8_TABLE: [u8; 256] = const {
 ///
 /// ## Example
 ///
-/// Note how the each character has its complement written directly below it.
-///
 /// ```rust
 /// use compact_genome::implementation::alphabets::generic_alphabet::CharacterFromToAsciiTable;
 ///
 /// struct AlphabetCharacterFromToAsciiTable;
 ///
 /// impl CharacterFromToAsciiTable for AlphabetCharacterFromToAsciiTable {
-///     const CHARACTER_TO_ASCII: &[u8] = b"ACGT";
-///     const CHAR_TO_COMP_ASCII: &[u8] = b"TGCA";
+///     const CHARACTER_TO_ASCII: &[u8] = b"ACGNT";
+///     const AMBIGUOUS_CHARACTERS: &[u8] = b"N";
+///     const PURINE_CHARACTERS: &[u8] = b"AG";
+///     const PYRIMIDINE_CHARACTERS: &[u8] = b"CT";
+///     const STRONG_CHARACTERS: &[u8] = b"CG";
+///     const WEAK_CHARACTERS: &[u8] = b"AT";
 /// }
 /// ```
 pub trait CharacterFromToAsciiTable: 'static {
     /// Convert internal character indices into ASCII characters.
     const CHARACTER_TO_ASCII: &[u8];
-    /// Convert internal character indices into their complement ASCII characters.
-    const CHAR_TO_COMP_ASCII: &[u8];
+
+    /// The ASCII characters that are [ambiguous](AlphabetCharacter::is_ambiguous).
+    const AMBIGUOUS_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that denote [gaps](AlphabetCharacter::is_gap).
+    const GAP_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that denote [stop codons](AlphabetCharacter::is_stop).
+    const STOP_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that stand only for [purines](AlphabetCharacter::is_purine).
+    const PURINE_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that stand only for [pyrimidines](AlphabetCharacter::is_pyrimidine).
+    const PYRIMIDINE_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that stand only for [strong nucleotides](AlphabetCharacter::is_strong).
+    const STRONG_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters that stand only for [weak nucleotides](AlphabetCharacter::is_weak).
+    const WEAK_CHARACTERS: &[u8] = b"";
+    /// The ASCII characters in their [canonical order](AlphabetCharacter::canonical_rank).
+    /// If empty, then the characters are ordered by their index, i.e. their order in [CHARACTER_TO_ASCII](Self::CHARACTER_TO_ASCII).
+    const CANONICAL_ORDER: &[u8] = b"";
 
     /// The size of the alphabet.
     /// Automatically determined by the required constants.
@@ -88,13 +167,28 @@ pub trait CharacterFromToAsciiTable: 'static {
     const ASCII_TO_CHARACTER: [u8; 256] =
         generate_ascii_to_character_lookup_table(Self::CHARACTER_TO_ASCII);
 
-    /// Convert internal character indices into their complement indices.
+    /// Convert internal character indices into the bitsets of their character classes.
     /// Automatically determined by the required constants.
-    // We cannot choose the length of this based on the alphabet size yet.
-    const CHARACTER_TO_COMPLEMENT_CHARACTER: [u8; 256] =
-        generate_character_to_complement_character_lookup_table(
-            Self::CHAR_TO_COMP_ASCII,
+    const CHARACTER_TO_CLASSES: [u8; 256] = generate_character_class_lookup_table(
+        &Self::ASCII_TO_CHARACTER,
+        &[
+            Self::AMBIGUOUS_CHARACTERS,
+            Self::GAP_CHARACTERS,
+            Self::STOP_CHARACTERS,
+            Self::PURINE_CHARACTERS,
+            Self::PYRIMIDINE_CHARACTERS,
+            Self::STRONG_CHARACTERS,
+            Self::WEAK_CHARACTERS,
+        ],
+    );
+
+    /// Convert internal character indices into their canonical ranks.
+    /// Automatically determined by the required constants.
+    const CHARACTER_TO_CANONICAL_RANK: [u8; 256] =
+        generate_character_to_canonical_rank_lookup_table(
+            Self::CHARACTER_TO_ASCII,
             &Self::ASCII_TO_CHARACTER,
+            Self::CANONICAL_ORDER,
         );
 
     /// Convert an ASCII character into an internal character index.
@@ -112,6 +206,53 @@ pub trait CharacterFromToAsciiTable: 'static {
         Self::CHARACTER_TO_ASCII[usize::from(character)]
     }
 
+    /// Convert an internal character index into the bitset of its character classes.
+    fn character_to_classes(character: u8) -> u8 {
+        Self::CHARACTER_TO_CLASSES[usize::from(character)]
+    }
+
+    /// Convert an internal character index into its canonical rank.
+    fn character_to_canonical_rank(character: u8) -> u8 {
+        Self::CHARACTER_TO_CANONICAL_RANK[usize::from(character)]
+    }
+}
+
+/// The translation table between internal character indices and the indices of their complements.
+///
+/// This trait should be implemented by specifying only the required constants.
+///
+/// ## Example
+///
+/// Note how the each character has its complement written directly below it.
+///
+/// ```rust
+/// use compact_genome::implementation::alphabets::generic_alphabet::{
+///     CharacterComplementTable, CharacterFromToAsciiTable,
+/// };
+///
+/// struct AlphabetCharacterFromToAsciiTable;
+///
+/// impl CharacterFromToAsciiTable for AlphabetCharacterFromToAsciiTable {
+///     const CHARACTER_TO_ASCII: &[u8] = b"ACGT";
+/// }
+///
+/// impl CharacterComplementTable for AlphabetCharacterFromToAsciiTable {
+///     const CHAR_TO_COMP_ASCII: &[u8] = b"TGCA";
+/// }
+/// ```
+pub trait CharacterComplementTable: CharacterFromToAsciiTable {
+    /// Convert internal character indices into their complement ASCII characters.
+    const CHAR_TO_COMP_ASCII: &[u8];
+
+    /// Convert internal character indices into their complement indices.
+    /// Automatically determined by the required constants.
+    // We cannot choose the length of this based on the alphabet size yet.
+    const CHARACTER_TO_COMPLEMENT_CHARACTER: [u8; 256] =
+        generate_character_to_complement_character_lookup_table(
+            Self::CHAR_TO_COMP_ASCII,
+            &Self::ASCII_TO_CHARACTER,
+        );
+
     /// Convert an internal character index into its complement index.
     fn character_to_complement(character: u8) -> u8 {
         Self::CHARACTER_TO_COMPLEMENT_CHARACTER[usize::from(character)]
@@ -195,6 +336,41 @@ impl<Table: CharacterFromToAsciiTable> AlphabetCharacter for GenericCharacter<Ta
         }
     }
 
+    /// The rank of this character in the [canonical order](CharacterFromToAsciiTable::CANONICAL_ORDER) of the table.
+    fn canonical_rank(&self) -> u8 {
+        Table::character_to_canonical_rank(self.character)
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        Table::character_to_classes(self.character) & AMBIGUOUS_CLASS != 0
+    }
+
+    fn is_gap(&self) -> bool {
+        Table::character_to_classes(self.character) & GAP_CLASS != 0
+    }
+
+    fn is_stop(&self) -> bool {
+        Table::character_to_classes(self.character) & STOP_CLASS != 0
+    }
+
+    fn is_purine(&self) -> bool {
+        Table::character_to_classes(self.character) & PURINE_CLASS != 0
+    }
+
+    fn is_pyrimidine(&self) -> bool {
+        Table::character_to_classes(self.character) & PYRIMIDINE_CLASS != 0
+    }
+
+    fn is_strong(&self) -> bool {
+        Table::character_to_classes(self.character) & STRONG_CLASS != 0
+    }
+
+    fn is_weak(&self) -> bool {
+        Table::character_to_classes(self.character) & WEAK_CLASS != 0
+    }
+}
+
+impl<Table: CharacterComplementTable> ComplementableAlphabetCharacter for GenericCharacter<Table> {
     fn complement(&self) -> Self {
         Self {
             character: Table::character_to_complement(self.character),
@@ -258,6 +434,9 @@ impl<Table: CharacterFromToAsciiTable> Hash for GenericCharacter<Table> {
 
 /// Generate a custom alphabet implementation.
 ///
+/// If the complements of the characters are given, then the character type additionally implements [`ComplementableAlphabetCharacter`].
+/// Otherwise, operations involving reverse complements are not available for sequences over the alphabet.
+///
 /// ## Example
 ///
 /// We want to implement a custom alphabet that is commonly referred to as "DNA".
@@ -267,9 +446,51 @@ impl<Table: CharacterFromToAsciiTable> Hash for GenericCharacter<Table> {
 /// ```rust
 /// compact_genome::impl_generic_alphabet!("DNA alphabet", DnaAlphabet, DnaCharacter, b"ACGT", b"TGCA");
 /// ```
+///
+/// An alphabet without complements is generated by omitting the complement characters.
+///
+/// ```rust
+/// compact_genome::impl_generic_alphabet!("binary alphabet", BinaryAlphabet, BinaryCharacter, b"01");
+/// ```
+///
+/// The optional constants of [`CharacterFromToAsciiTable`] can be specified after the characters, for example to declare character classes.
+///
+/// ```rust
+/// compact_genome::impl_generic_alphabet!(
+///     "DNA alphabet including N",
+///     DnaAlphabetOrN,
+///     DnaCharacterOrN,
+///     b"ACGNT",
+///     b"TGCNA",
+///     AMBIGUOUS_CHARACTERS = b"N",
+///     PURINE_CHARACTERS = b"AG",
+///     PYRIMIDINE_CHARACTERS = b"CT",
+///     CANONICAL_ORDER = b"ACGTN",
+/// );
+/// ```
 #[macro_export]
 macro_rules! impl_generic_alphabet {
-    ($name:literal, $alphabet:ident, $character:ident, $character_to_ascii:literal, $char_to_comp_ascii:literal $(,)?) => {
+    ($name:literal, $alphabet:ident, $character:ident, $character_to_ascii:literal $(, $constant:ident = $value:expr)* $(,)?) => {
+        $crate::impl_generic_alphabet!(@base $name, $alphabet, $character, $character_to_ascii $(, $constant = $value)*);
+    };
+
+    ($name:literal, $alphabet:ident, $character:ident, $character_to_ascii:literal, $char_to_comp_ascii:literal $(, $constant:ident = $value:expr)* $(,)?) => {
+        $crate::impl_generic_alphabet!(@base $name, $alphabet, $character, $character_to_ascii $(, $constant = $value)*);
+
+        impl $crate::implementation::alphabets::generic_alphabet::CharacterComplementTable
+            for AlphabetCharacterFromToAsciiTable
+        {
+            const CHAR_TO_COMP_ASCII: &[u8] = $char_to_comp_ascii;
+        }
+
+        impl $crate::interface::alphabet::ComplementableAlphabetCharacter for $character {
+            fn complement(&self) -> Self {
+                Self($crate::interface::alphabet::ComplementableAlphabetCharacter::complement(&self.0))
+            }
+        }
+    };
+
+    (@base $name:literal, $alphabet:ident, $character:ident, $character_to_ascii:literal $(, $constant:ident = $value:expr)*) => {
         #[doc = concat!("The translation table between internal ", $name, " character indices and ASCII characters.")]
         pub struct AlphabetCharacterFromToAsciiTable;
 
@@ -277,7 +498,7 @@ macro_rules! impl_generic_alphabet {
             for AlphabetCharacterFromToAsciiTable
         {
             const CHARACTER_TO_ASCII: &[u8] = $character_to_ascii;
-            const CHAR_TO_COMP_ASCII: &[u8] = $char_to_comp_ascii;
+            $(const $constant: &[u8] = $value;)*
         }
 
         #[doc = concat!("A character of a ", $name, ": ", stringify!($character_to_ascii), ".")]
@@ -345,8 +566,36 @@ macro_rules! impl_generic_alphabet {
                 ))
             }
 
-            fn complement(&self) -> Self {
-                Self(self.0.complement())
+            fn canonical_rank(&self) -> u8 {
+                self.0.canonical_rank()
+            }
+
+            fn is_ambiguous(&self) -> bool {
+                self.0.is_ambiguous()
+            }
+
+            fn is_gap(&self) -> bool {
+                self.0.is_gap()
+            }
+
+            fn is_stop(&self) -> bool {
+                self.0.is_stop()
+            }
+
+            fn is_purine(&self) -> bool {
+                self.0.is_purine()
+            }
+
+            fn is_pyrimidine(&self) -> bool {
+                self.0.is_pyrimidine()
+            }
+
+            fn is_strong(&self) -> bool {
+                self.0.is_strong()
+            }
+
+            fn is_weak(&self) -> bool {
+                self.0.is_weak()
             }
         }
 
@@ -361,3 +610,42 @@ macro_rules! impl_generic_alphabet {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::{Alphabet, AlphabetCharacter};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+
+    crate::impl_generic_alphabet!(
+        "DNA alignment alphabet",
+        DnaAlignmentAlphabet,
+        DnaAlignmentCharacter,
+        b"-ACGNT",
+        b"-TGCNA",
+        AMBIGUOUS_CHARACTERS = b"N",
+        GAP_CHARACTERS = b"-",
+        CANONICAL_ORDER = b"ACGTN-",
+    );
+
+    #[test]
+    fn test_gap_characters() {
+        for character in DnaAlignmentAlphabet::iter() {
+            let ascii = u8::from(character);
+            assert_eq!(character.is_gap(), ascii == b'-');
+            assert_eq!(character.is_ambiguous(), ascii == b'N');
+            assert!(!character.is_stop());
+        }
+
+        let gap = DnaAlignmentCharacter::try_from(b'-').unwrap();
+        assert_eq!((gap.index(), gap.canonical_rank()), (0, 5));
+
+        // The reverse complement of -A is T-, which is smaller since gaps are ordered last.
+        assert!(!VectorGenome::<DnaAlignmentAlphabet>::from_slice_u8(b"-A")
+            .unwrap()
+            .is_canonical());
+        assert!(VectorGenome::<DnaAlignmentAlphabet>::from_slice_u8(b"T-")
+            .unwrap()
+            .is_canonical());
+    }
+}
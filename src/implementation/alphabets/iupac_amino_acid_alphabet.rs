@@ -9,7 +9,7 @@ impl_generic_alphabet!(
     IupacAminoAcidAlphabet,
     IupacAminoAcidCharacter,
     b"ARNDCQEGHILKMFPSTWYVX",
-    b"ARNDCQEGHILKMFPSTWYVX",
+    AMBIGUOUS_CHARACTERS = b"X",
 );
 
 #[cfg(test)]
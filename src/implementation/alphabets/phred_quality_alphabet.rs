@@ -0,0 +1,481 @@
+//! Alphabets of [Phred quality scores][1] as found in fastq files.
+//!
+//! Quality scores are encoded as ASCII characters by adding an offset to the score, which is 33 for the Phred+33 encoding and 64 for the legacy Phred+64 encoding.
+//! The lossless alphabets represent each score exactly, while the binned alphabets map each score to the representative score of its bin, such that they can be stored with fewer bits per character.
+//! For example, [`Phred33Alphabet`] takes seven bits per character in a [`BitVectorGenome`](crate::implementation::bit_vec_sequence::BitVectorGenome), while [`Illumina8LevelPhred33Alphabet`] takes three bits and [`Illumina4LevelPhred33Alphabet`] takes two bits.
+//!
+//! Quality scores have no complement, so reverse complement operations are not available for sequences over these alphabets.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Phred_quality_score
+
+use ref_cast::RefCast;
+
+use crate::implementation::alphabets::generic_alphabet::U8_TABLE;
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, AlphabetError, PhredQualityCharacter,
+};
+
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+const fn generate_score_to_bin_lookup_table(
+    max_score: u8,
+    bin_lower_bounds: &[u8],
+    bin_scores: &[u8],
+) -> [u8; 256] {
+    assert!(!bin_lower_bounds.is_empty());
+    assert!(bin_lower_bounds.len() == bin_scores.len());
+    assert!(bin_lower_bounds[0] == 0);
+
+    // Each representative score must fall into its own bin, such that binned scores are stable.
+    let mut bin = 0;
+    while bin < bin_scores.len() {
+        assert!(bin_lower_bounds[bin] <= bin_scores[bin]);
+        assert!(bin_scores[bin] <= max_score);
+        if bin + 1 < bin_lower_bounds.len() {
+            assert!(bin_scores[bin] < bin_lower_bounds[bin + 1]);
+        }
+        bin += 1;
+    }
+
+    let mut result = [u8::MAX; 256];
+
+    let mut bin = 0;
+    let mut score = 0;
+    while score <= max_score as usize {
+        if bin + 1 < bin_lower_bounds.len() && bin_lower_bounds[bin + 1] as usize <= score {
+            bin += 1;
+        }
+        result[score] = bin as u8;
+        score += 1;
+    }
+
+    result
+}
+
+/// The translation table between Phred quality scores and internal character indices.
+///
+/// This trait should be implemented by specifying only the required constants.
+/// If the bins are not specified, then each score up to the maximum score is its own bin, i.e. the encoding is lossless.
+///
+/// ## Example
+///
+/// A binned encoding with three bins.
+/// Note how the representative score of each bin is written directly below its lowest score.
+///
+/// ```rust
+/// use compact_genome::implementation::alphabets::phred_quality_alphabet::PhredQualityTable;
+///
+/// struct ThreeLevelPhred33Table;
+///
+/// impl PhredQualityTable for ThreeLevelPhred33Table {
+///     const OFFSET: u8 = 33;
+///     const MAX_SCORE: u8 = 93;
+///     const BIN_LOWER_BOUNDS: &[u8] = &[0, 10, 30];
+///     const BIN_SCORES: &[u8] =       &[2, 20, 40];
+/// }
+/// ```
+pub trait PhredQualityTable: 'static {
+    /// The ASCII character encoding the score zero.
+    const OFFSET: u8;
+    /// The maximum score that can be encoded.
+    const MAX_SCORE: u8;
+    /// The lowest score of each bin, in ascending order.
+    /// The lowest score of the first bin must be zero.
+    const BIN_LOWER_BOUNDS: &[u8] = U8_TABLE.split_at(Self::MAX_SCORE as usize + 1).0;
+    /// The representative score of each bin.
+    const BIN_SCORES: &[u8] = Self::BIN_LOWER_BOUNDS;
+
+    /// The size of the alphabet.
+    /// Automatically determined by the required constants.
+    const ALPHABET_SIZE: u8 = Self::BIN_SCORES.len() as u8;
+
+    /// Convert scores into internal character indices.
+    /// Automatically determined by the required constants.
+    const SCORE_TO_CHARACTER: [u8; 256] = generate_score_to_bin_lookup_table(
+        Self::MAX_SCORE,
+        Self::BIN_LOWER_BOUNDS,
+        Self::BIN_SCORES,
+    );
+
+    /// Convert an ASCII character into an internal character index.
+    fn ascii_to_character(ascii: u8) -> Option<u8> {
+        ascii
+            .checked_sub(Self::OFFSET)
+            .filter(|&score| score <= Self::MAX_SCORE)
+            .map(Self::score_to_character)
+    }
+
+    /// Convert an internal character index into an ASCII character.
+    fn character_to_ascii(character: u8) -> u8 {
+        Self::character_to_score(character) + Self::OFFSET
+    }
+
+    /// Convert a score into an internal character index.
+    /// Scores above the maximum score are clamped to it.
+    fn score_to_character(score: u8) -> u8 {
+        Self::SCORE_TO_CHARACTER[usize::from(score.min(Self::MAX_SCORE))]
+    }
+
+    /// Convert an internal character index into its representative score.
+    fn character_to_score(character: u8) -> u8 {
+        Self::BIN_SCORES[usize::from(character)]
+    }
+}
+
+/// The lossless Phred+33 encoding with scores from 0 (`!`) to 93 (`~`).
+pub struct Phred33Table;
+
+impl PhredQualityTable for Phred33Table {
+    const OFFSET: u8 = 33;
+    const MAX_SCORE: u8 = 93;
+}
+
+/// The lossless Phred+64 encoding with scores from 0 (`@`) to 62 (`~`).
+pub struct Phred64Table;
+
+impl PhredQualityTable for Phred64Table {
+    const OFFSET: u8 = 64;
+    const MAX_SCORE: u8 = 62;
+}
+
+/// The Phred+33 encoding binned into the eight levels used by Illumina instruments.
+///
+/// The bins are 0-2 (represented by 2), 3-9 (6), 10-19 (15), 20-24 (22), 25-29 (27), 30-34 (33), 35-39 (37) and 40-93 (40).
+pub struct Illumina8LevelPhred33Table;
+
+impl PhredQualityTable for Illumina8LevelPhred33Table {
+    const OFFSET: u8 = 33;
+    const MAX_SCORE: u8 = 93;
+    const BIN_LOWER_BOUNDS: &[u8] = &[0, 3, 10, 20, 25, 30, 35, 40];
+    const BIN_SCORES: &[u8] = &[2, 6, 15, 22, 27, 33, 37, 40];
+}
+
+/// The Phred+33 encoding binned into the four levels used by Illumina NovaSeq instruments.
+///
+/// The bins are 0-2 (represented by 2), 3-14 (12), 15-30 (23) and 31-93 (37).
+pub struct Illumina4LevelPhred33Table;
+
+impl PhredQualityTable for Illumina4LevelPhred33Table {
+    const OFFSET: u8 = 33;
+    const MAX_SCORE: u8 = 93;
+    const BIN_LOWER_BOUNDS: &[u8] = &[0, 3, 15, 31];
+    const BIN_SCORES: &[u8] = &[2, 12, 23, 37];
+}
+
+/// A character of a Phred quality alphabet.
+#[derive(RefCast)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct GenericPhredCharacter<Table: PhredQualityTable> {
+    character: u8,
+    phantom_data: PhantomData<Table>,
+}
+
+/// A Phred quality alphabet.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericPhredAlphabet<Table: PhredQualityTable> {
+    phantom_data: PhantomData<Table>,
+}
+
+/// The lossless Phred+33 alphabet.
+pub type Phred33Alphabet = GenericPhredAlphabet<Phred33Table>;
+/// A character of the lossless Phred+33 alphabet.
+pub type Phred33Character = GenericPhredCharacter<Phred33Table>;
+/// The lossless Phred+64 alphabet.
+pub type Phred64Alphabet = GenericPhredAlphabet<Phred64Table>;
+/// A character of the lossless Phred+64 alphabet.
+pub type Phred64Character = GenericPhredCharacter<Phred64Table>;
+/// The Phred+33 alphabet binned into eight levels.
+pub type Illumina8LevelPhred33Alphabet = GenericPhredAlphabet<Illumina8LevelPhred33Table>;
+/// A character of the Phred+33 alphabet binned into eight levels.
+pub type Illumina8LevelPhred33Character = GenericPhredCharacter<Illumina8LevelPhred33Table>;
+/// The Phred+33 alphabet binned into four levels.
+pub type Illumina4LevelPhred33Alphabet = GenericPhredAlphabet<Illumina4LevelPhred33Table>;
+/// A character of the Phred+33 alphabet binned into four levels.
+pub type Illumina4LevelPhred33Character = GenericPhredCharacter<Illumina4LevelPhred33Table>;
+
+impl<Table: PhredQualityTable> From<GenericPhredCharacter<Table>> for u8 {
+    fn from(character: GenericPhredCharacter<Table>) -> u8 {
+        Table::character_to_ascii(character.character)
+    }
+}
+
+impl<Table: PhredQualityTable> From<GenericPhredCharacter<Table>> for char {
+    fn from(character: GenericPhredCharacter<Table>) -> Self {
+        u8::from(character).into()
+    }
+}
+
+impl<Table: PhredQualityTable> TryFrom<u8> for GenericPhredCharacter<Table> {
+    type Error = ();
+
+    fn try_from(ascii: u8) -> Result<Self, Self::Error> {
+        if let Some(character) = Table::ascii_to_character(ascii) {
+            Ok(Self {
+                character,
+                phantom_data: PhantomData,
+            })
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<Table: PhredQualityTable> TryFrom<char> for GenericPhredCharacter<Table> {
+    type Error = ();
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        u8::try_from(character).map_err(|_| ())?.try_into()
+    }
+}
+
+impl<Table: PhredQualityTable> AlphabetCharacter for GenericPhredCharacter<Table> {
+    const ALPHABET_SIZE: u8 = Table::ALPHABET_SIZE;
+
+    fn index(&self) -> u8 {
+        self.character
+    }
+
+    fn from_index(index: u8) -> Result<Self, AlphabetError> {
+        if index < Self::ALPHABET_SIZE {
+            Ok(Self {
+                character: index,
+                phantom_data: PhantomData,
+            })
+        } else {
+            Err(AlphabetError::IndexNotPartOfAlphabet { index })
+        }
+    }
+
+    fn from_index_ref(index: u8) -> Result<&'static Self, AlphabetError> {
+        if index < Self::ALPHABET_SIZE {
+            Ok(Self::ref_cast(&U8_TABLE[usize::from(index)]))
+        } else {
+            Err(AlphabetError::IndexNotPartOfAlphabet { index })
+        }
+    }
+}
+
+impl<Table: PhredQualityTable> PhredQualityCharacter for GenericPhredCharacter<Table> {
+    fn phred_score(&self) -> u8 {
+        Table::character_to_score(self.character)
+    }
+
+    fn from_phred_score(score: u8) -> Self {
+        Self {
+            character: Table::score_to_character(score),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<Table: PhredQualityTable> Alphabet for GenericPhredAlphabet<Table> {
+    type CharacterType = GenericPhredCharacter<Table>;
+}
+
+impl<Table: PhredQualityTable> Display for GenericPhredCharacter<Table> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl<Table: PhredQualityTable> Debug for GenericPhredCharacter<Table> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenericPhredCharacter")
+            .field("score", &self.phred_score())
+            .finish()
+    }
+}
+
+impl<Table: PhredQualityTable> Clone for GenericPhredCharacter<Table> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Table: PhredQualityTable> Copy for GenericPhredCharacter<Table> {}
+
+impl<Table: PhredQualityTable> Eq for GenericPhredCharacter<Table> {}
+
+impl<Table: PhredQualityTable> PartialEq for GenericPhredCharacter<Table> {
+    fn eq(&self, other: &Self) -> bool {
+        self.character == other.character
+    }
+}
+
+impl<Table: PhredQualityTable> Ord for GenericPhredCharacter<Table> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.character.cmp(&other.character)
+    }
+}
+
+impl<Table: PhredQualityTable> PartialOrd for GenericPhredCharacter<Table> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Table: PhredQualityTable> Hash for GenericPhredCharacter<Table> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.character.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::phred_quality_alphabet::{
+        GenericPhredCharacter, Illumina4LevelPhred33Alphabet, Illumina4LevelPhred33Table,
+        Illumina8LevelPhred33Alphabet, Illumina8LevelPhred33Character, Illumina8LevelPhred33Table,
+        Phred33Alphabet, Phred33Character, Phred33Table, Phred64Character, Phred64Table,
+        PhredQualityTable,
+    };
+    use crate::implementation::bit_vec_sequence::{alphabet_character_bit_width, BitVectorGenome};
+    use crate::implementation::bit_vec_sequence_store::BitVectorSequenceStore;
+    use crate::interface::alphabet::{Alphabet, PhredQualityCharacter};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::interface::sequence_store::SequenceStore;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_phred33_conversion() {
+        for ascii in 0u8..=255u8 {
+            if (b'!'..=b'~').contains(&ascii) {
+                let character = Phred33Character::try_from(ascii).unwrap_or_else(|_| {
+                    panic!("character {ascii} was expected to be valid, but is not")
+                });
+                assert_eq!(u8::from(character), ascii);
+                assert_eq!(character.phred_score(), ascii - 33);
+            } else {
+                assert!(Phred33Character::try_from(ascii).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_phred64_conversion() {
+        for ascii in 0u8..=255u8 {
+            if (b'@'..=b'~').contains(&ascii) {
+                let character = Phred64Character::try_from(ascii).unwrap();
+                assert_eq!(u8::from(character), ascii);
+                assert_eq!(character.phred_score(), ascii - 64);
+            } else {
+                assert!(Phred64Character::try_from(ascii).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_binning() {
+        let expected = [
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 6),
+            (9, 6),
+            (10, 15),
+            (19, 15),
+            (20, 22),
+            (24, 22),
+            (25, 27),
+            (30, 33),
+            (35, 37),
+            (39, 37),
+            (40, 40),
+            (93, 40),
+        ];
+        for (score, binned_score) in expected {
+            let character = Illumina8LevelPhred33Character::try_from(score + 33).unwrap();
+            assert_eq!(character.phred_score(), binned_score);
+            assert_eq!(u8::from(character), binned_score + 33);
+            assert_eq!(
+                Illumina8LevelPhred33Character::from_phred_score(score),
+                character
+            );
+        }
+        assert_eq!(
+            Illumina8LevelPhred33Character::from_phred_score(200).phred_score(),
+            40
+        );
+    }
+
+    fn assert_round_trip<Table: PhredQualityTable>() {
+        for ascii in 0u8..=255u8 {
+            if let Ok(character) = GenericPhredCharacter::<Table>::try_from(ascii) {
+                let printed = u8::from(character);
+                assert_eq!(
+                    GenericPhredCharacter::<Table>::try_from(printed),
+                    Ok(character),
+                    "character {ascii} prints as {printed}, which does not parse back"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip::<Phred33Table>();
+        assert_round_trip::<Phred64Table>();
+        assert_round_trip::<Illumina8LevelPhred33Table>();
+        assert_round_trip::<Illumina4LevelPhred33Table>();
+    }
+
+    #[test]
+    fn test_bit_width() {
+        assert_eq!(alphabet_character_bit_width(Phred33Alphabet::SIZE), 7);
+        assert_eq!(
+            alphabet_character_bit_width(Illumina8LevelPhred33Alphabet::SIZE),
+            3
+        );
+        assert_eq!(
+            alphabet_character_bit_width(Illumina4LevelPhred33Alphabet::SIZE),
+            2
+        );
+    }
+
+    #[test]
+    fn test_store() {
+        let mut store = BitVectorSequenceStore::<Illumina4LevelPhred33Alphabet>::new();
+        let handle1 = store.add_from_slice_u8(b"!#5?I").unwrap();
+        let handle2 = store.add_from_slice_u8(b"FFF:,").unwrap();
+        assert_eq!(store.get(&handle1).as_string(), "##88F");
+        assert_eq!(store.get(&handle2).as_string(), "FFF8-");
+        assert!(store.add_from_slice_u8(b"FF F").is_err());
+    }
+
+    #[test]
+    fn test_error_probability() {
+        let character = Phred33Character::from_phred_score(20);
+        assert!((character.error_probability() - 0.01).abs() < 1e-12);
+        let character = Phred33Character::from_phred_score(0);
+        assert!((character.error_probability() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quality_statistics() {
+        let qualities = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"+5?").unwrap();
+        assert_eq!(qualities.mean_phred_score(), Some(20.0));
+        assert!((qualities.expected_errors() - 0.111).abs() < 1e-12);
+        assert!((qualities.mean_error_probability().unwrap() - 0.037).abs() < 1e-12);
+
+        let empty = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"").unwrap();
+        assert_eq!(empty.mean_phred_score(), None);
+        assert_eq!(empty.expected_errors(), 0.0);
+        assert_eq!(empty.mean_error_probability(), None);
+    }
+
+    #[test]
+    fn test_quality_trim_range() {
+        let qualities = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"##??????##").unwrap();
+        assert_eq!(qualities.quality_trim_range(20), 2..8);
+        let qualities = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"?#??5?#+").unwrap();
+        assert_eq!(qualities.quality_trim_range(20), 0..6);
+        let qualities = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"####").unwrap();
+        assert_eq!(qualities.quality_trim_range(20), 4..4);
+        let qualities = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(b"").unwrap();
+        assert_eq!(qualities.quality_trim_range(20), 0..0);
+    }
+}
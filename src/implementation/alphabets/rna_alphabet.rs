@@ -1,13 +1,30 @@
 //! The RNA alphabet, consisting of characters A, C, G and U.
 
 use crate::impl_generic_alphabet;
+use crate::interface::alphabet::NucleotideAlphabet;
 
-impl_generic_alphabet!("RNA alphabet", RnaAlphabet, RnaCharacter, b"ACGU", b"UGCA");
+impl_generic_alphabet!(
+    "RNA alphabet",
+    RnaAlphabet,
+    RnaCharacter,
+    b"ACGU",
+    b"UGCA",
+    PURINE_CHARACTERS = b"AG",
+    PYRIMIDINE_CHARACTERS = b"CU",
+    STRONG_CHARACTERS = b"CG",
+    WEAK_CHARACTERS = b"AU",
+);
+
+impl NucleotideAlphabet for RnaAlphabet {}
 
 #[cfg(test)]
 mod tests {
-    use crate::implementation::alphabets::rna_alphabet::RnaCharacter;
+    use crate::implementation::alphabets::rna_alphabet::{RnaAlphabet, RnaCharacter};
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::ComplementableAlphabetCharacter;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
     use std::convert::TryFrom;
+    use traitsequence::interface::Sequence;
 
     #[test]
     fn test_rna_alphabet_conversion() {
@@ -32,4 +49,22 @@ mod tests {
             assert_eq!(format!("{character}"), format!("{rna_character}"));
         }
     }
+
+    #[test]
+    fn test_complement() {
+        for (ascii, complement_ascii) in b"ACGU".iter().zip(b"UGCA") {
+            let character = RnaCharacter::try_from(*ascii).unwrap();
+            assert_eq!(u8::from(character.complement()), *complement_ascii);
+        }
+
+        let genome = VectorGenome::<RnaAlphabet>::from_slice_u8(b"UUGCA").unwrap();
+        let reverse_complement: VectorGenome<RnaAlphabet> =
+            genome.convert_with_reverse_complement();
+        assert_eq!(reverse_complement.as_string(), "UGCAA");
+        assert!(genome
+            .reverse_complement_iter()
+            .eq(reverse_complement.iter().cloned()));
+        assert!(!genome.is_canonical());
+        assert!(reverse_complement.is_canonical());
+    }
 }
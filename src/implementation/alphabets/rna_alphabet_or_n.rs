@@ -1,6 +1,9 @@
 //! The RNA alphabet including N, consisting of characters A, C, G, U, and N.
+//!
+//! The [canonical order](crate::interface::alphabet::AlphabetCharacter::canonical_rank) is A, C, G, U, N, i.e. N is ordered last.
 
 use crate::impl_generic_alphabet;
+use crate::interface::alphabet::NucleotideAlphabet;
 
 impl_generic_alphabet!(
     "RNA alphabet including N",
@@ -8,12 +11,24 @@ impl_generic_alphabet!(
     RnaCharacterOrN,
     b"ACGNU",
     b"UGCNA",
+    AMBIGUOUS_CHARACTERS = b"N",
+    PURINE_CHARACTERS = b"AG",
+    PYRIMIDINE_CHARACTERS = b"CU",
+    STRONG_CHARACTERS = b"CG",
+    WEAK_CHARACTERS = b"AU",
+    CANONICAL_ORDER = b"ACGUN",
 );
 
+impl NucleotideAlphabet for RnaAlphabetOrN {}
+
 #[cfg(test)]
 mod tests {
-    use crate::implementation::alphabets::rna_alphabet_or_n::RnaCharacterOrN;
+    use crate::implementation::alphabets::rna_alphabet_or_n::{RnaAlphabetOrN, RnaCharacterOrN};
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::ComplementableAlphabetCharacter;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
     use std::convert::TryFrom;
+    use traitsequence::interface::Sequence;
 
     #[test]
     fn test_rna_alphabet_conversion() {
@@ -38,4 +53,22 @@ mod tests {
             assert_eq!(format!("{character}"), format!("{rna_character}"));
         }
     }
+
+    #[test]
+    fn test_complement() {
+        for (ascii, complement_ascii) in b"ACGNU".iter().zip(b"UGCNA") {
+            let character = RnaCharacterOrN::try_from(*ascii).unwrap();
+            assert_eq!(u8::from(character.complement()), *complement_ascii);
+        }
+
+        let genome = VectorGenome::<RnaAlphabetOrN>::from_slice_u8(b"UNGCA").unwrap();
+        let reverse_complement: VectorGenome<RnaAlphabetOrN> =
+            genome.convert_with_reverse_complement();
+        assert_eq!(reverse_complement.as_string(), "UGCNA");
+        assert!(genome
+            .reverse_complement_iter()
+            .eq(reverse_complement.iter().cloned()));
+        assert!(!genome.is_canonical());
+        assert!(reverse_complement.is_canonical());
+    }
 }
@@ -2,9 +2,12 @@
 //!
 //! This version omits the character T, to avoid the complement of A being ambiguous (T or U).
 //!
+//! The [canonical order](crate::interface::alphabet::AlphabetCharacter::canonical_rank) starts with the unambiguous characters A, C, G, U, followed by the two-base codes K, M, R, S, W, Y, the three-base codes B, D, H, V, and finally N.
+//!
 //! [1]: https://web.archive.org/web/20110811073845/http://www.dna.affrc.go.jp/misc/MPsrch/InfoIUPAC.html
 
 use crate::impl_generic_alphabet;
+use crate::interface::alphabet::NucleotideAlphabet;
 
 impl_generic_alphabet!(
     "RNA IUPAC nucleic acid alphabet",
@@ -12,4 +15,43 @@ impl_generic_alphabet!(
     RnaIupacNucleicAcidCharacter,
     b"ABCDGHKMNRSUVWY",
     b"UVGHCDMKNYWABSR",
+    AMBIGUOUS_CHARACTERS = b"BDHKMNRSVWY",
+    PURINE_CHARACTERS = b"AGR",
+    PYRIMIDINE_CHARACTERS = b"CUY",
+    STRONG_CHARACTERS = b"CGS",
+    WEAK_CHARACTERS = b"AUW",
+    CANONICAL_ORDER = b"ACGUKMRSWYBDHVN",
 );
+
+impl NucleotideAlphabet for RnaIupacNucleicAcidAlphabet {}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::rna_iupac_nucleic_acid_alphabet::{
+        RnaIupacNucleicAcidAlphabet, RnaIupacNucleicAcidCharacter,
+    };
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::ComplementableAlphabetCharacter;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use std::convert::TryFrom;
+    use traitsequence::interface::Sequence;
+
+    #[test]
+    fn test_complement() {
+        for (ascii, complement_ascii) in b"ABCDGHKMNRSUVWY".iter().zip(b"UVGHCDMKNYWABSR") {
+            let character = RnaIupacNucleicAcidCharacter::try_from(*ascii).unwrap();
+            assert_eq!(u8::from(character.complement()), *complement_ascii);
+        }
+
+        let genome = VectorGenome::<RnaIupacNucleicAcidAlphabet>::from_slice_u8(b"URYKM").unwrap();
+        let reverse_complement: VectorGenome<RnaIupacNucleicAcidAlphabet> =
+            genome.convert_with_reverse_complement();
+        assert_eq!(reverse_complement.as_string(), "KMRYA");
+        assert!(genome
+            .reverse_complement_iter()
+            .eq(reverse_complement.iter().cloned()));
+        // Unambiguous characters are ordered before ambiguous ones, independent of their index.
+        assert!(genome.is_canonical());
+        assert!(!reverse_complement.is_canonical());
+    }
+}
@@ -9,6 +9,8 @@ use std::ops::{Index, IndexMut, Range};
 use traitsequence::interface::{OwnedSequence, Sequence, SequenceMut};
 
 /// A k-mer stored as array of plain characters.
+///
+/// K-mers are ordered lexicographically by the [index](crate::interface::alphabet::AlphabetCharacter::index) of their characters, see [Kmer::to_index].
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct ArrayKmer<const K: usize, AlphabetType: Alphabet> {
     array: [AlphabetType::CharacterType; K],
@@ -19,14 +21,32 @@ impl<const K: usize, AlphabetType: Alphabet> Kmer<K, AlphabetType, SliceSubGenom
 {
 }
 
+impl<const K: usize, AlphabetType: Alphabet> Kmer<K, AlphabetType, SliceSubGenome<AlphabetType>>
+    for ArrayKmer<K, AlphabetType>
+{
+}
+
 impl<const K: usize, AlphabetType: Alphabet>
     OwnedKmer<K, AlphabetType, SliceSubGenome<AlphabetType>> for ArrayKmer<K, AlphabetType>
 {
     fn successor(&self, successor: <AlphabetType as Alphabet>::CharacterType) -> Self {
         let mut array = self.array.clone();
 
-        array.rotate_left(1);
-        array[array.len() - 1] = successor;
+        if K > 0 {
+            array.rotate_left(1);
+            array[K - 1] = successor;
+        }
+
+        Self { array }
+    }
+
+    fn predecessor(&self, predecessor: <AlphabetType as Alphabet>::CharacterType) -> Self {
+        let mut array = self.array.clone();
+
+        if K > 0 {
+            array.rotate_right(1);
+            array[0] = predecessor;
+        }
 
         Self { array }
     }
@@ -141,9 +161,15 @@ impl<const K: usize, AlphabetType: Alphabet> IndexMut<usize> for ArrayKmer<K, Al
 mod tests {
     use traitsequence::interface::Sequence;
 
+    use crate::test_util::random_ascii;
     use crate::{
         implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter},
-        interface::{k_mer::OwnedKmer, sequence::OwnedGenomeSequence},
+        implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN,
+        implementation::vec_sequence::VectorGenome,
+        interface::{
+            k_mer::{Kmer, OwnedKmer},
+            sequence::{GenomeSequence, OwnedGenomeSequence},
+        },
     };
 
     use super::ArrayKmer;
@@ -206,4 +232,99 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn cloned_k_mer_iter() {
+        let ascii = random_ascii(200, b"ACGTN", 1);
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let k_mers: Vec<_> = genome
+            .cloned_k_mer_iter::<7, ArrayKmer<7, DnaAlphabetOrN>>()
+            .collect();
+        assert_eq!(k_mers.len(), ascii.len() - 6);
+        for (offset, k_mer) in k_mers.iter().enumerate() {
+            assert_eq!(k_mer.clone_as_vec(), &ascii[offset..offset + 7]);
+        }
+
+        // Sequences shorter than k have no k-mers.
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<4, ArrayKmer<4, DnaAlphabet>>()
+                .count(),
+            0
+        );
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<3, ArrayKmer<3, DnaAlphabet>>()
+                .count(),
+            1
+        );
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<0, ArrayKmer<0, DnaAlphabet>>()
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn predecessor() {
+        let kmer = ArrayKmer::<4, DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(
+            kmer.predecessor(b'G'.try_into().unwrap()).as_string(),
+            "GACG"
+        );
+        assert_eq!(
+            kmer.predecessor(b'G'.try_into().unwrap())
+                .successor(b'T'.try_into().unwrap()),
+            kmer
+        );
+    }
+
+    #[test]
+    fn index() {
+        let kmer = ArrayKmer::<4, DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(kmer.to_index(), 27);
+        assert_eq!(ArrayKmer::<4, DnaAlphabet>::from_index(27), kmer);
+        assert_eq!(
+            ArrayKmer::<3, DnaAlphabet>::from_index(63).as_string(),
+            "TTT"
+        );
+        // The ranks of DNA 64-mers use all bits of a u128.
+        let kmer = ArrayKmer::<64, DnaAlphabet>::from_index(u128::MAX);
+        assert_eq!(kmer.to_index(), u128::MAX);
+        assert!(kmer.iter().all(|character| character.to_string() == "T"));
+    }
+
+    #[test]
+    fn index_and_canonical_index() {
+        crate::impl_generic_alphabet!(
+            "DNA alphabet including N, with N ordered last",
+            NLastAlphabet,
+            NLastCharacter,
+            b"ACGNT",
+            b"TGCNA",
+            CANONICAL_ORDER = b"ACGTN",
+        );
+
+        // For DNA, the canonical rank of each character is its index.
+        let kmer = ArrayKmer::<4, DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(kmer.to_canonical_index(), kmer.to_index());
+
+        // Digits by index are A=0, C=1, G=2, N=3, T=4, and by canonical rank T=3, N=4.
+        let n = ArrayKmer::<2, NLastAlphabet>::from_slice_u8(b"AN").unwrap();
+        let t = ArrayKmer::<2, NLastAlphabet>::from_slice_u8(b"AT").unwrap();
+        assert_eq!((n.to_index(), t.to_index()), (3, 4));
+        assert_eq!((n.to_canonical_index(), t.to_canonical_index()), (4, 3));
+        assert_eq!(ArrayKmer::<2, NLastAlphabet>::from_index(3), n);
+
+        // Ord follows the index, and canonical_cmp follows the canonical rank.
+        assert!(n < t);
+        assert!(n.canonical_cmp(&t).is_gt());
+        assert_eq!(n.cmp(&t), n.to_index().cmp(&t.to_index()));
+        assert_eq!(
+            n.canonical_cmp(&t),
+            n.to_canonical_index().cmp(&t.to_canonical_index())
+        );
+    }
 }
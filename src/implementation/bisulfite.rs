@@ -0,0 +1,329 @@
+//! In-silico bisulfite conversion of genome sequences.
+//!
+//! Bisulfite treatment converts unmethylated cytosines into uracils, which are sequenced as thymines.
+//! Methylated cytosines are protected from the conversion.
+//! On the reverse strand, the conversion shows as G→A in the coordinates of the forward strand.
+//!
+//! The [`BisulfiteConvertedSequence`] is a view of a genome sequence under such a conversion.
+//! It does not copy the sequence, but converts each character when it is accessed.
+//! Hence, both converted strands of a reference can be indexed while storing the reference only once.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::alphabets::dna_methylation_alphabet::DnaMethylationAlphabet;
+//! use compact_genome::implementation::bisulfite::{BisulfiteConvertedSequence, CToTConversion, GToAConversion};
+//! use compact_genome::implementation::vec_sequence::VectorGenome;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//!
+//! let reference = VectorGenome::<DnaMethylationAlphabet>::from_slice_u8(b"ACmGChG").unwrap();
+//! let forward = BisulfiteConvertedSequence::<CToTConversion, DnaMethylationAlphabet, _>::new(&reference);
+//! assert_eq!(forward.as_string(), "ATCGTCG");
+//! let reverse = BisulfiteConvertedSequence::<GToAConversion, DnaMethylationAlphabet, _>::new(&reference);
+//! assert_eq!(reverse.as_string(), "ACCACCA");
+//!
+//! let forward: VectorGenome<DnaAlphabet> = forward[1..4].convert();
+//! assert_eq!(forward.as_string(), "TCG");
+//! ```
+
+use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+use crate::implementation::alphabets::dna_methylation_alphabet::DnaMethylationAlphabet;
+use crate::interface::alphabet::{Alphabet, AlphabetCharacter};
+use crate::interface::sequence::GenomeSequence;
+use ref_cast::RefCast;
+use std::iter::Map;
+use std::marker::PhantomData;
+use std::ops::{Index, Range};
+use traitsequence::interface::Sequence;
+
+/// A bisulfite conversion from characters of the source alphabet to characters of the [target alphabet](BisulfiteConversion::TargetAlphabet).
+pub trait BisulfiteConversion<SourceAlphabet: Alphabet>: 'static {
+    /// The alphabet of the converted sequence.
+    type TargetAlphabet: Alphabet;
+
+    /// Converts the given character.
+    fn convert(
+        character: &SourceAlphabet::CharacterType,
+    ) -> &'static <Self::TargetAlphabet as Alphabet>::CharacterType;
+}
+
+/// The bisulfite conversion of the forward strand, converting each unmethylated C into a T.
+pub struct CToTConversion;
+
+/// The bisulfite conversion of the reverse strand, converting each G into an A.
+pub struct GToAConversion;
+
+/// Converts the given character by mapping its ASCII representation.
+fn convert_ascii<SourceAlphabet: Alphabet, TargetAlphabet: Alphabet>(
+    character: &SourceAlphabet::CharacterType,
+    mapping: impl FnOnce(u8) -> u8,
+) -> &'static TargetAlphabet::CharacterType {
+    let ascii = mapping(SourceAlphabet::character_to_ascii(character.clone()));
+    let index = TargetAlphabet::ascii_to_character(ascii)
+        .unwrap_or_else(|_| {
+            panic!(
+                "converted character {} is not part of the target alphabet",
+                char::from(ascii)
+            )
+        })
+        .index();
+    TargetAlphabet::CharacterType::from_index_ref(index).unwrap()
+}
+
+impl BisulfiteConversion<DnaAlphabet> for CToTConversion {
+    type TargetAlphabet = DnaAlphabet;
+
+    fn convert(
+        character: &<DnaAlphabet as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabet as Alphabet>::CharacterType {
+        convert_ascii::<DnaAlphabet, DnaAlphabet>(character, |ascii| match ascii {
+            b'C' => b'T',
+            ascii => ascii,
+        })
+    }
+}
+
+impl BisulfiteConversion<DnaAlphabet> for GToAConversion {
+    type TargetAlphabet = DnaAlphabet;
+
+    fn convert(
+        character: &<DnaAlphabet as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabet as Alphabet>::CharacterType {
+        convert_ascii::<DnaAlphabet, DnaAlphabet>(character, |ascii| match ascii {
+            b'G' => b'A',
+            ascii => ascii,
+        })
+    }
+}
+
+impl BisulfiteConversion<DnaAlphabetOrN> for CToTConversion {
+    type TargetAlphabet = DnaAlphabetOrN;
+
+    fn convert(
+        character: &<DnaAlphabetOrN as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabetOrN as Alphabet>::CharacterType {
+        convert_ascii::<DnaAlphabetOrN, DnaAlphabetOrN>(character, |ascii| match ascii {
+            b'C' => b'T',
+            ascii => ascii,
+        })
+    }
+}
+
+impl BisulfiteConversion<DnaAlphabetOrN> for GToAConversion {
+    type TargetAlphabet = DnaAlphabetOrN;
+
+    fn convert(
+        character: &<DnaAlphabetOrN as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabetOrN as Alphabet>::CharacterType {
+        convert_ascii::<DnaAlphabetOrN, DnaAlphabetOrN>(character, |ascii| match ascii {
+            b'G' => b'A',
+            ascii => ascii,
+        })
+    }
+}
+
+/// Methylated cytosines are protected from the conversion and are read as C.
+impl BisulfiteConversion<DnaMethylationAlphabet> for CToTConversion {
+    type TargetAlphabet = DnaAlphabet;
+
+    fn convert(
+        character: &<DnaMethylationAlphabet as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabet as Alphabet>::CharacterType {
+        convert_ascii::<DnaMethylationAlphabet, DnaAlphabet>(character, |ascii| match ascii {
+            b'C' => b'T',
+            b'm' | b'h' => b'C',
+            ascii => ascii,
+        })
+    }
+}
+
+/// The methylation state of the reverse strand is not encoded in the alphabet, so each G is converted.
+/// Methylated cytosines of the forward strand are read as C.
+impl BisulfiteConversion<DnaMethylationAlphabet> for GToAConversion {
+    type TargetAlphabet = DnaAlphabet;
+
+    fn convert(
+        character: &<DnaMethylationAlphabet as Alphabet>::CharacterType,
+    ) -> &'static <DnaAlphabet as Alphabet>::CharacterType {
+        convert_ascii::<DnaMethylationAlphabet, DnaAlphabet>(character, |ascii| match ascii {
+            b'G' => b'A',
+            b'm' | b'h' => b'C',
+            ascii => ascii,
+        })
+    }
+}
+
+/// A view of a genome subsequence under a bisulfite conversion.
+///
+/// The view is a genome sequence over the [target alphabet](BisulfiteConversion::TargetAlphabet) of the conversion.
+/// Its subsequences are again views of this type.
+#[derive(RefCast)]
+#[repr(transparent)]
+pub struct BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence: ?Sized> {
+    #[trivial]
+    phantom_data: PhantomData<(Conversion, SourceAlphabet)>,
+    source: GenomeSubsequence,
+}
+
+impl<
+        Conversion: BisulfiteConversion<SourceAlphabet>,
+        SourceAlphabet: Alphabet,
+        GenomeSubsequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized,
+    > BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence>
+{
+    /// Creates a converted view of the given genome sequence.
+    pub fn new<SourceSequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized>(
+        sequence: &SourceSequence,
+    ) -> &Self {
+        Self::ref_cast(sequence.as_genome_subsequence())
+    }
+
+    /// Returns the unconverted sequence of this view.
+    pub fn source(&self) -> &GenomeSubsequence {
+        &self.source
+    }
+}
+
+impl<
+        Conversion: BisulfiteConversion<SourceAlphabet>,
+        SourceAlphabet: Alphabet,
+        GenomeSubsequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized,
+    > GenomeSequence<Conversion::TargetAlphabet, Self>
+    for BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence>
+{
+}
+
+impl<
+        Conversion: BisulfiteConversion<SourceAlphabet>,
+        SourceAlphabet: Alphabet,
+        GenomeSubsequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized,
+    > Sequence<<Conversion::TargetAlphabet as Alphabet>::CharacterType, Self>
+    for BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence>
+{
+    type Iterator<'a>
+        = Map<
+        GenomeSubsequence::Iterator<'a>,
+        fn(
+            &'a SourceAlphabet::CharacterType,
+        ) -> &'a <Conversion::TargetAlphabet as Alphabet>::CharacterType,
+    >
+    where
+        Self: 'a,
+        <Conversion::TargetAlphabet as Alphabet>::CharacterType: 'a;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.source.iter().map(Conversion::convert)
+    }
+
+    fn len(&self) -> usize {
+        self.source.len()
+    }
+}
+
+impl<
+        Conversion: BisulfiteConversion<SourceAlphabet>,
+        SourceAlphabet: Alphabet,
+        GenomeSubsequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized,
+    > Index<Range<usize>>
+    for BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence>
+{
+    type Output = Self;
+
+    fn index(&self, index: Range<usize>) -> &Self::Output {
+        Self::ref_cast(&self.source[index])
+    }
+}
+
+impl<
+        Conversion: BisulfiteConversion<SourceAlphabet>,
+        SourceAlphabet: Alphabet,
+        GenomeSubsequence: GenomeSequence<SourceAlphabet, GenomeSubsequence> + ?Sized,
+    > Index<usize> for BisulfiteConvertedSequence<Conversion, SourceAlphabet, GenomeSubsequence>
+{
+    type Output = <Conversion::TargetAlphabet as Alphabet>::CharacterType;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        Conversion::convert(&self.source[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::alphabets::dna_methylation_alphabet::DnaMethylationAlphabet;
+    use crate::implementation::bisulfite::{
+        BisulfiteConvertedSequence, CToTConversion, GToAConversion,
+    };
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use traitsequence::interface::Sequence;
+
+    #[test]
+    fn test_dna_conversion() {
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTCCGA").unwrap();
+        let forward = BisulfiteConvertedSequence::<CToTConversion, DnaAlphabet, _>::new(&genome);
+        let reverse = BisulfiteConvertedSequence::<GToAConversion, DnaAlphabet, _>::new(&genome);
+        assert_eq!(forward.len(), genome.len());
+        assert_eq!(forward.as_string(), "ATGTTTGA");
+        assert_eq!(reverse.as_string(), "ACATCCAA");
+        assert_eq!(forward[1..5].as_string(), "TGTT");
+        assert_eq!(forward[1..5][2..4].as_string(), "TT");
+        assert_eq!(
+            forward
+                .iter()
+                .rev()
+                .cloned()
+                .collect::<VectorGenome<DnaAlphabet>>()
+                .as_string(),
+            "AGTTTGTA"
+        );
+        assert_eq!(forward.source().as_string(), "ACGTCCGA");
+        assert_eq!(
+            forward
+                .reverse_complement_iter()
+                .collect::<VectorGenome<DnaAlphabet>>()
+                .as_string(),
+            "TCAAACAT"
+        );
+    }
+
+    #[test]
+    fn test_n_conversion() {
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"NCGN").unwrap();
+        assert_eq!(
+            BisulfiteConvertedSequence::<CToTConversion, DnaAlphabetOrN, _>::new(&genome)
+                .as_string(),
+            "NTGN"
+        );
+        assert_eq!(
+            BisulfiteConvertedSequence::<GToAConversion, DnaAlphabetOrN, _>::new(&genome)
+                .as_string(),
+            "NCAN"
+        );
+    }
+
+    #[test]
+    fn test_methylation_conversion() {
+        let genome = BitVectorGenome::<DnaMethylationAlphabet>::from_slice_u8(b"CmhGTAC").unwrap();
+        let forward =
+            BisulfiteConvertedSequence::<CToTConversion, DnaMethylationAlphabet, _>::new(&genome);
+        assert_eq!(forward.as_string(), "TCCGTAT");
+        let converted: BitVectorGenome<DnaAlphabet> = forward.iter().cloned().collect();
+        assert_eq!(converted.as_string(), "TCCGTAT");
+        // The methylation alphabet has no complement, but the converted view has.
+        assert_eq!(
+            forward
+                .reverse_complement_iter()
+                .collect::<VectorGenome<DnaAlphabet>>()
+                .as_string(),
+            "ATACGGA"
+        );
+        assert_eq!(
+            BisulfiteConvertedSequence::<GToAConversion, DnaMethylationAlphabet, _>::new(&genome)
+                .as_string(),
+            "CCCATAC"
+        );
+    }
+}
@@ -8,35 +8,108 @@ use crate::interface::k_mer::{Kmer, OwnedKmer};
 use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
 use bitvec::array::BitArray;
 use bitvec::field::BitField;
+use bitvec::slice::BitSlice;
 pub use bitvec::store::BitStore;
 pub use bitvec::view::BitView;
 pub use bitvec::view::BitViewSized;
 use ref_cast::RefCast;
 use std::marker::PhantomData;
+use std::mem;
 use std::ops::{Index, Range};
 use traitsequence::interface::{OwnedSequence, Sequence};
 
 /// A k-mer stored as array of minimum-bit characters.
+///
+/// The bit array can be a single word like the default `usize`, or an array of words like `[u64; 2]`, see [MultiWordBitArrayKmer].
+/// It must be large enough to hold `K` characters, otherwise the k-mer type fails to compile when it is constructed.
+///
+/// ```rust,compile_fail
+/// use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+/// use compact_genome::implementation::bit_array_kmer::BitArrayKmer;
+/// use compact_genome::interface::sequence::OwnedGenomeSequence;
+///
+/// // 33 DNA characters take 66 bits, which do not fit into a u64.
+/// let k_mer = BitArrayKmer::<33, DnaAlphabet, u64>::from_slice_u8(&[b'A'; 33]);
+/// ```
 #[derive(Debug)]
 pub struct BitArrayKmer<const K: usize, AlphabetType: Alphabet, BitArrayType = usize>
 where
-    BitArrayType: BitViewSized + BitStore,
+    BitArrayType: BitViewSized,
 {
     phantom_data: PhantomData<AlphabetType>,
     array: BitArray<BitArrayType>,
 }
 
+/// A [BitArrayKmer] stored in an array of `WORDS` 64-bit words.
+///
+/// For example, `MultiWordBitArrayKmer<63, DnaAlphabet, 2>` stores 63 DNA characters in 126 bits.
+/// Shifting such k-mers, e.g. to compute a [successor](OwnedKmer::successor), processes one word at a time.
+pub type MultiWordBitArrayKmer<const K: usize, AlphabetType, const WORDS: usize> =
+    BitArrayKmer<K, AlphabetType, [u64; WORDS]>;
+
 impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore>
     Kmer<K, AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType>>
     for BitVectorSubGenome<AlphabetType, BitArrayType>
 {
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > OwnedKmer<K, AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType>>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    Kmer<K, AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
+    for BitArrayKmer<K, AlphabetType, BitArrayType>
+{
+}
+
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    BitArrayKmer<K, AlphabetType, BitArrayType>
+{
+    /// Fails to compile if the bit array cannot hold `K` characters.
+    const ASSERT_CAPACITY: () = assert!(
+        K * alphabet_character_bit_width(AlphabetType::SIZE) <= mem::size_of::<BitArrayType>() * 8,
+        "the bit array of the k-mer is too small to hold K characters"
+    );
+
+    /// Returns the underlying bit array.
+    ///
+    /// The characters are stored with their minimum bit width, starting at the least significant bits.
+    /// The bits after the last character are zero.
+    pub fn as_bit_array(&self) -> &BitArray<BitArrayType> {
+        &self.array
+    }
+
+    /// Returns the packed characters as integer, with the first character in the least significant bits.
+    ///
+    /// Panics if the characters take more than 128 bits.
+    pub fn packed_bits(&self) -> u128 {
+        let limit = K * alphabet_character_bit_width(AlphabetType::SIZE);
+        if limit == 0 {
+            0
+        } else {
+            self.array[..limit].load_le()
+        }
+    }
+
+    /// Constructs a k-mer from characters packed as by [packed_bits](Self::packed_bits).
+    ///
+    /// Panics if the characters take more than 128 bits, or do not fit into the bit array.
+    /// Bits after the last character are ignored, but invalid character indices are not checked.
+    pub fn from_packed_bits(packed_bits: u128) -> Self {
+        let () = Self::ASSERT_CAPACITY;
+        let limit = K * alphabet_character_bit_width(AlphabetType::SIZE);
+        let mut array: BitArray<BitArrayType> =
+            <BitArrayType as BitViewSized>::ZERO.into_bitarray();
+        if limit > 0 {
+            array[..limit].store_le(packed_bits);
+        }
+
+        Self {
+            phantom_data: Default::default(),
+            array,
+        }
+    }
+}
+
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    OwnedKmer<K, AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn successor(&self, successor: <AlphabetType as Alphabet>::CharacterType) -> Self {
@@ -45,45 +118,77 @@ impl<
         }
 
         let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
-
         let mut array = self.array.clone();
-        array.shift_left(bit_width);
+        push_back_packed(&mut array[..K * bit_width], successor.index(), bit_width);
 
-        let offset = (K - 1) * bit_width;
-        let limit = K * bit_width;
+        Self {
+            phantom_data: Default::default(),
+            array,
+        }
+    }
 
-        array[offset..limit].store(successor.index());
+    fn predecessor(&self, predecessor: <AlphabetType as Alphabet>::CharacterType) -> Self {
+        if K == 0 {
+            return self.clone();
+        }
+
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let mut array = self.array.clone();
+        push_front_packed(&mut array[..K * bit_width], predecessor.index(), bit_width);
 
         Self {
             phantom_data: Default::default(),
             array,
         }
     }
+
+    /// Locates the first differing character by comparing the packed bits word by word, such that only that character needs to be ranked.
+    fn canonical_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let limit = K * bit_width;
+        cmp_packed_canonical::<AlphabetType, _>(
+            &self.array[..limit],
+            &other.array[..limit],
+            bit_width,
+        )
+    }
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > GenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType>>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    GenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
-    fn as_genome_subsequence(&self) -> &BitVectorSubGenome<AlphabetType, BitArrayType> {
+    fn as_genome_subsequence(&self) -> &BitVectorSubGenome<AlphabetType, BitArrayType::Store> {
         BitVectorSubGenome::ref_cast(
             &self.array.as_bitslice()[..K * alphabet_character_bit_width(AlphabetType::SIZE)],
         )
     }
+
+    fn hamming_distance(
+        &self,
+        other: &BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    ) -> usize {
+        self.as_genome_subsequence().hamming_distance(other)
+    }
+
+    fn character_counts(&self) -> Vec<usize> {
+        self.as_genome_subsequence().character_counts()
+    }
+
+    fn mismatch_positions(
+        &self,
+        other: &BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    ) -> Vec<usize> {
+        self.as_genome_subsequence().mismatch_positions(other)
+    }
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > Sequence<AlphabetType::CharacterType, BitVectorSubGenome<AlphabetType, BitArrayType>>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    Sequence<AlphabetType::CharacterType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     type Iterator<'a>
-        = BitVectorSubGenomeIterator<'a, AlphabetType, BitArrayType>
+        = BitVectorSubGenomeIterator<'a, AlphabetType, BitArrayType::Store>
     where
         Self: 'a,
         AlphabetType::CharacterType: 'a;
@@ -97,28 +202,25 @@ impl<
     }
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > OwnedGenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType>>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    OwnedGenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > OwnedSequence<AlphabetType::CharacterType, BitVectorSubGenome<AlphabetType, BitArrayType>>
-    for BitArrayKmer<K, AlphabetType, BitArrayType>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    OwnedSequence<
+        AlphabetType::CharacterType,
+        BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    > for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
     FromIterator<AlphabetType::CharacterType> for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn from_iter<T: IntoIterator<Item = AlphabetType::CharacterType>>(iter: T) -> Self {
+        let () = Self::ASSERT_CAPACITY;
         let mut array: BitArray<BitArrayType> =
             <BitArrayType as BitViewSized>::ZERO.into_bitarray();
         let mut iter = iter.into_iter();
@@ -140,24 +242,18 @@ impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitSto
     }
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > Index<Range<usize>> for BitArrayKmer<K, AlphabetType, BitArrayType>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> Index<Range<usize>>
+    for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
-    type Output = BitVectorSubGenome<AlphabetType, BitArrayType>;
+    type Output = BitVectorSubGenome<AlphabetType, BitArrayType::Store>;
 
     fn index(&self, index: Range<usize>) -> &Self::Output {
         self.as_genome_subsequence().index(index)
     }
 }
 
-impl<
-        const K: usize,
-        AlphabetType: Alphabet,
-        BitArrayType: BitViewSized + BitStore + BitView<Store = BitArrayType>,
-    > Index<usize> for BitArrayKmer<K, AlphabetType, BitArrayType>
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> Index<usize>
+    for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     type Output = AlphabetType::CharacterType;
 
@@ -166,7 +262,7 @@ impl<
     }
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> Clone
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> Clone
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn clone(&self) -> Self {
@@ -177,12 +273,12 @@ impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitSto
     }
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore + Copy> Copy
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + Copy> Copy
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> PartialEq
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> PartialEq
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn eq(&self, other: &Self) -> bool {
@@ -190,12 +286,12 @@ impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitSto
     }
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> Eq
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> Eq
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> PartialOrd
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> PartialOrd
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
@@ -203,15 +299,19 @@ impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitSto
     }
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> Ord
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> Ord
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
+    /// Compares the k-mers lexicographically by the [index](AlphabetCharacter::index) of their characters, see [Kmer::to_index].
+    /// For the order by [canonical rank](AlphabetCharacter::canonical_rank), see [OwnedKmer::canonical_cmp].
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.array.cmp(&other.array)
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let limit = K * bit_width;
+        cmp_packed(&self.array[..limit], &other.array[..limit], bit_width)
     }
 }
 
-impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitStore> core::hash::Hash
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized> core::hash::Hash
     for BitArrayKmer<K, AlphabetType, BitArrayType>
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -219,20 +319,124 @@ impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + BitSto
     }
 }
 
+/// Shifts the characters packed into the given bits towards the front by one character, and stores the given character at the end.
+/// The first character is dropped.
+pub(crate) fn push_back_packed<Store: BitStore>(
+    bits: &mut BitSlice<Store>,
+    character: u8,
+    bit_width: usize,
+) {
+    if bits.len() <= u64::BITS as usize {
+        // Fast path: shift the characters as a single integer.
+        let characters: u64 = bits.load_le();
+        let offset = bits.len() - bit_width;
+        bits.store_le((characters >> bit_width) | (u64::from(character) << offset));
+        return;
+    }
+
+    // Shift one word at a time from the back, carrying the front bits of each word into the previous one.
+    let mut carry = u128::from(character);
+    for chunk in bits.chunks_mut(u64::BITS as usize).rev() {
+        let len = chunk.len();
+        let combined = u128::from(chunk.load_le::<u64>()) | (carry << len);
+        chunk.store_le((combined >> bit_width) as u64);
+        carry = combined & ((1 << bit_width) - 1);
+    }
+}
+
+/// Shifts the characters packed into the given bits towards the back by one character, and stores the given character at the front.
+/// The last character is dropped.
+pub(crate) fn push_front_packed<Store: BitStore>(
+    bits: &mut BitSlice<Store>,
+    character: u8,
+    bit_width: usize,
+) {
+    if bits.len() <= u64::BITS as usize {
+        // Fast path: shift the characters as a single integer.
+        let characters: u64 = bits.load_le();
+        bits.store_le((characters << bit_width) | u64::from(character));
+        return;
+    }
+
+    // Shift one word at a time from the front, carrying the back bits of each word into the next one.
+    let mut carry = u128::from(character);
+    for chunk in bits.chunks_mut(u64::BITS as usize) {
+        let len = chunk.len();
+        let combined = (u128::from(chunk.load_le::<u64>()) << bit_width) | carry;
+        chunk.store_le(combined as u64);
+        carry = combined >> len;
+    }
+}
+
+/// Compares the characters packed into the given bits of equal length lexicographically by their [index](AlphabetCharacter::index).
+pub(crate) fn cmp_packed<Store: BitStore>(
+    bits: &BitSlice<Store>,
+    other: &BitSlice<Store>,
+    bit_width: usize,
+) -> std::cmp::Ordering {
+    first_difference_packed(bits, other, bit_width)
+        .map_or(std::cmp::Ordering::Equal, |(character, other_character)| {
+            character.cmp(&other_character)
+        })
+}
+
+/// Compares the characters packed into the given bits of equal length lexicographically by their [canonical rank](AlphabetCharacter::canonical_rank).
+pub(crate) fn cmp_packed_canonical<AlphabetType: Alphabet, Store: BitStore>(
+    bits: &BitSlice<Store>,
+    other: &BitSlice<Store>,
+    bit_width: usize,
+) -> std::cmp::Ordering {
+    // Only the first differing character decides the order, so it suffices to rank that one.
+    let canonical_rank = |index| {
+        AlphabetType::CharacterType::from_index(index)
+            .expect("packed characters are part of the alphabet")
+            .canonical_rank()
+    };
+    first_difference_packed(bits, other, bit_width).map_or(
+        std::cmp::Ordering::Equal,
+        |(character, other_character)| {
+            canonical_rank(character).cmp(&canonical_rank(other_character))
+        },
+    )
+}
+
+/// Returns the indices of the first pair of differing characters packed into the given bits of equal length, or `None` if all characters are equal.
+fn first_difference_packed<Store: BitStore>(
+    bits: &BitSlice<Store>,
+    other: &BitSlice<Store>,
+    bit_width: usize,
+) -> Option<(u8, u8)> {
+    debug_assert_eq!(bits.len(), other.len());
+
+    let chunks = bits.chunks(u64::BITS as usize);
+    let other_chunks = other.chunks(u64::BITS as usize);
+    for (chunk_index, (chunk, other_chunk)) in chunks.zip(other_chunks).enumerate() {
+        let difference = chunk.load_le::<u64>() ^ other_chunk.load_le::<u64>();
+        if difference != 0 {
+            // The first differing character contains the least significant differing bit.
+            let offset = chunk_index * u64::BITS as usize + difference.trailing_zeros() as usize;
+            let range = offset / bit_width * bit_width..(offset / bit_width + 1) * bit_width;
+            return Some((
+                bits[range.clone()].load_le::<u8>(),
+                other[range].load_le::<u8>(),
+            ));
+        }
+    }
+
+    None
+}
+
 #[cfg(feature = "serde")]
 mod serde {
-    use bitvec::{array::BitArray, store::BitStore, view::BitViewSized};
+    use bitvec::{array::BitArray, view::BitViewSized};
     use serde::{Deserialize, Serialize};
 
     use crate::interface::alphabet::Alphabet;
 
-    use super::BitArrayKmer;
+    use super::{alphabet_character_bit_width, BitArrayKmer};
 
-    impl<
-            const K: usize,
-            AlphabetType: Alphabet,
-            BitArrayType: BitViewSized + BitStore + Serialize,
-        > Serialize for BitArrayKmer<K, AlphabetType, BitArrayType>
+    impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized + Serialize> Serialize
+        for BitArrayKmer<K, AlphabetType, BitArrayType>
     {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
@@ -246,19 +450,30 @@ mod serde {
             'a,
             const K: usize,
             AlphabetType: Alphabet,
-            BitArrayType: BitViewSized + BitStore + Deserialize<'a>,
+            BitArrayType: BitViewSized + Deserialize<'a>,
         > Deserialize<'a> for BitArrayKmer<K, AlphabetType, BitArrayType>
     {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: serde::Deserializer<'a>,
         {
+            let () = Self::ASSERT_CAPACITY;
+            let array = BitArray {
+                _ord: Default::default(),
+                data: BitArrayType::deserialize(deserializer)?,
+            };
+
+            // Equality and hashing compare whole words, so the bits after the last character must be zero.
+            let limit = K * alphabet_character_bit_width(AlphabetType::SIZE);
+            if array[limit..].any() {
+                return Err(serde::de::Error::custom(
+                    "the bits after the last character of the k-mer are not zero",
+                ));
+            }
+
             Ok(Self {
                 phantom_data: Default::default(),
-                array: BitArray {
-                    _ord: Default::default(),
-                    data: BitArrayType::deserialize(deserializer)?,
-                },
+                array,
             })
         }
 
@@ -266,7 +481,8 @@ mod serde {
         where
             D: serde::Deserializer<'a>,
         {
-            BitArrayType::deserialize_in_place(deserializer, &mut place.array.data)
+            *place = Self::deserialize(deserializer)?;
+            Ok(())
         }
     }
 }
@@ -275,12 +491,19 @@ mod serde {
 mod tests {
     use traitsequence::interface::Sequence;
 
+    use crate::test_util::random_ascii;
     use crate::{
         implementation::alphabets::dna_alphabet::{DnaAlphabet, DnaCharacter},
-        interface::{k_mer::OwnedKmer, sequence::OwnedGenomeSequence},
+        implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN,
+        implementation::bit_vec_sequence::BitVectorGenome,
+        interface::{
+            alphabet::AlphabetCharacter,
+            k_mer::{Kmer, OwnedKmer},
+            sequence::{GenomeSequence, OwnedGenomeSequence, Strand},
+        },
     };
 
-    use super::BitArrayKmer;
+    use super::{BitArrayKmer, MultiWordBitArrayKmer};
 
     #[test]
     fn successor() {
@@ -340,4 +563,278 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn cloned_k_mer_iter() {
+        let ascii = random_ascii(200, b"ACGTN", 1);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let k_mers: Vec<_> = genome
+            .cloned_k_mer_iter::<7, BitArrayKmer<7, DnaAlphabetOrN>>()
+            .collect();
+        assert_eq!(k_mers.len(), ascii.len() - 6);
+        for (offset, k_mer) in k_mers.iter().enumerate() {
+            assert_eq!(k_mer.clone_as_vec(), &ascii[offset..offset + 7]);
+        }
+
+        // 21 characters of three bits fill 63 bits of the word.
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let k_mers: Vec<_> = genome
+            .cloned_k_mer_iter::<21, BitArrayKmer<21, DnaAlphabetOrN>>()
+            .collect();
+        assert_eq!(k_mers.len(), ascii.len() - 20);
+        for (offset, k_mer) in k_mers.iter().enumerate() {
+            assert_eq!(k_mer.clone_as_vec(), &ascii[offset..offset + 21]);
+        }
+
+        // Sequences shorter than k have no k-mers.
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<4, BitArrayKmer<4, DnaAlphabet>>()
+                .count(),
+            0
+        );
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<3, BitArrayKmer<3, DnaAlphabet>>()
+                .count(),
+            1
+        );
+        assert_eq!(
+            genome
+                .cloned_k_mer_iter::<0, BitArrayKmer<0, DnaAlphabet>>()
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn predecessor() {
+        let kmer = BitArrayKmer::<4, DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(
+            kmer.predecessor(b'G'.try_into().unwrap()).as_string(),
+            "GACG"
+        );
+
+        // 32 characters of two bits fill the whole word.
+        let ascii = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let kmer = BitArrayKmer::<32, DnaAlphabet>::from_slice_u8(ascii).unwrap();
+        let predecessor = kmer.predecessor(b'T'.try_into().unwrap());
+        assert_eq!(predecessor.clone_as_vec()[1..], ascii[..31]);
+        assert_eq!(predecessor.clone_as_vec()[0], b'T');
+        assert_eq!(predecessor.successor(b'A'.try_into().unwrap()), kmer);
+    }
+
+    #[test]
+    fn canonical_k_mer_iter() {
+        let ascii = random_ascii(300, b"ACGTN", 3);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+
+        let k_mers: Vec<_> = genome
+            .canonical_k_mer_iter::<21, BitArrayKmer<21, DnaAlphabetOrN>>()
+            .collect();
+        assert_eq!(k_mers.len(), ascii.len() - 20);
+        for ((k_mer, strand), forward) in k_mers
+            .into_iter()
+            .zip(genome.cloned_k_mer_iter::<21, BitArrayKmer<21, DnaAlphabetOrN>>())
+        {
+            let reverse_complement = forward.clone_as_reverse_complement();
+            assert!(k_mer.is_canonical());
+            if forward.is_canonical() {
+                assert_eq!((k_mer, strand), (forward, Strand::Forward));
+            } else {
+                assert_eq!(
+                    (k_mer, strand),
+                    (reverse_complement, Strand::ReverseComplement)
+                );
+            }
+        }
+
+        // The self-complemental k-mer ACGT is reported on the forward strand.
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"TACGTA").unwrap();
+        let k_mers: Vec<_> = genome
+            .canonical_k_mer_iter::<4, BitArrayKmer<4, DnaAlphabet>>()
+            .map(|(k_mer, strand)| (k_mer.as_string(), strand))
+            .collect();
+        assert_eq!(
+            k_mers,
+            vec![
+                ("CGTA".to_string(), Strand::ReverseComplement),
+                ("ACGT".to_string(), Strand::Forward),
+                ("CGTA".to_string(), Strand::Forward),
+            ]
+        );
+        assert_eq!(
+            genome
+                .canonical_k_mer_iter::<7, BitArrayKmer<7, DnaAlphabet>>()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn index_and_order() {
+        let kmer = BitArrayKmer::<4, DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(kmer.to_index(), 0b00_01_10_11);
+        assert_eq!(kmer.packed_bits(), 0b11_10_01_00);
+        assert_eq!(BitArrayKmer::<4, DnaAlphabet>::from_index(27), kmer);
+        assert_eq!(
+            BitArrayKmer::<4, DnaAlphabet>::from_packed_bits(kmer.packed_bits()),
+            kmer
+        );
+        assert_eq!(BitArrayKmer::<0, DnaAlphabet>::from_index(0).to_index(), 0);
+
+        let ascii = random_ascii(100, b"ACGTN", 5);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let k_mers: Vec<_> = genome
+            .cloned_k_mer_iter::<21, BitArrayKmer<21, DnaAlphabetOrN>>()
+            .collect();
+
+        for k_mer in &k_mers {
+            assert_eq!(
+                BitArrayKmer::<21, DnaAlphabetOrN>::from_index(k_mer.to_index()),
+                *k_mer
+            );
+            for other in &k_mers {
+                assert_eq!(k_mer.cmp(other), k_mer.to_index().cmp(&other.to_index()));
+                assert_eq!(
+                    k_mer.cmp(other),
+                    k_mer.iter().cmp(other.iter()),
+                    "{} {}",
+                    k_mer.as_string(),
+                    other.as_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multi_word() {
+        let ascii = random_ascii(400, b"ACGTN", 7);
+
+        fn check<const K: usize, const WORDS: usize>(ascii: &[u8]) {
+            type Genome = BitVectorGenome<DnaAlphabetOrN, u64>;
+            let genome = Genome::from_slice_u8(ascii).unwrap();
+            let k_mers: Vec<_> = genome
+                .cloned_k_mer_iter::<K, MultiWordBitArrayKmer<K, DnaAlphabetOrN, WORDS>>()
+                .collect();
+            assert_eq!(k_mers.len(), ascii.len() + 1 - K);
+
+            for (offset, k_mer) in k_mers.iter().enumerate() {
+                assert_eq!(k_mer.clone_as_vec(), &ascii[offset..offset + K]);
+                let predecessor = k_mer.predecessor(b'N'.try_into().unwrap());
+                assert_eq!(predecessor.clone_as_vec()[0], b'N');
+                assert_eq!(
+                    predecessor.clone_as_vec()[1..],
+                    ascii[offset..offset + K - 1]
+                );
+                assert_eq!(predecessor.successor(k_mer[K - 1]), *k_mer);
+                assert_eq!(
+                    k_mer
+                        .clone_as_reverse_complement()
+                        .clone_as_reverse_complement(),
+                    *k_mer
+                );
+            }
+
+            for k_mer in k_mers.iter().step_by(7) {
+                for other in k_mers.iter().step_by(5) {
+                    assert_eq!(k_mer.cmp(other), k_mer.iter().cmp(other.iter()));
+                    assert_eq!(
+                        k_mer.canonical_cmp(other),
+                        k_mer
+                            .iter()
+                            .map(AlphabetCharacter::canonical_rank)
+                            .cmp(other.iter().map(AlphabetCharacter::canonical_rank))
+                    );
+                }
+            }
+        }
+
+        // 21 characters of three bits fit into one word, and characters straddle the word boundaries of larger k-mers.
+        check::<21, 1>(&ascii);
+        check::<22, 2>(&ascii);
+        check::<42, 2>(&ascii);
+        check::<50, 3>(&ascii);
+        check::<127, 6>(&ascii);
+
+        let genome = BitVectorGenome::<DnaAlphabet, u64>::from_slice_u8(&[b'A'; 200]).unwrap();
+        let k_mers: Vec<_> = genome
+            .canonical_k_mer_iter::<127, MultiWordBitArrayKmer<127, DnaAlphabet, 4>>()
+            .collect();
+        assert_eq!(k_mers.len(), 74);
+        assert!(k_mers
+            .iter()
+            .all(|(k_mer, strand)| k_mer.as_string() == "A".repeat(127)
+                && *strand == Strand::Forward));
+    }
+
+    #[test]
+    fn canonical_order() {
+        crate::impl_generic_alphabet!(
+            "DNA alphabet including N, with N ordered last",
+            NLastAlphabet,
+            NLastCharacter,
+            b"ACGNT",
+            b"TGCNA",
+            CANONICAL_ORDER = b"ACGTN",
+        );
+
+        let ascii = random_ascii(300, b"ACGTN", 9);
+
+        fn check<const K: usize, const WORDS: usize>(ascii: &[u8]) {
+            type KmerType<const K: usize, const WORDS: usize> =
+                MultiWordBitArrayKmer<K, NLastAlphabet, WORDS>;
+            let genome = BitVectorGenome::<NLastAlphabet, u64>::from_slice_u8(ascii).unwrap();
+            let k_mers: Vec<_> = genome
+                .cloned_k_mer_iter::<K, KmerType<K, WORDS>>()
+                .collect();
+
+            let mut differs = false;
+            for k_mer in k_mers.iter().step_by(3) {
+                for other in k_mers.iter().step_by(4) {
+                    let expected = k_mer
+                        .iter()
+                        .map(AlphabetCharacter::canonical_rank)
+                        .cmp(other.iter().map(AlphabetCharacter::canonical_rank));
+                    assert_eq!(k_mer.canonical_cmp(other), expected);
+                    differs |= k_mer.cmp(other) != expected;
+                }
+            }
+            // The order by index places N before T, while the canonical order places it after T.
+            assert!(differs);
+
+            for (offset, (k_mer, strand)) in genome
+                .canonical_k_mer_iter::<K, KmerType<K, WORDS>>()
+                .enumerate()
+            {
+                let forward = &genome[offset..offset + K];
+                assert_eq!(strand == Strand::Forward, forward.is_canonical());
+                assert!(k_mer.is_canonical());
+            }
+        }
+
+        check::<5, 1>(&ascii);
+        check::<21, 1>(&ascii);
+        check::<40, 2>(&ascii);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_padding() {
+        use serde::de::{value::Error, IntoDeserializer};
+        use serde::Deserialize;
+
+        let deserialize = |data: u64| {
+            BitArrayKmer::<4, DnaAlphabet, u64>::deserialize(
+                IntoDeserializer::<Error>::into_deserializer(data),
+            )
+        };
+        let k_mer = BitArrayKmer::<4, DnaAlphabet, u64>::from_slice_u8(b"ACGT").unwrap();
+        let data = k_mer.as_bit_array().data;
+        assert_eq!(deserialize(data).unwrap(), k_mer);
+        // The four characters take the lowest eight bits.
+        assert!(deserialize(data | (1 << 8)).is_err());
+        assert!(deserialize(data | (1 << 63)).is_err());
+    }
 }
@@ -445,6 +445,33 @@ impl<AlphabetType: Alphabet, BitStoreType: BitStore>
     fn as_genome_subsequence(&self) -> &BitVectorSubGenome<AlphabetType, BitStoreType> {
         BitVectorSubGenome::ref_cast(&self.bits[..])
     }
+
+    fn matches_at(
+        &self,
+        offset: usize,
+        pattern: &BitVectorSubGenome<AlphabetType, BitStoreType>,
+    ) -> bool {
+        self.as_genome_subsequence().matches_at(offset, pattern)
+    }
+
+    fn common_prefix_len(&self, other: &BitVectorSubGenome<AlphabetType, BitStoreType>) -> usize {
+        self.as_genome_subsequence().common_prefix_len(other)
+    }
+
+    fn hamming_distance(&self, other: &BitVectorSubGenome<AlphabetType, BitStoreType>) -> usize {
+        self.as_genome_subsequence().hamming_distance(other)
+    }
+
+    fn character_counts(&self) -> Vec<usize> {
+        self.as_genome_subsequence().character_counts()
+    }
+
+    fn mismatch_positions(
+        &self,
+        other: &BitVectorSubGenome<AlphabetType, BitStoreType>,
+    ) -> Vec<usize> {
+        self.as_genome_subsequence().mismatch_positions(other)
+    }
 }
 
 impl<AlphabetType: Alphabet, BitStoreType: BitStore>
@@ -463,6 +490,152 @@ impl<AlphabetType: Alphabet, BitStoreType: BitStore>
     GenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitStoreType>>
     for BitVectorSubGenome<AlphabetType, BitStoreType>
 {
+    /// Compares the packed bits of the pattern to this genome word by word, without decoding the characters.
+    fn matches_at(&self, offset: usize, pattern: &Self) -> bool {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let Some(end) = offset.checked_add(pattern.len()) else {
+            return false;
+        };
+        if end > self.len() {
+            return false;
+        }
+
+        self.bits[offset * bit_width..end * bit_width]
+            .chunks(u64::BITS as usize)
+            .zip(pattern.bits.chunks(u64::BITS as usize))
+            .all(|(word, pattern_word)| word.load_le::<u64>() == pattern_word.load_le::<u64>())
+    }
+
+    /// Compares the packed bits of both genomes word by word, locating the first difference with a bit operation.
+    fn common_prefix_len(&self, other: &Self) -> usize {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let bit_len = self.len().min(other.len()) * bit_width;
+
+        for (index, (word, other_word)) in self.bits[..bit_len]
+            .chunks(u64::BITS as usize)
+            .zip(other.bits[..bit_len].chunks(u64::BITS as usize))
+            .enumerate()
+        {
+            let difference = word.load_le::<u64>() ^ other_word.load_le::<u64>();
+            if difference != 0 {
+                return (index * u64::BITS as usize + difference.trailing_zeros() as usize)
+                    / bit_width;
+            }
+        }
+
+        bit_len / bit_width
+    }
+
+    /// Compares each packed word to all characters at once, counting the matching characters with a popcount.
+    ///
+    /// This takes one comparison per character of the alphabet for each word.
+    /// If the alphabet has more characters than fit into a word, then decoding the characters one by one is faster, so that is done instead.
+    fn character_counts(&self) -> Vec<usize> {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let characters_per_word = characters_per_word(bit_width);
+        let mut counts = vec![0; usize::from(AlphabetType::SIZE)];
+
+        if usize::from(AlphabetType::SIZE) > characters_per_word {
+            for character in self.iter() {
+                counts[usize::from(character.index())] += 1;
+            }
+            return counts;
+        }
+
+        let lowest_bits = lowest_character_bits(bit_width, characters_per_word);
+
+        for (position, word) in self.packed_words() {
+            let valid_bits =
+                lowest_character_bits(bit_width, characters_per_word.min(self.len() - position));
+            for (index, count) in counts.iter_mut().enumerate() {
+                // Multiplying with the lowest bits repeats the character index in each character of the word.
+                let difference = word ^ (index as u64).wrapping_mul(lowest_bits);
+                *count += (!fold_character_bits(difference, bit_width) & valid_bits).count_ones()
+                    as usize;
+            }
+        }
+
+        counts
+    }
+
+    /// Compares the packed bits of both genomes word by word, counting the differing characters with a popcount.
+    fn hamming_distance(&self, other: &Self) -> usize {
+        self.mismatch_words(other)
+            .map(|(_, mismatches)| mismatches.count_ones() as usize)
+            .sum()
+    }
+
+    /// Compares the packed bits of both genomes word by word, locating the differing characters with bit operations.
+    fn mismatch_positions(&self, other: &Self) -> Vec<usize> {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let mut result = Vec::new();
+
+        for (first_position, mut mismatches) in self.mismatch_words(other) {
+            while mismatches != 0 {
+                result.push(first_position + mismatches.trailing_zeros() as usize / bit_width);
+                mismatches &= mismatches - 1;
+            }
+        }
+
+        result
+    }
+}
+
+impl<AlphabetType: Alphabet, BitStoreType: BitStore>
+    BitVectorSubGenome<AlphabetType, BitStoreType>
+{
+    /// Returns an iterator over the packed words of this genome.
+    ///
+    /// Each word holds as many whole characters as fit into it, see [characters_per_word].
+    /// The iterator yields the position of the first character in the word and the word itself.
+    fn packed_words(&self) -> impl '_ + Iterator<Item = (usize, u64)> {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let characters_per_word = characters_per_word(bit_width);
+
+        self.bits
+            .chunks(characters_per_word * bit_width)
+            .enumerate()
+            .map(move |(index, word)| (index * characters_per_word, word.load_le::<u64>()))
+    }
+
+    /// Returns an iterator over the packed mismatches between this genome and the given genome.
+    ///
+    /// The iterator yields the position of the first character in the word,
+    /// and the word with the lowest bit of each differing character set and all other bits cleared.
+    fn mismatch_words<'a>(&'a self, other: &'a Self) -> impl 'a + Iterator<Item = (usize, u64)> {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "mismatches are only defined for genomes of equal length"
+        );
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let lowest_bits = lowest_character_bits(bit_width, characters_per_word(bit_width));
+
+        self.packed_words().zip(other.packed_words()).map(
+            move |((position, word), (_, other_word))| {
+                (
+                    position,
+                    fold_character_bits(word ^ other_word, bit_width) & lowest_bits,
+                )
+            },
+        )
+    }
+}
+
+/// Returns the number of whole characters of the given bit width that fit into a word.
+fn characters_per_word(bit_width: usize) -> usize {
+    u64::BITS as usize / bit_width
+}
+
+/// Returns a word with the lowest bit of each of the first `characters` characters of the given bit width set.
+fn lowest_character_bits(bit_width: usize, characters: usize) -> u64 {
+    (0..characters).fold(0, |mask, index| mask | (1 << (index * bit_width)))
+}
+
+/// Sets the lowest bit of each character in the word if any of the character's bits is set.
+/// The other bits of the result are unspecified.
+fn fold_character_bits(word: u64, bit_width: usize) -> u64 {
+    (0..bit_width).fold(0, |folded, shift| folded | (word >> shift))
 }
 
 impl<AlphabetType: Alphabet, BitStoreType: BitStore>
@@ -521,9 +694,11 @@ pub(crate) const fn alphabet_character_bit_width(size: u8) -> usize {
 #[cfg(test)]
 mod tests {
     use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
     use crate::implementation::bit_vec_sequence::BitVectorGenome;
     use crate::interface::alphabet::Alphabet;
     use crate::interface::sequence::{EditableGenomeSequence, GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::{random_ascii, Random};
     use traitsequence::interface::{EditableSequence, Sequence};
 
     #[test]
@@ -636,4 +811,80 @@ mod tests {
             BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ATCTCTTGT").unwrap()
         );
     }
+
+    #[test]
+    fn test_find() {
+        // A pseudo-random genome with a small alphabet, such that patterns occur often.
+        let ascii = random_ascii(1000, b"AACGT", 7);
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+
+        for (start, len) in [(0, 0), (3, 1), (10, 4), (100, 33), (500, 70), (990, 10)] {
+            let pattern = &genome[start..start + len];
+            let expected: Vec<_> = (0..=genome.len() - len)
+                .filter(|&offset| ascii[offset..offset + len] == ascii[start..start + len])
+                .collect();
+            assert_eq!(genome.find_iter(pattern).collect::<Vec<_>>(), expected);
+            assert_eq!(genome.find(pattern), expected.first().copied());
+            assert_eq!(genome.rfind(pattern), expected.last().copied());
+        }
+
+        let pattern = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"GTT").unwrap();
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"AGTTTG").unwrap();
+        assert_eq!(genome.find(&pattern), Some(1));
+        assert!(!genome.matches_at(4, pattern.as_genome_subsequence()));
+        assert_eq!(genome[2..].find(&pattern), None);
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let ascii = b"ACGTTGCAACGTACGTACGTACGTACGTACGTACGTACGTTTGCA";
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(ascii).unwrap();
+        for (a, b) in [(0, 0), (0, 12), (1, 13), (12, 16), (5, 3), (40, 44)] {
+            let expected = ascii[a..]
+                .iter()
+                .zip(&ascii[b..])
+                .take_while(|(x, y)| x == y)
+                .count();
+            assert_eq!(
+                genome[a..ascii.len()].common_prefix_len(&genome[b..ascii.len()]),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        let mut random = Random::new(7);
+
+        for len in [0, 1, 31, 32, 33, 64, 100] {
+            let ascii = random.ascii(len, b"ACGT");
+            let mut mutated = ascii.clone();
+            for _ in 0..len / 5 {
+                mutated[random.below(len)] = random.character(b"ACGT");
+            }
+            let expected: Vec<_> = (0..len).filter(|&i| ascii[i] != mutated[i]).collect();
+
+            let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+            let mutated = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&mutated).unwrap();
+            assert_eq!(
+                genome.hamming_distance(mutated.as_genome_subsequence()),
+                expected.len()
+            );
+            assert_eq!(
+                genome.mismatch_positions(mutated.as_genome_subsequence()),
+                expected
+            );
+        }
+
+        // Three bits per character do not divide the word size.
+        let ascii = b"ACGTNNACGTACGTACGTACGTNACGTNACGTACGT";
+        let mutated = b"ACGTNAACGTACGTACGTACGTTACGTNACGTACGA";
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(ascii).unwrap();
+        let mutated = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(mutated).unwrap();
+        assert_eq!(genome[1..].hamming_distance(&mutated[1..]), 3);
+        assert_eq!(
+            genome[1..].mismatch_positions(&mutated[1..]),
+            vec![4, 21, 34]
+        );
+    }
 }
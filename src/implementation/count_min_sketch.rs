@@ -0,0 +1,266 @@
+//! An approximate k-mer counter with sublinear memory.
+
+use crate::interface::k_mer_counter::{spectrum, KmerCounter};
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// Counts k-mers approximately with a count-min sketch.
+///
+/// The sketch is described in Cormode and Muthukrishnan, "An improved data stream summary: the count-min sketch and its applications", Journal of Algorithms, 2005.
+/// It never underestimates the number of occurrences of a k-mer.
+/// With a width of `w` and a depth of `d`, it overestimates the number of occurrences by more than `e / w` times the [total count](KmerCounter::total_count) with a probability of at most `exp(-d)`.
+///
+/// Since the sketch does not store the k-mers, they need to be given to rank them [by abundance](Self::by_abundance_of) or to compute their [spectrum](Self::spectrum_of).
+#[derive(Debug, Eq, PartialEq)]
+pub struct CountMinSketch<KmerType> {
+    width: usize,
+    depth: usize,
+    /// The counters of all rows, one row after the other.
+    counters: Vec<u64>,
+    total_count: u64,
+    phantom_data: PhantomData<KmerType>,
+}
+
+impl<KmerType: Hash> CountMinSketch<KmerType> {
+    /// Creates a new empty sketch with the given number of counters per row and number of rows.
+    ///
+    /// Panics if the width or the depth is zero.
+    pub fn new(width: usize, depth: usize) -> Self {
+        assert!(
+            width > 0 && depth > 0,
+            "the width and the depth of a count-min sketch must be positive"
+        );
+
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth],
+            total_count: 0,
+            phantom_data: Default::default(),
+        }
+    }
+
+    /// Creates a new empty sketch that overestimates the number of occurrences by more than `error_rate` times the [total count](KmerCounter::total_count) with a probability of at most `error_probability`.
+    ///
+    /// Panics if the error rate or the error probability are not between zero and one, both exclusive.
+    pub fn with_error_rate(error_rate: f64, error_probability: f64) -> Self {
+        assert!(
+            error_rate > 0.0 && error_rate < 1.0,
+            "the error rate must be between zero and one"
+        );
+        assert!(
+            error_probability > 0.0 && error_probability < 1.0,
+            "the error probability must be between zero and one"
+        );
+
+        Self::new(
+            (std::f64::consts::E / error_rate).ceil() as usize,
+            (1.0 / error_probability).ln().ceil().max(1.0) as usize,
+        )
+    }
+
+    /// The number of counters per row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the index of the counter of the given k-mer in each row.
+    fn counter_indices(
+        width: usize,
+        depth: usize,
+        k_mer: &KmerType,
+    ) -> impl Iterator<Item = usize> {
+        // Derive all row hashes from two hashes, as in Kirsch and Mitzenmacher, "Less hashing, same performance: building a better Bloom filter", 2006.
+        let first = hash_with_seed(k_mer, 0);
+        let second = hash_with_seed(k_mer, 1) | 1;
+
+        (0..depth).map(move |row| {
+            let hash = first.wrapping_add((row as u64).wrapping_mul(second));
+            row * width + (hash % width as u64) as usize
+        })
+    }
+
+    /// Returns the given k-mers without duplicates together with their estimated number of occurrences.
+    /// The k-mers are ordered by decreasing number of occurrences, and k-mers with the same number of occurrences are ordered ascendingly.
+    /// K-mers that have not been counted are omitted.
+    pub fn by_abundance_of(
+        &self,
+        k_mers: impl IntoIterator<Item = KmerType>,
+    ) -> Vec<(KmerType, u64)>
+    where
+        KmerType: Eq + Ord + Clone,
+    {
+        let mut result: Vec<_> = k_mers
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|k_mer| {
+                let count = self.count(&k_mer);
+                (k_mer, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        result.sort_unstable_by(|(k_mer, count), (other_k_mer, other_count)| {
+            other_count.cmp(count).then_with(|| k_mer.cmp(other_k_mer))
+        });
+        result
+    }
+
+    /// Returns the estimated spectrum of the given k-mers without duplicates, see [EnumerableKmerCounter::spectrum](crate::interface::k_mer_counter::EnumerableKmerCounter::spectrum).
+    pub fn spectrum_of(&self, k_mers: impl IntoIterator<Item = KmerType>) -> Vec<(u64, usize)>
+    where
+        KmerType: Eq + Clone,
+    {
+        spectrum(
+            k_mers
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .iter()
+                .map(|k_mer| self.count(k_mer)),
+        )
+    }
+}
+
+impl<KmerType> Clone for CountMinSketch<KmerType> {
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width,
+            depth: self.depth,
+            counters: self.counters.clone(),
+            total_count: self.total_count,
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<KmerType: Hash> KmerCounter<KmerType> for CountMinSketch<KmerType> {
+    fn add_count(&mut self, k_mer: &KmerType, count: u64) {
+        for index in Self::counter_indices(self.width, self.depth, k_mer) {
+            self.counters[index] = self.counters[index].saturating_add(count);
+        }
+        self.total_count = self.total_count.saturating_add(count);
+    }
+
+    fn count(&self, k_mer: &KmerType) -> u64 {
+        Self::counter_indices(self.width, self.depth, k_mer)
+            .map(|index| self.counters[index])
+            .min()
+            .unwrap()
+    }
+
+    fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// Adds the occurrences counted by the given sketch to this sketch.
+    ///
+    /// Panics if the sketches have different dimensions.
+    fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            (self.width, self.depth),
+            (other.width, other.depth),
+            "only count-min sketches with the same dimensions can be merged"
+        );
+
+        for (counter, &count) in self.counters.iter_mut().zip(&other.counters) {
+            *counter = counter.saturating_add(count);
+        }
+        self.total_count = self.total_count.saturating_add(other.total_count);
+    }
+}
+
+/// Hashes the given value deterministically, such that sketches are reproducible.
+fn hash_with_seed(value: &impl Hash, seed: u64) -> u64 {
+    let mut hasher = SeededHasher { state: seed };
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A simple deterministic multiplicative hasher.
+struct SeededHasher {
+    state: u64,
+}
+
+impl Hasher for SeededHasher {
+    fn finish(&self) -> u64 {
+        // The finaliser of the SplitMix64 random number generator.
+        let mut value = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.state = (self.state.rotate_left(5) ^ value).wrapping_mul(0x517cc1b727220a95);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::count_min_sketch::CountMinSketch;
+    use crate::implementation::hash_map_k_mer_counter::HashMapKmerCounter;
+    use crate::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::random_ascii;
+
+    type Kmer = BitArrayKmer<9, DnaAlphabet>;
+
+    fn random_genome(len: usize, seed: u64) -> BitVectorGenome<DnaAlphabet> {
+        BitVectorGenome::from_slice_u8(&random_ascii(len, b"ACGT", seed)).unwrap()
+    }
+
+    #[test]
+    fn test_never_underestimates() {
+        let genome = random_genome(2000, 11);
+        let mut sketch = CountMinSketch::<Kmer>::with_error_rate(0.01, 0.01);
+        assert_eq!((sketch.width(), sketch.depth()), (272, 5));
+        let mut exact = HashMapKmerCounter::<Kmer>::new();
+        sketch.add_canonical_k_mers_of(&genome);
+        exact.add_canonical_k_mers_of(&genome);
+
+        assert_eq!(sketch.total_count(), exact.total_count());
+        let mut overestimated = 0;
+        for (k_mer, count) in exact.iter() {
+            let estimate = sketch.count(&k_mer);
+            assert!(estimate >= count);
+            if estimate as f64 > count as f64 + 0.01 * exact.total_count() as f64 {
+                overestimated += 1;
+            }
+        }
+        assert!(overestimated <= exact.distinct_count() / 20);
+    }
+
+    #[test]
+    fn test_merge_and_spectrum() {
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"AAAAAAAAAACCCCCCCCC").unwrap();
+        let mut sketch = CountMinSketch::<Kmer>::new(1 << 12, 4);
+        let mut other = sketch.clone();
+        sketch.add_k_mers_of(&genome);
+        other.add_k_mers_of(&genome[..10]);
+        sketch.merge(&other);
+
+        let k_mers: Vec<_> = genome.cloned_k_mer_iter::<9, Kmer>().collect();
+        let by_abundance = sketch.by_abundance_of(k_mers.iter().cloned());
+        assert_eq!(by_abundance[0].0.as_string(), "AAAAAAAAA");
+        assert_eq!(by_abundance[0].1, 4);
+        assert_eq!(by_abundance.len(), 10);
+        assert_eq!(sketch.spectrum_of(k_mers), vec![(1, 9), (4, 1)]);
+    }
+}
@@ -0,0 +1,181 @@
+//! An exact k-mer counter that stores a counter for every possible k-mer.
+
+use crate::interface::alphabet::Alphabet;
+use crate::interface::k_mer::{Kmer, OwnedKmer};
+use crate::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+use crate::interface::sequence::GenomeSequence;
+use std::marker::PhantomData;
+
+/// Counts k-mers exactly by storing a counter for each possible k-mer, addressed by its [lexicographic rank](Kmer::to_index).
+///
+/// Since the counter has a size of `AlphabetType::SIZE` to the power of `K`, it is only suited for small `K`, e.g. up to 12 for DNA.
+/// In exchange, it requires no hashing and no comparisons of k-mers.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DirectKmerCounter<
+    const K: usize,
+    AlphabetType: Alphabet,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+> {
+    counts: Vec<u64>,
+    total_count: u64,
+    phantom_data: PhantomData<(AlphabetType, KmerType)>,
+    phantom_subsequence: PhantomData<GenomeSubsequence>,
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    > DirectKmerCounter<K, AlphabetType, GenomeSubsequence, KmerType>
+{
+    /// Creates a new counter where all k-mers have zero occurrences.
+    ///
+    /// Panics if the number of possible k-mers does not fit into a `usize`.
+    pub fn new() -> Self {
+        let len = usize::from(AlphabetType::SIZE)
+            .checked_pow(K.try_into().unwrap_or(u32::MAX))
+            .expect("the number of possible k-mers does not fit into a usize");
+
+        Self {
+            counts: vec![0; len],
+            total_count: 0,
+            phantom_data: Default::default(),
+            phantom_subsequence: Default::default(),
+        }
+    }
+
+    fn index(k_mer: &KmerType) -> usize {
+        // The index is smaller than the length of the counts, which is a usize.
+        k_mer.to_index() as usize
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    > Default for DirectKmerCounter<K, AlphabetType, GenomeSubsequence, KmerType>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    > Clone for DirectKmerCounter<K, AlphabetType, GenomeSubsequence, KmerType>
+{
+    fn clone(&self) -> Self {
+        Self {
+            counts: self.counts.clone(),
+            total_count: self.total_count,
+            phantom_data: Default::default(),
+            phantom_subsequence: Default::default(),
+        }
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    > KmerCounter<KmerType> for DirectKmerCounter<K, AlphabetType, GenomeSubsequence, KmerType>
+{
+    fn add_count(&mut self, k_mer: &KmerType, count: u64) {
+        let counter = &mut self.counts[Self::index(k_mer)];
+        *counter = counter.saturating_add(count);
+        self.total_count = self.total_count.saturating_add(count);
+    }
+
+    fn count(&self, k_mer: &KmerType) -> u64 {
+        self.counts[Self::index(k_mer)]
+    }
+
+    fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (counter, &count) in self.counts.iter_mut().zip(&other.counts) {
+            *counter = counter.saturating_add(count);
+        }
+        self.total_count = self.total_count.saturating_add(other.total_count);
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: Kmer<K, AlphabetType, GenomeSubsequence> + OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    > EnumerableKmerCounter<KmerType>
+    for DirectKmerCounter<K, AlphabetType, GenomeSubsequence, KmerType>
+{
+    /// Returns an iterator over all k-mers that occur at least once, together with their number of occurrences, in lexicographic order.
+    fn iter(&self) -> impl Iterator<Item = (KmerType, u64)> {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(index, &count)| (KmerType::from_index(index as u128), count))
+    }
+
+    fn distinct_count(&self) -> usize {
+        self.counts.iter().filter(|&&count| count > 0).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::array_kmer::ArrayKmer;
+    use crate::implementation::direct_k_mer_counter::DirectKmerCounter;
+    use crate::implementation::hash_map_k_mer_counter::HashMapKmerCounter;
+    use crate::implementation::vec_sequence::{SliceSubGenome, VectorGenome};
+    use crate::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+    use crate::interface::sequence::OwnedGenomeSequence;
+    use crate::test_util::Random;
+
+    type Counter =
+        DirectKmerCounter<5, DnaAlphabet, SliceSubGenome<DnaAlphabet>, ArrayKmer<5, DnaAlphabet>>;
+
+    #[test]
+    fn test_equals_hash_map_counter() {
+        let mut random = Random::new(9);
+        let genomes: Vec<_> = (0..3)
+            .map(|_| {
+                VectorGenome::<DnaAlphabet>::from_slice_u8(&random.ascii(500, b"ACGT")).unwrap()
+            })
+            .collect();
+
+        let mut direct = Counter::new();
+        let mut other_direct = Counter::new();
+        let mut hash_map = HashMapKmerCounter::new();
+        direct.add_canonical_k_mers_of(&genomes[0]);
+        other_direct.add_canonical_k_mers_of(&genomes[1]);
+        other_direct.add_k_mers_of(&genomes[2]);
+        direct.merge(&other_direct);
+        hash_map.add_canonical_k_mers_of(&genomes[0]);
+        hash_map.add_canonical_k_mers_of(&genomes[1]);
+        hash_map.add_k_mers_of(&genomes[2]);
+
+        assert_eq!(direct.total_count(), 3 * 496);
+        assert_eq!(direct.total_count(), hash_map.total_count());
+        assert_eq!(direct.distinct_count(), hash_map.distinct_count());
+        assert_eq!(direct.by_abundance(), hash_map.by_abundance());
+        assert_eq!(direct.spectrum(), hash_map.spectrum());
+
+        let k_mers: Vec<_> = direct.iter().map(|(k_mer, _)| k_mer).collect();
+        assert!(k_mers.windows(2).all(|pair| pair[0] < pair[1]));
+        for (k_mer, count) in hash_map.iter() {
+            assert_eq!(direct.count(&k_mer), count);
+        }
+    }
+}
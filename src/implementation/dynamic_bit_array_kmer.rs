@@ -0,0 +1,628 @@
+//! A representation of a k-mer as an array, where k is chosen at runtime.
+
+use crate::implementation::bit_array_kmer::{
+    cmp_packed, push_back_packed, push_front_packed, BitArrayKmer,
+};
+use crate::implementation::bit_vec_sequence::alphabet_character_bit_width;
+use crate::implementation::bit_vec_sequence::{BitVectorSubGenome, BitVectorSubGenomeIterator};
+use crate::interface::alphabet::Alphabet;
+use crate::interface::alphabet::AlphabetCharacter;
+use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+use bitvec::array::BitArray;
+use bitvec::field::BitField;
+use bitvec::view::BitViewSized;
+use ref_cast::RefCast;
+use std::iter::{self, FromIterator};
+use std::marker::PhantomData;
+use std::ops::{Index, Range};
+use traitsequence::interface::{OwnedSequence, Sequence};
+
+/// A k-mer stored as array of minimum-bit characters, where k is chosen at runtime.
+///
+/// This is the runtime counterpart of [BitArrayKmer], with the same packed representation.
+/// Its length can be at most its [capacity](Self::capacity), which by default is 128 bits, i.e. 64 DNA characters.
+/// To run code that needs k at compile time with a k chosen at runtime, see [dispatch_k](crate::dispatch_k).
+///
+/// ```rust
+/// use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+/// use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+/// use compact_genome::implementation::dynamic_bit_array_kmer::DynamicBitArrayKmer;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTT").unwrap();
+/// let k = 3;
+/// let k_mers: Vec<_> = DynamicBitArrayKmer::<DnaAlphabet>::k_mer_iter(&genome, k)
+///     .map(|k_mer| k_mer.as_string())
+///     .collect();
+/// assert_eq!(k_mers, ["ACG", "CGT", "GTT"]);
+/// ```
+#[derive(Debug)]
+pub struct DynamicBitArrayKmer<AlphabetType: Alphabet, BitArrayType = [u64; 2]>
+where
+    BitArrayType: BitViewSized,
+{
+    k: usize,
+    phantom_data: PhantomData<AlphabetType>,
+    array: BitArray<BitArrayType>,
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    /// The maximum length of k-mers of this type.
+    pub fn capacity() -> usize {
+        BitArray::<BitArrayType>::ZERO.len() / alphabet_character_bit_width(AlphabetType::SIZE)
+    }
+
+    /// The length of this k-mer.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the underlying bit array.
+    ///
+    /// The characters are stored with their minimum bit width, starting at the least significant bits.
+    /// The bits after the last character are zero.
+    pub fn as_bit_array(&self) -> &BitArray<BitArrayType> {
+        &self.array
+    }
+
+    /// Returns the packed characters as integer, with the first character in the least significant bits.
+    ///
+    /// Panics if the characters take more than 128 bits.
+    pub fn packed_bits(&self) -> u128 {
+        let limit = self.limit();
+        if limit == 0 {
+            0
+        } else {
+            self.array[..limit].load_le()
+        }
+    }
+
+    /// Constructs a k-mer of length `k` from characters packed as by [packed_bits](Self::packed_bits).
+    ///
+    /// Panics if the characters take more than 128 bits, or `k` exceeds the [capacity](Self::capacity).
+    /// Bits after the last character are ignored, but invalid character indices are not checked.
+    pub fn from_packed_bits(k: usize, packed_bits: u128) -> Self {
+        let mut result = Self::empty(k);
+        let limit = result.limit();
+        if limit > 0 {
+            result.array[..limit].store_le(packed_bits);
+        }
+        result
+    }
+
+    /// Returns the rank of this k-mer in the lexicographic order of all sequences of its length over its alphabet.
+    /// See [Kmer::to_index](crate::interface::k_mer::Kmer::to_index).
+    ///
+    /// Panics if the rank does not fit into a `u128`.
+    pub fn to_index(&self) -> u128 {
+        self.iter().fold(0, |index, character| {
+            index
+                .checked_mul(AlphabetType::SIZE.into())
+                .and_then(|index| index.checked_add(character.index().into()))
+                .expect("the k-mer index does not fit into a u128")
+        })
+    }
+
+    /// Constructs the k-mer of length `k` with the given [lexicographic rank](Self::to_index).
+    ///
+    /// The index must be smaller than `AlphabetType::SIZE` to the power of `k`, otherwise its higher digits are ignored.
+    ///
+    /// Panics if `k` exceeds the [capacity](Self::capacity).
+    pub fn from_index(k: usize, mut index: u128) -> Self {
+        let size = u128::from(AlphabetType::SIZE);
+        let mut digits: Vec<_> = (0..k)
+            .map(|_| {
+                let digit = (index % size) as u8;
+                index /= size;
+                AlphabetType::CharacterType::from_index(digit)
+                    .expect("the digit is smaller than the alphabet size")
+            })
+            .collect();
+        digits.reverse();
+        digits.into_iter().collect()
+    }
+
+    /// Get the successor of this k-mer with the specified character.
+    ///
+    /// This works by shifting the k-mer to the left and adding the character at the end.
+    pub fn successor(&self, successor: AlphabetType::CharacterType) -> Self {
+        if self.k == 0 {
+            return self.clone();
+        }
+
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let limit = self.limit();
+        let mut array = self.array.clone();
+        push_back_packed(&mut array[..limit], successor.index(), bit_width);
+
+        Self {
+            k: self.k,
+            phantom_data: Default::default(),
+            array,
+        }
+    }
+
+    /// Get the predecessor of this k-mer with the specified character.
+    ///
+    /// This works by shifting the k-mer to the right and adding the character at the start.
+    pub fn predecessor(&self, predecessor: AlphabetType::CharacterType) -> Self {
+        if self.k == 0 {
+            return self.clone();
+        }
+
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let limit = self.limit();
+        let mut array = self.array.clone();
+        push_front_packed(&mut array[..limit], predecessor.index(), bit_width);
+
+        Self {
+            k: self.k,
+            phantom_data: Default::default(),
+            array,
+        }
+    }
+
+    /// Returns an iterator over the k-mers of length `k` of the given sequence.
+    ///
+    /// Panics if `k` exceeds the [capacity](Self::capacity).
+    pub fn k_mer_iter<
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        sequence: &SequenceType,
+        k: usize,
+    ) -> DynamicBitArrayKmerIterator<
+        AlphabetType,
+        BitArrayType,
+        iter::Cloned<SequenceType::Iterator<'_>>,
+    > {
+        Self::assert_capacity(k);
+        DynamicBitArrayKmerIterator::new(sequence.iter().cloned(), sequence.len(), k)
+    }
+
+    /// Returns a k-mer of length `k` whose characters all have index zero.
+    fn empty(k: usize) -> Self {
+        Self::assert_capacity(k);
+        Self {
+            k,
+            phantom_data: Default::default(),
+            array: <BitArrayType as BitViewSized>::ZERO.into_bitarray(),
+        }
+    }
+
+    fn assert_capacity(k: usize) {
+        assert!(
+            k <= Self::capacity(),
+            "the k-mer length {k} exceeds the capacity {}",
+            Self::capacity()
+        );
+    }
+
+    /// The number of bits used by the characters of this k-mer.
+    fn limit(&self) -> usize {
+        self.k * alphabet_character_bit_width(AlphabetType::SIZE)
+    }
+}
+
+/// An iterator over the k-mers of a sequence as [DynamicBitArrayKmer]s.
+pub struct DynamicBitArrayKmerIterator<
+    AlphabetType: Alphabet,
+    BitArrayType: BitViewSized,
+    CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+> {
+    characters: CharacterIterator,
+    next_k_mer: Option<DynamicBitArrayKmer<AlphabetType, BitArrayType>>,
+}
+
+impl<
+        AlphabetType: Alphabet,
+        BitArrayType: BitViewSized,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > DynamicBitArrayKmerIterator<AlphabetType, BitArrayType, CharacterIterator>
+{
+    fn new(mut characters: CharacterIterator, len: usize, k: usize) -> Self {
+        let next_k_mer = if len >= k {
+            Some(characters.by_ref().take(k).collect())
+        } else {
+            None
+        };
+
+        Self {
+            characters,
+            next_k_mer,
+        }
+    }
+}
+
+impl<
+        AlphabetType: Alphabet,
+        BitArrayType: BitViewSized,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > Iterator for DynamicBitArrayKmerIterator<AlphabetType, BitArrayType, CharacterIterator>
+{
+    type Item = DynamicBitArrayKmer<AlphabetType, BitArrayType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let k_mer = self.next_k_mer.take()?;
+        self.next_k_mer = self
+            .characters
+            .next()
+            .map(|character| k_mer.successor(character));
+        Some(k_mer)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next_k_mer.is_some() {
+            let (lower, upper) = self.characters.size_hint();
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<const K: usize, AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    From<BitArrayKmer<K, AlphabetType, BitArrayType>>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn from(k_mer: BitArrayKmer<K, AlphabetType, BitArrayType>) -> Self {
+        Self {
+            k: K,
+            phantom_data: Default::default(),
+            array: k_mer.as_bit_array().clone(),
+        }
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    GenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn as_genome_subsequence(&self) -> &BitVectorSubGenome<AlphabetType, BitArrayType::Store> {
+        BitVectorSubGenome::ref_cast(&self.array.as_bitslice()[..self.limit()])
+    }
+
+    fn hamming_distance(
+        &self,
+        other: &BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    ) -> usize {
+        self.as_genome_subsequence().hamming_distance(other)
+    }
+
+    fn character_counts(&self) -> Vec<usize> {
+        self.as_genome_subsequence().character_counts()
+    }
+
+    fn mismatch_positions(
+        &self,
+        other: &BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    ) -> Vec<usize> {
+        self.as_genome_subsequence().mismatch_positions(other)
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    Sequence<AlphabetType::CharacterType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    type Iterator<'a>
+        = BitVectorSubGenomeIterator<'a, AlphabetType, BitArrayType::Store>
+    where
+        Self: 'a,
+        AlphabetType::CharacterType: 'a;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.as_genome_subsequence().iter()
+    }
+
+    fn len(&self) -> usize {
+        self.k
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    OwnedGenomeSequence<AlphabetType, BitVectorSubGenome<AlphabetType, BitArrayType::Store>>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized>
+    OwnedSequence<
+        AlphabetType::CharacterType,
+        BitVectorSubGenome<AlphabetType, BitArrayType::Store>,
+    > for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> FromIterator<AlphabetType::CharacterType>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    /// Collects the characters into a k-mer, where k is the number of characters.
+    ///
+    /// Panics if there are more characters than the [capacity](Self::capacity).
+    fn from_iter<T: IntoIterator<Item = AlphabetType::CharacterType>>(iter: T) -> Self {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let capacity = Self::capacity();
+        let mut result = Self::empty(0);
+
+        for character in iter {
+            assert!(
+                result.k < capacity,
+                "the k-mer length exceeds the capacity {capacity}"
+            );
+            let offset = result.k * bit_width;
+            result.array[offset..offset + bit_width].store(character.index());
+            result.k += 1;
+        }
+
+        result
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> Index<Range<usize>>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    type Output = BitVectorSubGenome<AlphabetType, BitArrayType::Store>;
+
+    fn index(&self, index: Range<usize>) -> &Self::Output {
+        self.as_genome_subsequence().index(index)
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> Index<usize>
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    type Output = AlphabetType::CharacterType;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.as_genome_subsequence().index(index)
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> Clone
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn clone(&self) -> Self {
+        Self {
+            k: self.k,
+            phantom_data: PhantomData,
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized + Copy> Copy
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> PartialEq
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.k == other.k && self.array == other.array
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> Eq
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> PartialOrd
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> Ord
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    /// Compares the k-mers lexicographically by the [index](AlphabetCharacter::index) of their characters, see [to_index](Self::to_index).
+    /// If one k-mer is a prefix of the other, then the shorter one is smaller.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let bit_width = alphabet_character_bit_width(AlphabetType::SIZE);
+        let limit = self.k.min(other.k) * bit_width;
+        cmp_packed(&self.array[..limit], &other.array[..limit], bit_width)
+            .then(self.k.cmp(&other.k))
+    }
+}
+
+impl<AlphabetType: Alphabet, BitArrayType: BitViewSized> core::hash::Hash
+    for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.k.hash(state);
+        self.array.hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use bitvec::{array::BitArray, view::BitViewSized};
+    use serde::{Deserialize, Serialize};
+
+    use crate::interface::alphabet::Alphabet;
+
+    use super::DynamicBitArrayKmer;
+
+    impl<AlphabetType: Alphabet, BitArrayType: BitViewSized + Serialize> Serialize
+        for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            (self.k, &self.array.data).serialize(serializer)
+        }
+    }
+
+    impl<'a, AlphabetType: Alphabet, BitArrayType: BitViewSized + Deserialize<'a>> Deserialize<'a>
+        for DynamicBitArrayKmer<AlphabetType, BitArrayType>
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'a>,
+        {
+            let (k, data) = <(usize, BitArrayType)>::deserialize(deserializer)?;
+            if k > Self::capacity() {
+                return Err(serde::de::Error::custom(format!(
+                    "the k-mer length {k} exceeds the capacity {}",
+                    Self::capacity()
+                )));
+            }
+
+            let result = Self {
+                k,
+                phantom_data: Default::default(),
+                array: BitArray {
+                    _ord: Default::default(),
+                    data,
+                },
+            };
+
+            // Equality and hashing compare whole words, so the bits after the last character must be zero.
+            if result.array[result.limit()..].any() {
+                return Err(serde::de::Error::custom(
+                    "the bits after the last character of the k-mer are not zero",
+                ));
+            }
+
+            Ok(result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use traitsequence::interface::Sequence;
+
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::interface::k_mer::{Kmer, OwnedKmer};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::random_ascii;
+
+    use super::DynamicBitArrayKmer;
+
+    #[test]
+    fn k_mer_iter() {
+        let ascii = random_ascii(200, b"ACGTN", 1);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        assert_eq!(DynamicBitArrayKmer::<DnaAlphabetOrN>::capacity(), 42);
+
+        // 21 characters fit into a word, 30 characters do not.
+        for k in [1, 7, 21, 30, 42] {
+            let k_mers: Vec<_> =
+                DynamicBitArrayKmer::<DnaAlphabetOrN>::k_mer_iter(&genome, k).collect();
+            assert_eq!(k_mers.len(), ascii.len() + 1 - k);
+            for (offset, k_mer) in k_mers.iter().enumerate() {
+                assert_eq!(k_mer.k(), k);
+                assert_eq!(k_mer.clone_as_vec(), &ascii[offset..offset + k]);
+            }
+        }
+
+        let k_mers: Vec<_> = DynamicBitArrayKmer::<DnaAlphabetOrN, usize>::k_mer_iter(&genome, 21)
+            .zip(genome.cloned_k_mer_iter::<21, BitArrayKmer<21, DnaAlphabetOrN>>())
+            .collect();
+        for (k_mer, static_k_mer) in k_mers {
+            assert_eq!(k_mer, static_k_mer.into());
+        }
+
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        assert_eq!(
+            DynamicBitArrayKmer::<DnaAlphabet>::k_mer_iter(&genome, 4).count(),
+            0
+        );
+        assert_eq!(
+            DynamicBitArrayKmer::<DnaAlphabet>::k_mer_iter(&genome, 0).count(),
+            4
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn k_mer_iter_exceeds_capacity() {
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        DynamicBitArrayKmer::<DnaAlphabet, usize>::k_mer_iter(&genome, 33);
+    }
+
+    #[test]
+    fn predecessor_index_and_order() {
+        let k_mer = DynamicBitArrayKmer::<DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        assert_eq!(
+            k_mer.predecessor(b'G'.try_into().unwrap()).as_string(),
+            "GACG"
+        );
+        assert_eq!(k_mer.to_index(), 0b00_01_10_11);
+        assert_eq!(k_mer.packed_bits(), 0b11_10_01_00);
+        assert_eq!(DynamicBitArrayKmer::from_index(4, 27), k_mer);
+        assert_eq!(
+            DynamicBitArrayKmer::from_packed_bits(4, k_mer.packed_bits()),
+            k_mer
+        );
+        assert_eq!(k_mer.clone_as_reverse_complement(), k_mer);
+
+        let ascii = random_ascii(80, b"ACGTN", 2);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        for k in [5, 30] {
+            let k_mers: Vec<_> =
+                DynamicBitArrayKmer::<DnaAlphabetOrN>::k_mer_iter(&genome, k).collect();
+            for k_mer in &k_mers {
+                let predecessor = k_mer.predecessor(b'N'.try_into().unwrap());
+                assert_eq!(
+                    predecessor.clone_as_vec()[1..],
+                    k_mer.clone_as_vec()[..k - 1]
+                );
+                assert_eq!(
+                    predecessor.successor(k_mer[k - 1]).clone_as_vec(),
+                    k_mer.clone_as_vec()
+                );
+                assert_eq!(DynamicBitArrayKmer::from_index(k, k_mer.to_index()), *k_mer);
+                for other in &k_mers {
+                    assert_eq!(k_mer.cmp(other), k_mer.iter().cmp(other.iter()));
+                }
+            }
+        }
+
+        let short = DynamicBitArrayKmer::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        assert!(short < k_mer);
+        assert!(DynamicBitArrayKmer::<DnaAlphabet>::from_slice_u8(b"AG").unwrap() > k_mer);
+        assert_eq!(
+            BitArrayKmer::<4, DnaAlphabet>::from_index(k_mer.to_index()).to_index(),
+            k_mer.to_index()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_padding() {
+        use serde::de::value::{Error, SeqDeserializer};
+        use serde::Deserialize;
+
+        let deserialize = |k: u64, data: u64| {
+            DynamicBitArrayKmer::<DnaAlphabet, u64>::deserialize(SeqDeserializer::<_, Error>::new(
+                [k, data].into_iter(),
+            ))
+        };
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGT").unwrap();
+        let k_mer = DynamicBitArrayKmer::<DnaAlphabet, u64>::k_mer_iter(&genome, 4)
+            .next()
+            .unwrap();
+        let data = k_mer.as_bit_array().data;
+        assert_eq!(deserialize(4, data).unwrap(), k_mer);
+        // The four characters take the lowest eight bits.
+        assert!(deserialize(4, data | (1 << 8)).is_err());
+        assert!(deserialize(3, data).is_err());
+        assert!(deserialize(33, data).is_err());
+    }
+}
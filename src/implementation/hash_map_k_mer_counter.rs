@@ -0,0 +1,156 @@
+//! An exact k-mer counter backed by a hash map.
+
+use crate::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Counts k-mers exactly by storing each distinct k-mer in a hash map.
+///
+/// This works well with compact k-mer types like [BitArrayKmer](crate::implementation::bit_array_kmer::BitArrayKmer).
+///
+/// ```rust
+/// use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+/// use compact_genome::implementation::bit_array_kmer::BitArrayKmer;
+/// use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+/// use compact_genome::implementation::hash_map_k_mer_counter::HashMapKmerCounter;
+/// use compact_genome::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTACGT").unwrap();
+/// let mut counter = HashMapKmerCounter::<BitArrayKmer<4, DnaAlphabet>>::new();
+/// counter.add_k_mers_of(&genome);
+///
+/// let acgt = BitArrayKmer::from_slice_u8(b"ACGT").unwrap();
+/// assert_eq!(counter.count(&acgt), 2);
+/// assert_eq!(counter.distinct_count(), 4);
+/// assert_eq!(counter.spectrum(), vec![(1, 3), (2, 1)]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HashMapKmerCounter<KmerType: Hash + Eq> {
+    counts: HashMap<KmerType, u64>,
+    total_count: u64,
+}
+
+impl<KmerType: Hash + Eq> HashMapKmerCounter<KmerType> {
+    /// Creates a new empty counter.
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            total_count: 0,
+        }
+    }
+}
+
+impl<KmerType: Hash + Eq> Default for HashMapKmerCounter<KmerType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<KmerType: Hash + Eq + Clone> KmerCounter<KmerType> for HashMapKmerCounter<KmerType> {
+    fn add_count(&mut self, k_mer: &KmerType, count: u64) {
+        if count == 0 {
+            return;
+        }
+
+        if let Some(existing) = self.counts.get_mut(k_mer) {
+            *existing = existing.saturating_add(count);
+        } else {
+            self.counts.insert(k_mer.clone(), count);
+        }
+        self.total_count = self.total_count.saturating_add(count);
+    }
+
+    fn count(&self, k_mer: &KmerType) -> u64 {
+        self.counts.get(k_mer).copied().unwrap_or(0)
+    }
+
+    fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (k_mer, &count) in &other.counts {
+            self.add_count(k_mer, count);
+        }
+    }
+}
+
+impl<KmerType: Hash + Eq + Clone> EnumerableKmerCounter<KmerType> for HashMapKmerCounter<KmerType> {
+    fn iter(&self) -> impl Iterator<Item = (KmerType, u64)> {
+        self.counts
+            .iter()
+            .map(|(k_mer, &count)| (k_mer.clone(), count))
+    }
+
+    fn distinct_count(&self) -> usize {
+        self.counts.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::hash_map_k_mer_counter::HashMapKmerCounter;
+    use crate::interface::k_mer_counter::{EnumerableKmerCounter, KmerCounter};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+
+    type Kmer = BitArrayKmer<3, DnaAlphabet>;
+
+    fn k_mer(ascii: &[u8]) -> Kmer {
+        Kmer::from_slice_u8(ascii).unwrap()
+    }
+
+    #[test]
+    fn test_canonical_counting_and_merge() {
+        // The reverse complement of AACG is CGTT.
+        let forward = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"AACG").unwrap();
+        let reverse = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"CGTT").unwrap();
+
+        let mut counter = HashMapKmerCounter::<Kmer>::new();
+        counter.add_canonical_k_mers_of(&forward);
+        let mut other = HashMapKmerCounter::<Kmer>::new();
+        other.add_canonical_k_mers_of(&reverse);
+        assert_eq!(counter, other);
+
+        counter.merge(&other);
+        assert_eq!(counter.total_count(), 4);
+        assert_eq!(counter.count(&k_mer(b"AAC")), 2);
+        assert_eq!(counter.count(&k_mer(b"ACG")), 2);
+        assert_eq!(counter.count(&k_mer(b"GTT")), 0);
+        assert_eq!(counter.spectrum(), vec![(2, 2)]);
+
+        let mut forward_counter = HashMapKmerCounter::<Kmer>::new();
+        forward_counter.add_k_mers_of(&forward);
+        forward_counter.add_k_mers_of(&reverse);
+        assert_eq!(forward_counter.distinct_count(), 4);
+        assert_eq!(forward_counter.count(&k_mer(b"GTT")), 1);
+    }
+
+    #[test]
+    fn test_by_abundance() {
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"TTTTCAAAA").unwrap();
+        let mut counter = HashMapKmerCounter::<Kmer>::new();
+        counter.add_k_mers_of(&genome);
+        counter.add_count(&k_mer(b"GGG"), 0);
+
+        let by_abundance: Vec<_> = counter
+            .by_abundance()
+            .into_iter()
+            .map(|(k_mer, count)| (k_mer.as_string(), count))
+            .collect();
+        assert_eq!(
+            by_abundance,
+            vec![
+                ("AAA".to_string(), 2),
+                ("TTT".to_string(), 2),
+                ("CAA".to_string(), 1),
+                ("TCA".to_string(), 1),
+                ("TTC".to_string(), 1),
+            ]
+        );
+        assert_eq!(counter.spectrum(), vec![(1, 3), (2, 2)]);
+    }
+}
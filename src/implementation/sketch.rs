@@ -0,0 +1,736 @@
+//! Sketches of the k-mer content of genomes, to estimate their similarity.
+//!
+//! Both sketches hash each k-mer with the canonical [ntHash](crate::interface::sequence::nt_hash), such that a k-mer and its reverse complement are treated equally.
+//! K-mers that contain characters other than nucleotides are skipped.
+//! The canonical ntHash is mixed once more to make the hash values uniformly distributed.
+//!
+//! * A [MinHashSketch] keeps the smallest hash values, up to a fixed number, as in [Mash](https://doi.org/10.1186/s13059-016-0997-x).
+//! * A [FracMinHashSketch] keeps all hash values below a threshold, i.e. a fixed fraction of the k-mers, as in [sourmash](https://doi.org/10.21105/joss.00027).
+//!   Its size grows with the genome, but it estimates the containment of genomes of very different sizes accurately.
+//!
+//! Sketches can be serialised with the `serde` feature.
+//! Deserialisation checks the sketch like [MinHashSketch::from_hashes] and [FracMinHashSketch::from_hashes].
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::vec_sequence::VectorGenome;
+//! use compact_genome::interface::sequence::OwnedGenomeSequence;
+//! use compact_genome::implementation::sketch::MinHashSketch;
+//!
+//! let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTTGCAACGGTCATGCA").unwrap();
+//! let reverse_complement = VectorGenome::<DnaAlphabet>::from_slice_u8(b"TGCATGACCGTTGCAACGT").unwrap();
+//! let sketch = MinHashSketch::from_sequence(5, 100, &genome);
+//! let other = MinHashSketch::from_sequence(5, 100, &reverse_complement);
+//! assert_eq!(sketch.jaccard(&other), 1.0);
+//! assert_eq!(sketch.mash_distance(&other), 0.0);
+//! ```
+
+use crate::interface::alphabet::NucleotideAlphabet;
+use crate::interface::sequence::nt_hash::NtHash;
+use crate::interface::sequence::sampling::mix;
+use crate::interface::sequence::GenomeSequence;
+use crate::interface::sequence_store::SequenceStore;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// A bottom-k MinHash sketch, which keeps the `size` smallest hash values of the canonical k-mers of its genomes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct MinHashSketch {
+    k: usize,
+    size: usize,
+    hashes: BTreeSet<u64>,
+}
+
+/// A FracMinHash sketch, which keeps the hash values of the canonical k-mers of its genomes that are at most `u64::MAX / scaled`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct FracMinHashSketch {
+    k: usize,
+    scaled: u64,
+    hashes: BTreeSet<u64>,
+}
+
+/// An error when creating a sketch from its parts.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum SketchError {
+    #[error("the k-mer length must be positive")]
+    /// The k-mer length is zero.
+    ZeroKmerLength,
+
+    #[error("the sketch size must be positive")]
+    /// The size of a [MinHashSketch] is zero.
+    ZeroSize,
+
+    #[error("the scaling factor must be positive")]
+    /// The scaling factor of a [FracMinHashSketch] is zero.
+    ZeroScaled,
+
+    #[error("the sketch keeps {len} hash values, but its size is {size}")]
+    /// A [MinHashSketch] keeps more hash values than its size.
+    TooManyHashes {
+        /// The number of hash values.
+        len: usize,
+        /// The size of the sketch.
+        size: usize,
+    },
+
+    #[error("the hash value {hash} exceeds the largest hash value {max_hash} of the sketch")]
+    /// A [FracMinHashSketch] keeps a hash value above its [max_hash](FracMinHashSketch::max_hash).
+    HashAboveMax {
+        /// The offending hash value.
+        hash: u64,
+        /// The largest hash value of the sketch.
+        max_hash: u64,
+    },
+}
+
+impl MinHashSketch {
+    /// Creates a new empty sketch that keeps up to `size` hash values of k-mers of length `k`.
+    ///
+    /// Panics if `k` or `size` is zero.
+    pub fn new(k: usize, size: usize) -> Self {
+        assert!(k > 0, "the k-mer length must be positive");
+        assert!(size > 0, "the sketch size must be positive");
+
+        Self {
+            k,
+            size,
+            hashes: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a sketch that keeps the given hash values of k-mers of length `k`, e.g. as obtained from [hashes](Self::hashes).
+    ///
+    /// Returns an error if `k` or `size` is zero, or if there are more than `size` hash values.
+    pub fn from_hashes(
+        k: usize,
+        size: usize,
+        hashes: impl IntoIterator<Item = u64>,
+    ) -> Result<Self, SketchError> {
+        if k == 0 {
+            return Err(SketchError::ZeroKmerLength);
+        }
+        if size == 0 {
+            return Err(SketchError::ZeroSize);
+        }
+
+        let hashes: BTreeSet<_> = hashes.into_iter().collect();
+        if hashes.len() > size {
+            return Err(SketchError::TooManyHashes {
+                len: hashes.len(),
+                size,
+            });
+        }
+
+        Ok(Self { k, size, hashes })
+    }
+
+    /// Creates a new sketch of the given sequence, see [new](Self::new).
+    pub fn from_sequence<
+        AlphabetType: NucleotideAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        k: usize,
+        size: usize,
+        sequence: &SequenceType,
+    ) -> Self {
+        let mut result = Self::new(k, size);
+        result.add_sequence(sequence);
+        result
+    }
+
+    /// The length of the sketched k-mers.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The maximum number of hash values kept by this sketch.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns an iterator over the hash values kept by this sketch, in ascending order.
+    pub fn hashes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hashes.iter().copied()
+    }
+
+    /// The number of hash values kept by this sketch.
+    /// This is smaller than [size](Self::size) if fewer distinct k-mers were added.
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns true if no k-mers were added to this sketch.
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Adds the canonical k-mers of the given sequence to this sketch.
+    pub fn add_sequence<
+        AlphabetType: NucleotideAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &mut self,
+        sequence: &SequenceType,
+    ) {
+        for hash in sequence.nt_hash_iter(self.k).map(canonical_hash) {
+            self.insert(hash);
+        }
+    }
+
+    /// Adds the canonical k-mers of the sequences referred by the given handles to this sketch.
+    pub fn add_store_sequences<
+        'handle,
+        AlphabetType: NucleotideAlphabet,
+        SequenceStoreType: SequenceStore<AlphabetType>,
+    >(
+        &mut self,
+        sequence_store: &SequenceStoreType,
+        handles: impl IntoIterator<Item = &'handle SequenceStoreType::Handle>,
+    ) where
+        SequenceStoreType::Handle: 'handle,
+    {
+        for handle in handles {
+            self.add_sequence(sequence_store.get(handle));
+        }
+    }
+
+    /// Adds the k-mers sketched by the given sketch to this sketch.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn merge(&mut self, other: &Self) {
+        assert_compatible(self.k, other.k);
+        for &hash in &other.hashes {
+            self.insert(hash);
+        }
+    }
+
+    fn insert(&mut self, hash: u64) {
+        if self.hashes.len() < self.size {
+            self.hashes.insert(hash);
+        } else if hash < *self.hashes.last().unwrap() && self.hashes.insert(hash) {
+            self.hashes.pop_last();
+        }
+    }
+
+    /// Returns the largest hash value up to which this sketch knows all hash values of its k-mers.
+    fn threshold(&self) -> u64 {
+        if self.hashes.len() < self.size {
+            u64::MAX
+        } else {
+            *self.hashes.last().unwrap()
+        }
+    }
+
+    /// Estimates the Jaccard index of the k-mer sets of the genomes sketched by this and the other sketch.
+    ///
+    /// The estimate uses the smallest hash values of the union of both sketches, as many as the smaller sketch size.
+    /// If both sketches are empty, the estimate is zero.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        assert_compatible(self.k, other.k);
+
+        let size = self.size.min(other.size);
+        let mut union = 0;
+        let mut shared = 0;
+        for (is_shared, _) in sorted_union(self.hashes(), other.hashes()).take(size) {
+            union += 1;
+            if is_shared {
+                shared += 1;
+            }
+        }
+
+        ratio(shared, union)
+    }
+
+    /// Estimates which fraction of the k-mers of the genomes sketched by this sketch are also k-mers of the genomes sketched by the other sketch.
+    ///
+    /// Only hash values that are known to both sketches are considered.
+    /// If no such hash value exists in this sketch, the estimate is zero.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn containment(&self, other: &Self) -> f64 {
+        assert_compatible(self.k, other.k);
+
+        let threshold = self.threshold().min(other.threshold());
+        let hashes = self.hashes.range(..=threshold);
+        let total = hashes.clone().count();
+        let shared = hashes.filter(|hash| other.hashes.contains(hash)).count();
+        ratio(shared, total)
+    }
+
+    /// Estimates the Mash distance between the genomes sketched by this and the other sketch, see [mash_distance].
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn mash_distance(&self, other: &Self) -> f64 {
+        mash_distance(self.jaccard(other), self.k)
+    }
+}
+
+impl FracMinHashSketch {
+    /// Creates a new empty sketch that keeps about one in `scaled` hash values of k-mers of length `k`.
+    ///
+    /// Panics if `k` or `scaled` is zero.
+    pub fn new(k: usize, scaled: u64) -> Self {
+        assert!(k > 0, "the k-mer length must be positive");
+        assert!(scaled > 0, "the scaling factor must be positive");
+
+        Self {
+            k,
+            scaled,
+            hashes: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a sketch that keeps the given hash values of k-mers of length `k`, e.g. as obtained from [hashes](Self::hashes).
+    ///
+    /// Returns an error if `k` or `scaled` is zero, or if a hash value exceeds the resulting [max_hash](Self::max_hash).
+    pub fn from_hashes(
+        k: usize,
+        scaled: u64,
+        hashes: impl IntoIterator<Item = u64>,
+    ) -> Result<Self, SketchError> {
+        if k == 0 {
+            return Err(SketchError::ZeroKmerLength);
+        }
+        if scaled == 0 {
+            return Err(SketchError::ZeroScaled);
+        }
+
+        let hashes: BTreeSet<_> = hashes.into_iter().collect();
+        let max_hash = u64::MAX / scaled;
+        if let Some(&hash) = hashes.last().filter(|&&hash| hash > max_hash) {
+            return Err(SketchError::HashAboveMax { hash, max_hash });
+        }
+
+        Ok(Self { k, scaled, hashes })
+    }
+
+    /// Creates a new sketch of the given sequence, see [new](Self::new).
+    pub fn from_sequence<
+        AlphabetType: NucleotideAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        k: usize,
+        scaled: u64,
+        sequence: &SequenceType,
+    ) -> Self {
+        let mut result = Self::new(k, scaled);
+        result.add_sequence(sequence);
+        result
+    }
+
+    /// The length of the sketched k-mers.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The scaling factor, i.e. this sketch keeps about one in `scaled` hash values.
+    pub fn scaled(&self) -> u64 {
+        self.scaled
+    }
+
+    /// The largest hash value kept by this sketch.
+    pub fn max_hash(&self) -> u64 {
+        u64::MAX / self.scaled
+    }
+
+    /// Returns an iterator over the hash values kept by this sketch, in ascending order.
+    pub fn hashes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hashes.iter().copied()
+    }
+
+    /// The number of hash values kept by this sketch.
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns true if this sketch keeps no hash values.
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Estimates the number of distinct canonical k-mers of the genomes sketched by this sketch.
+    pub fn estimated_cardinality(&self) -> u64 {
+        (self.hashes.len() as u64).saturating_mul(self.scaled)
+    }
+
+    /// Adds the canonical k-mers of the given sequence to this sketch.
+    pub fn add_sequence<
+        AlphabetType: NucleotideAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &mut self,
+        sequence: &SequenceType,
+    ) {
+        let max_hash = self.max_hash();
+        self.hashes.extend(
+            sequence
+                .nt_hash_iter(self.k)
+                .map(canonical_hash)
+                .filter(|&hash| hash <= max_hash),
+        );
+    }
+
+    /// Adds the canonical k-mers of the sequences referred by the given handles to this sketch.
+    pub fn add_store_sequences<
+        'handle,
+        AlphabetType: NucleotideAlphabet,
+        SequenceStoreType: SequenceStore<AlphabetType>,
+    >(
+        &mut self,
+        sequence_store: &SequenceStoreType,
+        handles: impl IntoIterator<Item = &'handle SequenceStoreType::Handle>,
+    ) where
+        SequenceStoreType::Handle: 'handle,
+    {
+        for handle in handles {
+            self.add_sequence(sequence_store.get(handle));
+        }
+    }
+
+    /// Adds the k-mers sketched by the given sketch to this sketch.
+    /// If the other sketch has a larger scaling factor, then this sketch is [downsampled](Self::downsample) to it first.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn merge(&mut self, other: &Self) {
+        assert_compatible(self.k, other.k);
+        if other.scaled > self.scaled {
+            *self = self.downsample(other.scaled);
+        }
+
+        let max_hash = self.max_hash();
+        self.hashes.extend(other.hashes.range(..=max_hash).copied());
+    }
+
+    /// Returns a copy of this sketch with the given larger scaling factor, which keeps fewer hash values.
+    ///
+    /// Panics if the given scaling factor is smaller than the scaling factor of this sketch.
+    pub fn downsample(&self, scaled: u64) -> Self {
+        assert!(
+            scaled >= self.scaled,
+            "a FracMinHash sketch can only be downsampled to a larger scaling factor"
+        );
+
+        Self {
+            k: self.k,
+            scaled,
+            hashes: self.hashes.range(..=u64::MAX / scaled).copied().collect(),
+        }
+    }
+
+    /// Estimates the Jaccard index of the k-mer sets of the genomes sketched by this and the other sketch.
+    ///
+    /// If the sketches have different scaling factors, only the hash values kept by both are considered.
+    /// If both sketches are empty, the estimate is zero.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        assert_compatible(self.k, other.k);
+
+        let max_hash = self.max_hash().min(other.max_hash());
+        let mut union = 0;
+        let mut shared = 0;
+        for (is_shared, _) in sorted_union(
+            self.hashes.range(..=max_hash).copied(),
+            other.hashes.range(..=max_hash).copied(),
+        ) {
+            union += 1;
+            if is_shared {
+                shared += 1;
+            }
+        }
+
+        ratio(shared, union)
+    }
+
+    /// Estimates which fraction of the k-mers of the genomes sketched by this sketch are also k-mers of the genomes sketched by the other sketch.
+    ///
+    /// If the sketches have different scaling factors, only the hash values kept by both are considered.
+    /// If this sketch is empty, the estimate is zero.
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn containment(&self, other: &Self) -> f64 {
+        assert_compatible(self.k, other.k);
+
+        let hashes = self.hashes.range(..=self.max_hash().min(other.max_hash()));
+        let total = hashes.clone().count();
+        let shared = hashes.filter(|hash| other.hashes.contains(hash)).count();
+        ratio(shared, total)
+    }
+
+    /// Estimates the Mash distance between the genomes sketched by this and the other sketch, see [mash_distance].
+    ///
+    /// Panics if the sketches have different k-mer lengths.
+    pub fn mash_distance(&self, other: &Self) -> f64 {
+        mash_distance(self.jaccard(other), self.k)
+    }
+}
+
+/// Computes the Mash distance from a Jaccard index of k-mer sets with the given k-mer length.
+///
+/// The Mash distance estimates the rate of point mutations between two genomes, hence `1 - d` estimates their average nucleotide identity.
+/// It is `-ln(2j / (1 + j)) / k` for a Jaccard index `j`, and one if `j` is zero.
+pub fn mash_distance(jaccard: f64, k: usize) -> f64 {
+    if jaccard <= 0.0 {
+        1.0
+    } else {
+        (-(2.0 * jaccard / (1.0 + jaccard)).ln() / k as f64).clamp(0.0, 1.0)
+    }
+}
+
+/// Returns the canonical hash value of a k-mer, mixed to be uniformly distributed.
+fn canonical_hash(nt_hash: NtHash) -> u64 {
+    mix(nt_hash.canonical())
+}
+
+/// Merges two ascending iterators without duplicates into their ascending union.
+/// Each value is paired with true if it is contained in both iterators.
+fn sorted_union(
+    first: impl Iterator<Item = u64>,
+    second: impl Iterator<Item = u64>,
+) -> impl Iterator<Item = (bool, u64)> {
+    let mut first = first.peekable();
+    let mut second = second.peekable();
+    std::iter::from_fn(move || match (first.peek(), second.peek()) {
+        (Some(a), Some(b)) => match a.cmp(b) {
+            Ordering::Less => first.next().map(|a| (false, a)),
+            Ordering::Greater => second.next().map(|b| (false, b)),
+            Ordering::Equal => {
+                second.next();
+                first.next().map(|a| (true, a))
+            }
+        },
+        (Some(_), None) => first.next().map(|a| (false, a)),
+        (None, _) => second.next().map(|b| (false, b)),
+    })
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+fn assert_compatible(k: usize, other_k: usize) {
+    assert_eq!(
+        k, other_k,
+        "only sketches with the same k-mer length can be compared"
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use serde::Deserialize;
+    use std::collections::BTreeSet;
+
+    use super::{FracMinHashSketch, MinHashSketch};
+
+    #[derive(Deserialize)]
+    #[serde(rename = "MinHashSketch")]
+    struct MinHashSketchData {
+        k: usize,
+        size: usize,
+        hashes: BTreeSet<u64>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "FracMinHashSketch")]
+    struct FracMinHashSketchData {
+        k: usize,
+        scaled: u64,
+        hashes: BTreeSet<u64>,
+    }
+
+    impl<'a> Deserialize<'a> for MinHashSketch {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'a>,
+        {
+            let MinHashSketchData { k, size, hashes } =
+                MinHashSketchData::deserialize(deserializer)?;
+            Self::from_hashes(k, size, hashes).map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl<'a> Deserialize<'a> for FracMinHashSketch {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'a>,
+        {
+            let FracMinHashSketchData { k, scaled, hashes } =
+                FracMinHashSketchData::deserialize(deserializer)?;
+            Self::from_hashes(k, scaled, hashes).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::sketch::{
+        mash_distance, FracMinHashSketch, MinHashSketch, SketchError,
+    };
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::implementation::vec_sequence_store::VectorSequenceStore;
+    use crate::interface::sequence::OwnedGenomeSequence;
+    use crate::interface::sequence_store::SequenceStore;
+    use crate::test_util::random_ascii;
+
+    fn genome(ascii: &[u8]) -> VectorGenome<DnaAlphabet> {
+        VectorGenome::from_slice_u8(ascii).unwrap()
+    }
+
+    #[test]
+    fn test_min_hash() {
+        let ascii = random_ascii(20_000, b"ACGT", 3);
+        let whole = MinHashSketch::from_sequence(21, 1000, &genome(&ascii));
+        let half = MinHashSketch::from_sequence(21, 1000, &genome(&ascii[..10_000]));
+        assert_eq!(whole.len(), 1000);
+        assert!(whole
+            .hashes()
+            .zip(whole.hashes().skip(1))
+            .all(|(a, b)| a < b));
+
+        // Half of the k-mers are shared.
+        let jaccard = whole.jaccard(&half);
+        assert!((jaccard - 0.5).abs() < 0.07, "{jaccard}");
+        let containment = half.containment(&whole);
+        assert!(containment > 0.95, "{containment}");
+        let containment = whole.containment(&half);
+        assert!((containment - 0.5).abs() < 0.07, "{containment}");
+
+        let mut merged = half.clone();
+        merged.merge(&MinHashSketch::from_sequence(
+            21,
+            1000,
+            &genome(&ascii[9_980..]),
+        ));
+        assert_eq!(merged, whole);
+        assert_eq!(merged.jaccard(&whole), 1.0);
+
+        let unrelated =
+            MinHashSketch::from_sequence(21, 1000, &genome(&random_ascii(20_000, b"ACGT", 4)));
+        assert_eq!(whole.jaccard(&unrelated), 0.0);
+        assert_eq!(whole.mash_distance(&unrelated), 1.0);
+    }
+
+    #[test]
+    fn test_min_hash_small_and_store() {
+        let mut store = VectorSequenceStore::<DnaAlphabetOrN>::new();
+        let handles = [
+            store.add_from_slice_u8(b"ACGTANNACGTA").unwrap(),
+            store.add_from_slice_u8(b"TACGT").unwrap(),
+        ];
+        let mut sketch = MinHashSketch::new(5, 10);
+        sketch.add_store_sequences(&store, &handles);
+        // ACGTA and its reverse complement TACGT are the only k-mers.
+        assert_eq!(sketch.len(), 1);
+
+        let other = MinHashSketch::from_sequence(
+            5,
+            3,
+            &VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTAC").unwrap(),
+        );
+        assert_eq!(other.len(), 2);
+        assert_eq!(sketch.jaccard(&other), 0.5);
+        assert_eq!(sketch.containment(&other), 1.0);
+        assert_eq!(other.containment(&sketch), 0.5);
+        assert_eq!(
+            MinHashSketch::new(5, 1).jaccard(&MinHashSketch::new(5, 2)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_frac_min_hash() {
+        let ascii = random_ascii(50_000, b"ACGT", 5);
+        let whole = FracMinHashSketch::from_sequence(21, 10, &genome(&ascii));
+        let quarter = FracMinHashSketch::from_sequence(21, 10, &genome(&ascii[..12_500]));
+        assert!(whole.hashes().all(|hash| hash <= whole.max_hash()));
+        let cardinality = whole.estimated_cardinality() as f64;
+        assert!((cardinality / 50_000.0 - 1.0).abs() < 0.1, "{cardinality}");
+
+        assert_eq!(quarter.containment(&whole), 1.0);
+        let jaccard = whole.jaccard(&quarter);
+        assert!((jaccard - 0.25).abs() < 0.03, "{jaccard}");
+        assert_eq!(whole.jaccard(&quarter), quarter.jaccard(&whole));
+
+        let downsampled = whole.downsample(100);
+        assert_eq!(downsampled.scaled(), 100);
+        assert!(downsampled.len() < whole.len() / 5);
+        assert_eq!(downsampled.jaccard(&whole), 1.0);
+        assert_eq!(
+            downsampled.jaccard(&quarter),
+            downsampled.jaccard(&quarter.downsample(100))
+        );
+
+        let mut merged = FracMinHashSketch::from_sequence(21, 10, &genome(&ascii[12_480..]));
+        merged.merge(&quarter);
+        assert_eq!(merged, whole);
+        merged.merge(&downsampled);
+        assert_eq!(merged, downsampled);
+    }
+
+    #[test]
+    fn test_from_hashes() {
+        let sketch = MinHashSketch::from_sequence(5, 3, &genome(b"ACGTACGGTCA"));
+        assert_eq!(
+            MinHashSketch::from_hashes(sketch.k(), sketch.size(), sketch.hashes()),
+            Ok(sketch.clone())
+        );
+        assert_eq!(
+            MinHashSketch::from_hashes(0, 3, []),
+            Err(SketchError::ZeroKmerLength)
+        );
+        assert_eq!(
+            MinHashSketch::from_hashes(5, 0, []),
+            Err(SketchError::ZeroSize)
+        );
+        assert_eq!(
+            MinHashSketch::from_hashes(5, 2, sketch.hashes()),
+            Err(SketchError::TooManyHashes { len: 3, size: 2 })
+        );
+
+        let sketch = FracMinHashSketch::from_sequence(5, 2, &genome(b"ACGTACGGTCA"));
+        assert_eq!(
+            FracMinHashSketch::from_hashes(sketch.k(), sketch.scaled(), sketch.hashes()),
+            Ok(sketch.clone())
+        );
+        assert_eq!(
+            FracMinHashSketch::from_hashes(0, 2, []),
+            Err(SketchError::ZeroKmerLength)
+        );
+        assert_eq!(
+            FracMinHashSketch::from_hashes(5, 0, []),
+            Err(SketchError::ZeroScaled)
+        );
+        assert_eq!(
+            FracMinHashSketch::from_hashes(5, 4, [1, u64::MAX]),
+            Err(SketchError::HashAboveMax {
+                hash: u64::MAX,
+                max_hash: u64::MAX / 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_mash_distance() {
+        assert_eq!(mash_distance(1.0, 21), 0.0);
+        assert_eq!(mash_distance(0.0, 21), 1.0);
+        // A mutation rate of d leaves about (1 - d)^k of the k-mers intact.
+        let intact = 0.99f64.powi(21);
+        let jaccard = intact / (2.0 - intact);
+        assert!((mash_distance(jaccard, 21) - 0.01).abs() < 1e-3);
+    }
+}
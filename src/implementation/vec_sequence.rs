@@ -371,7 +371,7 @@ impl<AlphabetType: Alphabet> From<VectorGenome<AlphabetType>> for Vec<AlphabetTy
 mod tests {
     use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
     use crate::implementation::vec_sequence::VectorGenome;
-    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence, Strand};
 
     #[test]
     fn test_reverse_complement() {
@@ -388,4 +388,39 @@ mod tests {
         let expected_string = "ATTCGGT";
         debug_assert_eq!(display_string, expected_string);
     }
+
+    #[test]
+    fn test_find() {
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGACGTCGTA").unwrap();
+        let pattern = VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        assert_eq!(genome.find_iter(&pattern).collect::<Vec<_>>(), vec![0, 3]);
+        assert_eq!(genome.find(&pattern), Some(0));
+        assert_eq!(genome.rfind(&pattern), Some(3));
+        assert_eq!(
+            genome.find_iter_both_strands(&pattern).collect::<Vec<_>>(),
+            vec![
+                (0, Strand::Forward),
+                (3, Strand::Forward),
+                (4, Strand::ReverseComplement),
+                (7, Strand::ReverseComplement)
+            ]
+        );
+
+        let pattern = VectorGenome::<DnaAlphabet>::from_slice_u8(b"CG").unwrap();
+        assert_eq!(
+            genome.find_iter_both_strands(&pattern).collect::<Vec<_>>(),
+            vec![
+                (1, Strand::Forward),
+                (1, Strand::ReverseComplement),
+                (4, Strand::Forward),
+                (4, Strand::ReverseComplement),
+                (7, Strand::Forward),
+                (7, Strand::ReverseComplement)
+            ]
+        );
+
+        let pattern = VectorGenome::<DnaAlphabet>::from_slice_u8(b"GGG").unwrap();
+        assert_eq!(genome.find(&pattern), None);
+        assert_eq!(genome.find_iter_both_strands(&pattern).next(), None);
+    }
 }
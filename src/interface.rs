@@ -5,3 +5,4 @@ pub mod sequence;
 pub mod sequence_store;
 
 pub mod k_mer;
+pub mod k_mer_counter;
@@ -22,6 +22,52 @@ pub trait AlphabetCharacter: Into<u8> + Into<char> + TryFrom<u8> + TryFrom<char>
     /// It is required to create an implementation of [std::ops::Index] for genome sequence types that do not store the characters in plain format.
     fn from_index_ref(index: u8) -> Result<&'static Self, AlphabetError>;
 
+    /// The rank of this character in the order that is used to decide if a sequence is [canonical](crate::interface::sequence::GenomeSequence::is_canonical).
+    ///
+    /// This order is independent of the order of the character indices, which is an implementation detail of the alphabet.
+    /// If not overwritten, the order is by index.
+    fn canonical_rank(&self) -> u8 {
+        self.index()
+    }
+
+    /// Returns true if this character is ambiguous, i.e. it stands for any of multiple characters, like the IUPAC code `N`.
+    fn is_ambiguous(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character denotes a gap, like `-` in alignments.
+    fn is_gap(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character denotes a stop codon, like `*` in protein sequences.
+    fn is_stop(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character stands only for purines, i.e. adenine or guanine.
+    fn is_purine(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character stands only for pyrimidines, i.e. cytosine, thymine or uracil.
+    fn is_pyrimidine(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character stands only for strong nucleotides, i.e. cytosine or guanine, which pair with three hydrogen bonds.
+    fn is_strong(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this character stands only for weak nucleotides, i.e. adenine, thymine or uracil, which pair with two hydrogen bonds.
+    fn is_weak(&self) -> bool {
+        false
+    }
+}
+
+/// A character in an alphabet that has a meaningful complement, such as a nucleotide.
+pub trait ComplementableAlphabetCharacter: AlphabetCharacter {
     /// Constructs the complement of this character.
     fn complement(&self) -> Self;
 }
@@ -55,6 +101,110 @@ pub trait Alphabet: Sized {
     }
 }
 
+/// An alphabet whose characters have a meaningful complement, such as a nucleotide alphabet.
+///
+/// This trait is implemented automatically for each alphabet whose character type is a [ComplementableAlphabetCharacter].
+/// Operations involving reverse complements are only available for such alphabets.
+///
+/// ```compile_fail
+/// use compact_genome::implementation::alphabets::iupac_amino_acid_alphabet::IupacAminoAcidAlphabet;
+/// use compact_genome::implementation::vec_sequence::VectorGenome;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let protein = VectorGenome::<IupacAminoAcidAlphabet>::from_slice_u8(b"MAGIC").unwrap();
+/// // Amino acids have no complement.
+/// protein.is_canonical();
+/// ```
+pub trait ComplementableAlphabet: Alphabet<CharacterType: ComplementableAlphabetCharacter> {}
+
+impl<AlphabetType: Alphabet<CharacterType: ComplementableAlphabetCharacter>> ComplementableAlphabet
+    for AlphabetType
+{
+}
+
+/// An alphabet of nucleotides, such as DNA or RNA.
+///
+/// Operations that interpret the characters of a sequence as nucleotides, such as hashing k-mers with ntHash or masking low-complexity regions with DUST, are only available for such alphabets.
+/// Unlike [ComplementableAlphabet], this trait is not implemented automatically, but needs to be declared for each nucleotide alphabet.
+///
+/// ```compile_fail
+/// use compact_genome::implementation::alphabets::ascii_alphabet::AsciiAlphabet;
+/// use compact_genome::implementation::vec_sequence::VectorGenome;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let text = VectorGenome::<AsciiAlphabet>::from_slice_u8(b"ACGTACGT").unwrap();
+/// // ASCII text is not made of nucleotides, even if it looks like it.
+/// text.nt_hash_iter(4);
+/// ```
+///
+/// The same holds for the GC and AT content.
+///
+/// ```compile_fail
+/// use compact_genome::implementation::alphabets::ascii_alphabet::AsciiAlphabet;
+/// use compact_genome::implementation::vec_sequence::VectorGenome;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let text = VectorGenome::<AsciiAlphabet>::from_slice_u8(b"ACGTACGT").unwrap();
+/// text.gc_content();
+/// ```
+///
+/// ```compile_fail
+/// use compact_genome::implementation::alphabets::iupac_amino_acid_alphabet::IupacAminoAcidAlphabet;
+/// use compact_genome::implementation::vec_sequence::VectorGenome;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+///
+/// let protein = VectorGenome::<IupacAminoAcidAlphabet>::from_slice_u8(b"MAGIC").unwrap();
+/// protein.composition().at_content();
+/// ```
+pub trait NucleotideAlphabet: ComplementableAlphabet {}
+
+/// A character that encodes a [Phred quality score](https://en.wikipedia.org/wiki/Phred_quality_score).
+pub trait PhredQualityCharacter: AlphabetCharacter {
+    /// The Phred quality score encoded by this character.
+    fn phred_score(&self) -> u8;
+
+    /// Constructs the character that encodes the given Phred quality score.
+    /// If the score cannot be represented exactly, then it is mapped to a representable score, e.g. by binning or clamping.
+    fn from_phred_score(score: u8) -> Self;
+
+    /// The probability that the base call qualified by this character is erroneous.
+    fn error_probability(&self) -> f64 {
+        10f64.powf(-f64::from(self.phred_score()) / 10.0)
+    }
+}
+
+/// An alphabet of [Phred quality scores](https://en.wikipedia.org/wiki/Phred_quality_score).
+///
+/// This trait is implemented automatically for each alphabet whose character type is a [PhredQualityCharacter].
+pub trait PhredQualityAlphabet: Alphabet<CharacterType: PhredQualityCharacter> {}
+
+impl<AlphabetType: Alphabet<CharacterType: PhredQualityCharacter>> PhredQualityAlphabet
+    for AlphabetType
+{
+}
+
+/// An alphabet whose characters carry a soft-mask bit, such as upper- and lower-case nucleotides.
+///
+/// Each character is the combination of a character of the [unmasked alphabet](SoftMaskedAlphabet::UnmaskedAlphabet) and a mask bit.
+pub trait SoftMaskedAlphabet: Alphabet {
+    /// The alphabet obtained by removing the mask bit from each character.
+    type UnmaskedAlphabet: Alphabet;
+
+    /// Returns true if the given character is masked.
+    fn is_masked(character: &Self::CharacterType) -> bool;
+
+    /// Returns the given character without its mask bit.
+    fn unmask(
+        character: &Self::CharacterType,
+    ) -> <Self::UnmaskedAlphabet as Alphabet>::CharacterType;
+
+    /// Returns the character that consists of the given unmasked character and the given mask bit.
+    fn mask(
+        character: &<Self::UnmaskedAlphabet as Alphabet>::CharacterType,
+        masked: bool,
+    ) -> Self::CharacterType;
+}
+
 /// An error when dealing with alphabets.
 #[derive(Debug, Clone, Eq, PartialEq, Error)]
 pub enum AlphabetError {
@@ -1,7 +1,9 @@
 //! K-mers are sequences of length k.
 
-use crate::interface::alphabet::Alphabet;
+use crate::interface::alphabet::{Alphabet, AlphabetCharacter};
 use crate::interface::sequence::{GenomeSequence, GenomeSequenceMut, OwnedGenomeSequence};
+use std::cmp::Ordering;
+use std::iter;
 
 /// A sequence of fixed length k.
 /// Fixing the length allows for more efficient representations, such as arrays.
@@ -15,6 +17,39 @@ pub trait Kmer<
     fn k() -> usize {
         K
     }
+
+    /// Returns the rank of this k-mer in the lexicographic order of all sequences of its length over its alphabet.
+    ///
+    /// The characters are ordered by their [index](AlphabetCharacter::index), and the first character is the most significant digit.
+    /// Hence, the result is smaller than `AlphabetType::SIZE` to the power of the length of this k-mer.
+    /// This is the order of the `Ord` implementations of the k-mer types of this crate, and [OwnedKmer::from_index] is its inverse.
+    ///
+    /// The order by index is an implementation detail of the alphabet, and may differ from the order by [canonical rank](Kmer::to_canonical_index).
+    ///
+    /// Panics if the rank does not fit into a `u128`.
+    fn to_index(&self) -> u128 {
+        self.iter().fold(0, |index, character| {
+            index
+                .checked_mul(AlphabetType::SIZE.into())
+                .and_then(|index| index.checked_add(character.index().into()))
+                .expect("the k-mer index does not fit into a u128")
+        })
+    }
+
+    /// Returns the rank of this k-mer in the lexicographic order of all sequences of its length over its alphabet, where the characters are ordered by their [canonical rank](AlphabetCharacter::canonical_rank).
+    ///
+    /// This is the order of [OwnedKmer::canonical_cmp], which decides if a k-mer is [canonical](GenomeSequence::is_canonical), and the order by which the [sampling schemes](crate::interface::sequence::sampling) rank k-mers.
+    /// It is equal to the [order by index](Kmer::to_index) if the canonical rank of each character is equal to its index.
+    ///
+    /// Panics if the rank does not fit into a `u128`.
+    fn to_canonical_index(&self) -> u128 {
+        self.iter().fold(0, |index, character| {
+            index
+                .checked_mul(AlphabetType::SIZE.into())
+                .and_then(|index| index.checked_add(character.canonical_rank().into()))
+                .expect("the k-mer index does not fit into a u128")
+        })
+    }
 }
 
 /// An owned k-mer.
@@ -28,6 +63,48 @@ pub trait OwnedKmer<
     ///
     /// This works by shifting the k-mer to the left and adding the character at the end.
     fn successor(&self, successor: AlphabetType::CharacterType) -> Self;
+
+    /// Constructs the k-mer with the given [lexicographic rank](Kmer::to_index).
+    ///
+    /// The index must be smaller than `AlphabetType::SIZE` to the power of `K`, otherwise its higher digits are ignored.
+    fn from_index(mut index: u128) -> Self {
+        let size = u128::from(AlphabetType::SIZE);
+        let first_character = AlphabetType::CharacterType::from_index(0)
+            .expect("the alphabet contains at least one character");
+
+        // Prepend the digits from least to most significant.
+        (0..K).fold(
+            iter::repeat(first_character).take(K).collect(),
+            |k_mer: Self, _| {
+                let digit = (index % size) as u8;
+                index /= size;
+                k_mer.predecessor(
+                    AlphabetType::CharacterType::from_index(digit)
+                        .expect("the digit is smaller than the alphabet size"),
+                )
+            },
+        )
+    }
+
+    /// Get the predecessor of this k-mer with the specified character.
+    ///
+    /// This works by shifting the k-mer to the right and adding the character at the start.
+    fn predecessor(&self, predecessor: AlphabetType::CharacterType) -> Self {
+        iter::once(predecessor)
+            .chain(self.iter().take(K.saturating_sub(1)).cloned())
+            .take(K)
+            .collect()
+    }
+
+    /// Compares this k-mer lexicographically to the given k-mer, where the characters are ordered by their [canonical rank](AlphabetCharacter::canonical_rank).
+    ///
+    /// This is the order used to decide if a k-mer is [canonical](GenomeSequence::is_canonical).
+    /// Implementations with a packed representation may override this with a faster comparison.
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        self.iter()
+            .map(AlphabetCharacter::canonical_rank)
+            .cmp(other.iter().map(AlphabetCharacter::canonical_rank))
+    }
 }
 
 /// A k-mer whose characters can be mutated.
@@ -37,3 +114,65 @@ pub trait KmerMut<
 >: GenomeSequenceMut<AlphabetType, GenomeSubsequenceMut>
 {
 }
+
+/// Runs code that needs the k-mer length at compile time with a k-mer length chosen at runtime.
+///
+/// `dispatch_k!(k, K => expression)` evaluates `expression` with a constant `K` that is equal to `k`, for `k` between 1 and 32.
+/// The supported lengths can be listed explicitly as `dispatch_k!(k, [15, 21, 31], K => expression)`.
+/// If `k` is not supported, the macro panics, unless a fallback is given as `dispatch_k!(k, [15, 21, 31], K => expression, _ => fallback)`.
+///
+/// The expression is compiled once for each supported length, so long lists increase compile times.
+/// For k-mer lengths that are not supported, [DynamicBitArrayKmer](crate::implementation::dynamic_bit_array_kmer::DynamicBitArrayKmer) needs no dispatch.
+///
+/// ```rust
+/// use compact_genome::dispatch_k;
+/// use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+/// use compact_genome::implementation::bit_array_kmer::BitArrayKmer;
+/// use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+/// use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+/// use std::collections::HashSet;
+///
+/// fn count_distinct<const K: usize>(genome: &BitVectorGenome<DnaAlphabet>) -> usize {
+///     genome
+///         .cloned_k_mer_iter::<K, BitArrayKmer<K, DnaAlphabet>>()
+///         .collect::<HashSet<_>>()
+///         .len()
+/// }
+///
+/// let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"AAAAC").unwrap();
+/// let k = 2; // E.g. parsed from the command line.
+/// assert_eq!(dispatch_k!(k, K => count_distinct::<K>(&genome)), 2);
+/// assert_eq!(dispatch_k!(k + 1, [1, 2, 3], K => count_distinct::<K>(&genome)), 2);
+/// assert_eq!(dispatch_k!(k, [1, 3], K => Some(count_distinct::<K>(&genome)), _ => None), None);
+/// ```
+#[macro_export]
+macro_rules! dispatch_k {
+    ($k:expr, [$($supported:literal),+ $(,)?], $const_k:ident => $body:expr, _ => $fallback:expr $(,)?) => {
+        match $k {
+            $($supported => {
+                const $const_k: usize = $supported;
+                $body
+            })+
+            _ => $fallback,
+        }
+    };
+    ($k:expr, [$($supported:literal),+ $(,)?], $const_k:ident => $body:expr $(,)?) => {
+        match $k {
+            $($supported => {
+                const $const_k: usize = $supported;
+                $body
+            })+
+            k => panic!("unsupported k-mer length {}", k),
+        }
+    };
+    ($k:expr, $const_k:ident => $body:expr $(,)?) => {
+        $crate::dispatch_k!(
+            $k,
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+                24, 25, 26, 27, 28, 29, 30, 31, 32
+            ],
+            $const_k => $body
+        )
+    };
+}
@@ -0,0 +1,108 @@
+//! Traits for counting the k-mers of genome sequences.
+
+use crate::interface::alphabet::{Alphabet, ComplementableAlphabet};
+use crate::interface::k_mer::OwnedKmer;
+use crate::interface::sequence::GenomeSequence;
+
+/// A data structure that counts how often k-mers occur.
+pub trait KmerCounter<KmerType> {
+    /// Adds the given number of occurrences of the given k-mer.
+    fn add_count(&mut self, k_mer: &KmerType, count: u64);
+
+    /// Adds one occurrence of the given k-mer.
+    fn add(&mut self, k_mer: &KmerType) {
+        self.add_count(k_mer, 1);
+    }
+
+    /// Returns the number of occurrences of the given k-mer.
+    /// Approximate counters may overestimate this number.
+    fn count(&self, k_mer: &KmerType) -> u64;
+
+    /// Returns the number of occurrences of all k-mers.
+    fn total_count(&self) -> u64;
+
+    /// Adds the occurrences counted by the given counter to this counter.
+    fn merge(&mut self, other: &Self);
+
+    /// Adds the occurrences of all k-mers of the given sequence.
+    fn add_k_mers_of<
+        const K: usize,
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &mut self,
+        sequence: &SequenceType,
+    ) where
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    {
+        for k_mer in sequence.cloned_k_mer_iter::<K, KmerType>() {
+            self.add(&k_mer);
+        }
+    }
+
+    /// Adds the occurrences of all canonical k-mers of the given sequence.
+    /// This way, each k-mer is counted together with its reverse complement.
+    ///
+    /// See [canonical_k_mer_iter](GenomeSequence::canonical_k_mer_iter).
+    fn add_canonical_k_mers_of<
+        const K: usize,
+        AlphabetType: ComplementableAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &mut self,
+        sequence: &SequenceType,
+    ) where
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    {
+        for (k_mer, _) in sequence.canonical_k_mer_iter::<K, KmerType>() {
+            self.add(&k_mer);
+        }
+    }
+}
+
+/// A k-mer counter that knows its counted k-mers.
+pub trait EnumerableKmerCounter<KmerType>: KmerCounter<KmerType> {
+    /// Returns an iterator over all k-mers that occur at least once, together with their number of occurrences, in arbitrary order.
+    fn iter(&self) -> impl Iterator<Item = (KmerType, u64)>;
+
+    /// Returns the number of distinct k-mers that occur at least once.
+    fn distinct_count(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns all k-mers that occur at least once, together with their number of occurrences.
+    /// The k-mers are ordered by decreasing number of occurrences, and k-mers with the same number of occurrences are ordered ascendingly.
+    fn by_abundance(&self) -> Vec<(KmerType, u64)>
+    where
+        KmerType: Ord,
+    {
+        let mut result: Vec<_> = self.iter().collect();
+        result.sort_unstable_by(|(k_mer, count), (other_k_mer, other_count)| {
+            other_count.cmp(count).then_with(|| k_mer.cmp(other_k_mer))
+        });
+        result
+    }
+
+    /// Returns the k-mer spectrum, i.e. pairs of a number of occurrences and the number of distinct k-mers with that many occurrences.
+    /// The pairs are ordered by increasing number of occurrences, and only numbers of occurrences of at least one k-mer are reported.
+    fn spectrum(&self) -> Vec<(u64, usize)> {
+        spectrum(self.iter().map(|(_, count)| count))
+    }
+}
+
+/// Returns the spectrum of the given numbers of occurrences, as in [EnumerableKmerCounter::spectrum].
+pub(crate) fn spectrum(counts: impl IntoIterator<Item = u64>) -> Vec<(u64, usize)> {
+    let mut counts: Vec<_> = counts.into_iter().filter(|&count| count > 0).collect();
+    counts.sort_unstable();
+
+    let mut result: Vec<(u64, usize)> = Vec::new();
+    for count in counts {
+        match result.last_mut() {
+            Some((last_count, frequency)) if *last_count == count => *frequency += 1,
+            _ => result.push((count, 1)),
+        }
+    }
+    result
+}
@@ -1,14 +1,38 @@
 //! Traits for genome sequences.
 
-use crate::interface::alphabet::{Alphabet, AlphabetCharacter, AlphabetError};
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, AlphabetError, ComplementableAlphabet,
+    ComplementableAlphabetCharacter, NucleotideAlphabet, PhredQualityAlphabet,
+    PhredQualityCharacter, SoftMaskedAlphabet,
+};
 use crate::interface::k_mer::OwnedKmer;
+use crate::interface::sequence::composition::Composition;
+use crate::interface::sequence::nt_hash::{NtHash, NtHashConstIterator, NtHashIterator};
+use crate::interface::sequence::repeats::{Palindrome, TandemRepeat};
+use crate::interface::sequence::sampling::{KmerOrder, SampledKmer, SyncmerKind};
+use crate::interface::sequence::search::approximate::{
+    ApproximatePattern, EditSearchIterator, HammingSearchIterator,
+};
+use crate::interface::sequence::search::{BothStrandsFindIterator, FindIterator};
+use crate::interface::sequence::spaced_seed::{SpacedKmer, SpacedSeed};
 use std::cmp::Ordering;
 use std::iter;
-use std::iter::{FromIterator, Map, Repeat, Rev, Zip};
-use std::ops::Range;
+use std::iter::{FromIterator, Map, Rev};
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
 use traitsequence::interface::{EditableSequence, OwnedSequence, Sequence, SequenceMut};
 
+pub mod composition;
+pub mod genetic_code;
+pub mod low_complexity;
 pub mod neighbor_iterators;
+pub mod nt_hash;
+pub mod orf;
+pub mod repeats;
+pub mod restriction;
+pub mod sampling;
+pub mod search;
+pub mod spaced_seed;
 
 /// An iterator over the reverse complement of a genome sequence.
 pub type ReverseComplementIterator<I, AlphabetType> = Map<
@@ -18,12 +42,171 @@ pub type ReverseComplementIterator<I, AlphabetType> = Map<
     ) -> <AlphabetType as Alphabet>::CharacterType,
 >;
 
-/// An iterator over the cloned k-mers of a genome sequence.
-pub type OwnedKmerIterator<'a, GenomeSequenceType, KmerType> = Map<
-    Zip<Range<usize>, Repeat<&'a GenomeSequenceType>>,
-    fn((usize, &'a GenomeSequenceType)) -> KmerType,
+/// An iterator over the characters of a soft-masked genome sequence with their mask bit removed.
+pub type UnmaskedIterator<I, AlphabetType> = Map<
+    I,
+    for<'c> fn(
+        &'c <AlphabetType as Alphabet>::CharacterType,
+    ) -> <<AlphabetType as SoftMaskedAlphabet>::UnmaskedAlphabet as Alphabet>::CharacterType,
 >;
 
+/// An iterator over the cloned k-mers of a genome sequence.
+///
+/// The first k-mer is built once, and each further k-mer is computed from its predecessor with [OwnedKmer::successor].
+pub struct OwnedKmerIterator<
+    const K: usize,
+    AlphabetType: Alphabet,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+> {
+    /// The characters after the next k-mer.
+    characters: CharacterIterator,
+    next_k_mer: Option<KmerType>,
+    phantom_data: PhantomData<AlphabetType>,
+    phantom_subsequence: PhantomData<GenomeSubsequence>,
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > OwnedKmerIterator<K, AlphabetType, GenomeSubsequence, KmerType, CharacterIterator>
+{
+    /// Creates a new k-mer iterator over the given characters, of which there are `len`.
+    fn new(mut characters: CharacterIterator, len: usize) -> Self {
+        let next_k_mer = (len >= K).then(|| characters.by_ref().take(K).collect());
+        Self {
+            characters,
+            next_k_mer,
+            phantom_data: Default::default(),
+            phantom_subsequence: Default::default(),
+        }
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: Alphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > Iterator
+    for OwnedKmerIterator<K, AlphabetType, GenomeSubsequence, KmerType, CharacterIterator>
+{
+    type Item = KmerType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let k_mer = self.next_k_mer.take()?;
+        self.next_k_mer = self
+            .characters
+            .next()
+            .map(|character| k_mer.successor(character));
+        Some(k_mer)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next_k_mer.is_some() {
+            let (lower, upper) = self.characters.size_hint();
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+/// An iterator over the canonical k-mers of a genome sequence together with the strand they were taken from.
+///
+/// The k-mer and its reverse complement are both updated in constant time per character, with [OwnedKmer::successor] and [OwnedKmer::predecessor] respectively.
+/// Both are compared with [OwnedKmer::canonical_cmp], which compares whole words of characters at once for packed k-mers like [BitArrayKmer](crate::implementation::bit_array_kmer::BitArrayKmer).
+/// A k-mer is canonical if it is lexicographically smaller or equal to its reverse complement, see [GenomeSequence::is_canonical].
+/// If a k-mer equals its reverse complement, then its strand is [Strand::Forward].
+pub struct CanonicalKmerIterator<
+    const K: usize,
+    AlphabetType: ComplementableAlphabet,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+> {
+    /// The characters after the next k-mer.
+    characters: CharacterIterator,
+    /// The next k-mer and its reverse complement.
+    next_k_mers: Option<(KmerType, KmerType)>,
+    phantom_data: PhantomData<AlphabetType>,
+    phantom_subsequence: PhantomData<GenomeSubsequence>,
+}
+
+impl<
+        const K: usize,
+        AlphabetType: ComplementableAlphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > CanonicalKmerIterator<K, AlphabetType, GenomeSubsequence, KmerType, CharacterIterator>
+{
+    /// Creates a new canonical k-mer iterator over the given characters, of which there are `len`.
+    fn new(mut characters: CharacterIterator, len: usize) -> Self {
+        let next_k_mers = (len >= K).then(|| {
+            let forward: KmerType = characters.by_ref().take(K).collect();
+            let reverse_complement = forward.clone_as_reverse_complement();
+            (forward, reverse_complement)
+        });
+        Self {
+            characters,
+            next_k_mers,
+            phantom_data: Default::default(),
+            phantom_subsequence: Default::default(),
+        }
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: ComplementableAlphabet,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > Iterator
+    for CanonicalKmerIterator<K, AlphabetType, GenomeSubsequence, KmerType, CharacterIterator>
+{
+    type Item = (KmerType, Strand);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (forward, reverse_complement) = self.next_k_mers.take()?;
+        let is_canonical = forward.canonical_cmp(&reverse_complement).is_le();
+
+        self.next_k_mers = self.characters.next().map(|character| {
+            (
+                forward.successor(character.clone()),
+                reverse_complement.predecessor(character.complement()),
+            )
+        });
+
+        Some(if is_canonical {
+            (forward, Strand::Forward)
+        } else {
+            (reverse_complement, Strand::ReverseComplement)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next_k_mers.is_some() {
+            let (lower, upper) = self.characters.size_hint();
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
 /// A genome sequence.
 pub trait GenomeSequence<
     AlphabetType: Alphabet,
@@ -56,9 +239,10 @@ pub trait GenomeSequence<
 
     /// Returns an iterator over the reverse complement of this genome.
     /// Panics if the iterator his an invalid character (see [not valid](GenomeSequence::is_valid)).
-    fn reverse_complement_iter(
-        &self,
-    ) -> ReverseComplementIterator<Self::Iterator<'_>, AlphabetType> {
+    fn reverse_complement_iter(&self) -> ReverseComplementIterator<Self::Iterator<'_>, AlphabetType>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
         self.iter()
             .rev()
             .map(AlphabetType::CharacterType::complement)
@@ -71,12 +255,307 @@ pub trait GenomeSequence<
         KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
     >(
         &self,
-    ) -> OwnedKmerIterator<'_, Self, KmerType> {
-        (0..self.len() - K + 1)
-            .zip(iter::repeat(self))
-            .map(|(offset, source_genome)| {
-                source_genome.iter().skip(offset).take(K).cloned().collect()
-            })
+    ) -> OwnedKmerIterator<
+        K,
+        AlphabetType,
+        GenomeSubsequence,
+        KmerType,
+        iter::Cloned<Self::Iterator<'_>>,
+    > {
+        OwnedKmerIterator::new(self.iter().cloned(), self.len())
+    }
+
+    /// Returns an iterator over the canonical k-mers of this genome, each with the strand it was taken from.
+    /// The k-mers are cloned from this genome.
+    ///
+    /// Panics if this genome is [not valid](GenomeSequence::is_valid).
+    fn canonical_k_mer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    >(
+        &self,
+    ) -> CanonicalKmerIterator<
+        K,
+        AlphabetType,
+        GenomeSubsequence,
+        KmerType,
+        iter::Cloned<Self::Iterator<'_>>,
+    >
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        CanonicalKmerIterator::new(self.iter().cloned(), self.len())
+    }
+
+    /// Returns an iterator over the [ntHash](nt_hash) values of the k-mers of this genome.
+    /// K-mers that contain characters other than nucleotides are skipped.
+    ///
+    /// This takes `k` at runtime, e.g. to choose it from user input.
+    /// For a `k` known at compile time, see [nt_hash_iter_const](Self::nt_hash_iter_const).
+    ///
+    /// Panics if `k` is zero.
+    fn nt_hash_iter(
+        &self,
+        k: usize,
+    ) -> NtHashIterator<AlphabetType, iter::Cloned<Self::Iterator<'_>>>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        NtHashIterator::new(self.iter().cloned(), k)
+    }
+
+    /// Returns an iterator over the [ntHash](nt_hash) values of the k-mers of this genome, where `K` is known at compile time.
+    /// K-mers that contain characters other than nucleotides are skipped.
+    ///
+    /// The hash values are the same as those of [nt_hash_iter](Self::nt_hash_iter) with the same k.
+    /// Fails to compile if `K` is zero.
+    fn nt_hash_iter_const<const K: usize>(
+        &self,
+    ) -> NtHashConstIterator<K, AlphabetType, iter::Cloned<Self::Iterator<'_>>>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        NtHashConstIterator::new(self.iter().cloned())
+    }
+
+    /// Returns the [ntHash](nt_hash) values of this genome as a whole, e.g. of a k-mer.
+    /// Returns `None` if this genome is empty or contains characters other than nucleotides.
+    fn nt_hash(&self) -> Option<NtHash>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            self.nt_hash_iter(self.len()).next()
+        }
+    }
+
+    /// Returns an iterator over the (w, k)-minimizers of this genome, see [sampling].
+    ///
+    /// Each window of `w` consecutive k-mers selects its smallest k-mer in the given order, where ties are broken by preferring the leftmost k-mer.
+    /// Windows that select the same k-mer report it only once, hence the k-mers are reported with increasing positions.
+    /// All k-mers are reported on the [forward](Strand::Forward) strand.
+    ///
+    /// Fails to compile if the [lexicographic index](sampling#limits) of the k-mers does not fit into a `u128`.
+    /// Panics if `w` is zero.
+    fn minimizer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        w: usize,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>> {
+        let () = sampling::AssertIndexFits::<K, AlphabetType>::ASSERT;
+        sampling::minimizer_positions(
+            self.iter().map(sampling::forward_character_digits),
+            AlphabetType::SIZE,
+            K,
+            w,
+            order,
+            false,
+        )
+        .map(move |position| sampling::sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the canonical (w, k)-minimizers of this genome, see [sampling].
+    ///
+    /// Like [minimizer_iter](GenomeSequence::minimizer_iter), but k-mers are ranked equally to their reverse complement, and reported on the strand on which they are canonical.
+    ///
+    /// Fails to compile if the [lexicographic index](sampling#limits) of the k-mers does not fit into a `u128`.
+    /// Panics if `w` is zero.
+    fn canonical_minimizer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        w: usize,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        let () = sampling::AssertIndexFits::<K, AlphabetType>::ASSERT;
+        sampling::minimizer_positions(
+            self.iter().map(sampling::character_digits),
+            AlphabetType::SIZE,
+            K,
+            w,
+            order,
+            true,
+        )
+        .map(move |position| sampling::canonical_sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the mod-minimizers of this genome, see [sampling].
+    ///
+    /// Each window of `w` consecutive k-mers selects the k-mer whose offset in the window is the offset of the smallest t-mer in the window modulo `w`, where `t = r + (k - r) mod w`.
+    /// For long k-mers, this results in a lower density than [minimizers](GenomeSequence::minimizer_iter), and if `k <= r`, then mod-minimizers are minimizers.
+    /// Ties and strands are as for [minimizers](GenomeSequence::minimizer_iter).
+    ///
+    /// Panics if `w` is zero, or if the [lexicographic index](sampling#limits) of the t-mers does not fit into a `u128`.
+    fn mod_minimizer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        w: usize,
+        r: usize,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>> {
+        sampling::mod_minimizer_positions(
+            self.iter().map(sampling::forward_character_digits),
+            AlphabetType::SIZE,
+            K,
+            w,
+            r,
+            order,
+            false,
+        )
+        .map(move |position| sampling::sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the canonical mod-minimizers of this genome, see [sampling].
+    ///
+    /// Like [mod_minimizer_iter](GenomeSequence::mod_minimizer_iter), but with ties and strands as for [canonical minimizers](GenomeSequence::canonical_minimizer_iter).
+    ///
+    /// Panics if `w` is zero, or if the [lexicographic index](sampling#limits) of the t-mers does not fit into a `u128`.
+    fn canonical_mod_minimizer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        w: usize,
+        r: usize,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        sampling::mod_minimizer_positions(
+            self.iter().map(sampling::character_digits),
+            AlphabetType::SIZE,
+            K,
+            w,
+            r,
+            order,
+            true,
+        )
+        .map(move |position| sampling::canonical_sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the open or closed syncmers of this genome, see [sampling].
+    ///
+    /// A k-mer is a syncmer if its smallest s-mer in the given order is at the position given by the [kind](SyncmerKind), where ties are broken by preferring the leftmost s-mer.
+    /// All k-mers are reported on the [forward](Strand::Forward) strand.
+    ///
+    /// Panics if `s` is zero or larger than `K`, or if the [lexicographic index](sampling#limits) of the s-mers does not fit into a `u128`.
+    fn syncmer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        s: usize,
+        kind: SyncmerKind,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>> {
+        sampling::syncmer_positions(
+            self.iter().map(sampling::forward_character_digits),
+            AlphabetType::SIZE,
+            K,
+            s,
+            kind,
+            order,
+            false,
+        )
+        .map(move |position| sampling::sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the canonical open or closed syncmers of this genome, see [sampling].
+    ///
+    /// Like [syncmer_iter](GenomeSequence::syncmer_iter), but s-mers are ranked equally to their reverse complement, and k-mers are reported on the strand on which they are canonical.
+    ///
+    /// Panics if `s` is zero or larger than `K`, or if the [lexicographic index](sampling#limits) of the s-mers does not fit into a `u128`.
+    fn canonical_syncmer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+        Order: KmerOrder,
+    >(
+        &self,
+        s: usize,
+        kind: SyncmerKind,
+        order: Order,
+    ) -> impl Iterator<Item = SampledKmer<KmerType>>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        sampling::syncmer_positions(
+            self.iter().map(sampling::character_digits),
+            AlphabetType::SIZE,
+            K,
+            s,
+            kind,
+            order,
+            true,
+        )
+        .map(move |position| sampling::canonical_sampled_k_mer(self, position))
+    }
+
+    /// Returns an iterator over the k-mers extracted by the given spaced seeds from this genome, see [spaced_seed].
+    ///
+    /// Each seed is applied to each window of its span.
+    /// The k-mers are ordered by the position of their window, and then by the index of their seed.
+    ///
+    /// Panics if the weight of a seed is not `K`.
+    fn spaced_seed_iter<const K: usize, KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>>(
+        &self,
+        seeds: &[SpacedSeed],
+    ) -> impl Iterator<Item = SpacedKmer<KmerType>> {
+        spaced_seed::assert_weights(seeds, K);
+        (0..self.len()).flat_map(move |position| {
+            seeds
+                .iter()
+                .enumerate()
+                .filter(move |(_, seed)| position + seed.span() <= self.len())
+                .map(move |(seed_index, seed)| {
+                    spaced_seed::spaced_k_mer(self, seed_index, seed, position)
+                })
+        })
+    }
+
+    /// Returns an iterator over the canonical k-mers extracted by the given spaced seeds from this genome, see [spaced_seed].
+    ///
+    /// Each seed is applied to each window of its span and to the reverse complement of the window, and the smaller k-mer is reported.
+    /// Ties are broken by preferring the forward strand.
+    /// The k-mers are ordered by the position of their window, and then by the index of their seed.
+    ///
+    /// Panics if the weight of a seed is not `K`.
+    fn canonical_spaced_seed_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+    >(
+        &self,
+        seeds: &[SpacedSeed],
+    ) -> impl Iterator<Item = SpacedKmer<KmerType>>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        spaced_seed::assert_weights(seeds, K);
+        (0..self.len()).flat_map(move |position| {
+            seeds
+                .iter()
+                .enumerate()
+                .filter(move |(_, seed)| position + seed.span() <= self.len())
+                .map(move |(seed_index, seed)| {
+                    spaced_seed::canonical_spaced_k_mer(self, seed_index, seed, position)
+                })
+        })
     }
 
     /// Returns an owned copy of the reverse complement of this genome.
@@ -86,7 +565,10 @@ pub trait GenomeSequence<
         ReverseComplementSubsequence: GenomeSequence<AlphabetType, ReverseComplementSubsequence> + ?Sized,
     >(
         &self,
-    ) -> ReverseComplementSequence {
+    ) -> ReverseComplementSequence
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
         self.reverse_complement_iter().collect()
     }
 
@@ -102,11 +584,19 @@ pub trait GenomeSequence<
 
     /// Returns true if the genome is canonical.
     /// A canonical genome is lexicographically smaller or equal to its reverse complement.
-    fn is_canonical(&self) -> bool {
+    ///
+    /// Characters are ordered by their [canonical rank](AlphabetCharacter::canonical_rank).
+    fn is_canonical(&self) -> bool
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
         for (forward_character, reverse_character) in
             self.iter().cloned().zip(self.reverse_complement_iter())
         {
-            match forward_character.cmp(&reverse_character) {
+            match forward_character
+                .canonical_rank()
+                .cmp(&reverse_character.canonical_rank())
+            {
                 Ordering::Less => return true,
                 Ordering::Greater => return false,
                 _ => {}
@@ -117,9 +607,482 @@ pub trait GenomeSequence<
 
     /// Returns true if the genome is self-complemental.
     /// A self-complemental genome is equivalent to its reverse complement.
-    fn is_self_complemental(&self) -> bool {
+    fn is_self_complemental(&self) -> bool
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
         self.iter().cloned().eq(self.reverse_complement_iter())
     }
+
+    /// Returns an iterator over the characters of this genome with their mask bit removed.
+    fn unmasked_iter(&self) -> UnmaskedIterator<Self::Iterator<'_>, AlphabetType>
+    where
+        AlphabetType: SoftMaskedAlphabet,
+    {
+        self.iter().map(AlphabetType::unmask)
+    }
+
+    /// Returns an iterator over the k-mers of this genome with their mask bit removed.
+    /// The k-mers are cloned from this genome.
+    fn cloned_unmasked_k_mer_iter<
+        const K: usize,
+        KmerType: OwnedKmer<K, AlphabetType::UnmaskedAlphabet, KmerSubsequence>,
+        KmerSubsequence: GenomeSequence<AlphabetType::UnmaskedAlphabet, KmerSubsequence> + ?Sized,
+    >(
+        &self,
+    ) -> impl Iterator<Item = KmerType>
+    where
+        AlphabetType: SoftMaskedAlphabet,
+    {
+        OwnedKmerIterator::<K, _, KmerSubsequence, KmerType, _>::new(
+            self.unmasked_iter(),
+            self.len(),
+        )
+    }
+
+    /// Returns true if this genome equals the given genome when ignoring the mask bits.
+    fn eq_ignoring_mask<
+        OtherSequence: GenomeSequence<AlphabetType, OtherSubsequence> + ?Sized,
+        OtherSubsequence: GenomeSequence<AlphabetType, OtherSubsequence> + ?Sized,
+    >(
+        &self,
+        other: &OtherSequence,
+    ) -> bool
+    where
+        AlphabetType: SoftMaskedAlphabet,
+    {
+        self.unmasked_iter().eq(other.unmasked_iter())
+    }
+
+    /// Compares this genome lexicographically with the given genome when ignoring the mask bits.
+    fn cmp_ignoring_mask<
+        OtherSequence: GenomeSequence<AlphabetType, OtherSubsequence> + ?Sized,
+        OtherSubsequence: GenomeSequence<AlphabetType, OtherSubsequence> + ?Sized,
+    >(
+        &self,
+        other: &OtherSequence,
+    ) -> Ordering
+    where
+        AlphabetType: SoftMaskedAlphabet,
+    {
+        self.unmasked_iter().cmp(other.unmasked_iter())
+    }
+
+    /// Returns true if the genome is canonical when ignoring the mask bits.
+    /// See [is_canonical](GenomeSequence::is_canonical).
+    fn is_canonical_ignoring_mask(&self) -> bool
+    where
+        AlphabetType: SoftMaskedAlphabet,
+        AlphabetType::UnmaskedAlphabet: ComplementableAlphabet,
+    {
+        self.unmasked_iter()
+            .map(|character| character.canonical_rank())
+            .cmp(
+                self.unmasked_iter()
+                    .rev()
+                    .map(|character| character.complement().canonical_rank()),
+            )
+            .is_le()
+    }
+
+    /// Returns true if the genome is self-complemental when ignoring the mask bits.
+    /// See [is_self_complemental](GenomeSequence::is_self_complemental).
+    fn is_self_complemental_ignoring_mask(&self) -> bool
+    where
+        AlphabetType: SoftMaskedAlphabet,
+        AlphabetType::UnmaskedAlphabet: ComplementableAlphabet,
+    {
+        self.unmasked_iter().eq(self
+            .unmasked_iter()
+            .rev()
+            .map(|character| character.complement()))
+    }
+
+    /// Returns the arithmetic mean of the Phred quality scores of this sequence, or `None` if it is empty.
+    fn mean_phred_score(&self) -> Option<f64>
+    where
+        AlphabetType: PhredQualityAlphabet,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            let sum: u64 = self
+                .iter()
+                .map(|character| u64::from(character.phred_score()))
+                .sum();
+            Some(sum as f64 / self.len() as f64)
+        }
+    }
+
+    /// Returns the expected number of erroneous base calls qualified by this sequence of Phred quality scores.
+    ///
+    /// This is the sum of the [error probabilities](PhredQualityCharacter::error_probability) of the characters.
+    fn expected_errors(&self) -> f64
+    where
+        AlphabetType: PhredQualityAlphabet,
+    {
+        self.iter()
+            .map(PhredQualityCharacter::error_probability)
+            .sum()
+    }
+
+    /// Returns the mean error probability of the base calls qualified by this sequence of Phred quality scores, or `None` if it is empty.
+    ///
+    /// The result can be converted back into a Phred quality score, which is more meaningful than the [arithmetic mean of the scores](GenomeSequence::mean_phred_score).
+    fn mean_error_probability(&self) -> Option<f64>
+    where
+        AlphabetType: PhredQualityAlphabet,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.expected_errors() / self.len() as f64)
+        }
+    }
+
+    /// Returns the range of this sequence of Phred quality scores that remains after quality trimming both ends with the given threshold.
+    ///
+    /// This uses the algorithm of BWA, which trims the suffix (or prefix) that maximises the sum of `threshold - score` over its characters.
+    /// If the whole sequence is trimmed, then an empty range is returned.
+    fn quality_trim_range(&self, threshold: u8) -> Range<usize>
+    where
+        AlphabetType: PhredQualityAlphabet,
+    {
+        fn trim_position<'a, CharacterType: PhredQualityCharacter + 'a>(
+            characters: impl Iterator<Item = (usize, &'a CharacterType)>,
+            threshold: u8,
+            mut position: usize,
+        ) -> usize {
+            let mut sum = 0i64;
+            let mut max = 0i64;
+            for (index, character) in characters {
+                sum += i64::from(threshold) - i64::from(character.phred_score());
+                if sum < 0 {
+                    break;
+                }
+                if sum > max {
+                    max = sum;
+                    position = index;
+                }
+            }
+            position
+        }
+
+        let start = trim_position(self.iter().enumerate(), threshold, usize::MAX).wrapping_add(1);
+        let end = trim_position(
+            (start..self.len()).rev().map(|index| (index, &self[index])),
+            threshold,
+            self.len(),
+        );
+        start..end
+    }
+
+    /// Returns true if the given pattern occurs in this genome at the given offset.
+    /// If the pattern would reach over the end of this genome, then false is returned.
+    ///
+    /// This is the primitive used by [find](GenomeSequence::find) and related methods.
+    /// Implementations with a packed representation may override it with a faster comparison.
+    fn matches_at(&self, offset: usize, pattern: &GenomeSubsequence) -> bool {
+        offset
+            .checked_add(pattern.len())
+            .is_some_and(|end| end <= self.len())
+            && self[offset..offset + pattern.len()]
+                .iter()
+                .eq(pattern.iter())
+    }
+
+    /// Returns the length of the longest common prefix of this genome and the given genome.
+    ///
+    /// Implementations with a packed representation may override this with a faster comparison.
+    fn common_prefix_len(&self, other: &GenomeSubsequence) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns the number of positions at which this genome and the given genome differ.
+    ///
+    /// Implementations with a packed representation may override this with a faster comparison.
+    ///
+    /// Panics if the genomes have different lengths.
+    fn hamming_distance(&self, other: &GenomeSubsequence) -> usize {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "the hamming distance is only defined for genomes of equal length"
+        );
+        self.iter()
+            .zip(other.iter())
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// Returns the positions at which this genome and the given genome differ, in increasing order.
+    ///
+    /// Implementations with a packed representation may override this with a faster comparison.
+    ///
+    /// Panics if the genomes have different lengths.
+    fn mismatch_positions(&self, other: &GenomeSubsequence) -> Vec<usize> {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "mismatch positions are only defined for genomes of equal length"
+        );
+        self.iter()
+            .zip(other.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the offset of the first occurrence of the given pattern in this genome, or `None` if it does not occur.
+    fn find<Pattern: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized>(
+        &self,
+        pattern: &Pattern,
+    ) -> Option<usize> {
+        self.find_iter(pattern).next()
+    }
+
+    /// Returns the offset of the last occurrence of the given pattern in this genome, or `None` if it does not occur.
+    fn rfind<Pattern: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized>(
+        &self,
+        pattern: &Pattern,
+    ) -> Option<usize> {
+        self.find_iter(pattern).next_back()
+    }
+
+    /// Returns an iterator over the offsets of all occurrences of the given pattern in this genome, in increasing order.
+    ///
+    /// Overlapping occurrences are all reported.
+    /// An empty pattern occurs at each offset, including the offset behind the last character.
+    fn find_iter<'a, Pattern: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized>(
+        &'a self,
+        pattern: &'a Pattern,
+    ) -> FindIterator<'a, AlphabetType, Self, GenomeSubsequence> {
+        FindIterator::new(self, pattern.as_genome_subsequence())
+    }
+
+    /// Returns an iterator over the offsets of all occurrences of the given pattern and its reverse complement in this genome, in increasing order.
+    ///
+    /// Each offset is reported together with the [Strand] on which the pattern occurs.
+    /// Offsets always refer to the forward strand.
+    /// A pattern that is [self-complemental](GenomeSequence::is_self_complemental) is reported once for each strand.
+    fn find_iter_both_strands<
+        'a,
+        Pattern: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &'a self,
+        pattern: &'a Pattern,
+    ) -> BothStrandsFindIterator<'a, AlphabetType, Self, GenomeSubsequence, GenomeSubsequence::Owned>
+    where
+        AlphabetType: ComplementableAlphabet,
+        GenomeSubsequence: ToOwned<Owned: OwnedGenomeSequence<AlphabetType, GenomeSubsequence>>,
+    {
+        BothStrandsFindIterator::new(
+            self,
+            pattern.as_genome_subsequence(),
+            pattern.reverse_complement_iter().collect(),
+        )
+    }
+
+    /// Returns an iterator over all occurrences of the given pattern in this genome with at most `max_distance` mismatches, in increasing order of offset.
+    ///
+    /// See [approximate](search::approximate) for details and for matching with a custom predicate.
+    fn find_iter_within_hamming_distance<
+        Pattern: GenomeSequence<AlphabetType, PatternSubsequence> + ?Sized,
+        PatternSubsequence: GenomeSequence<AlphabetType, PatternSubsequence> + ?Sized,
+    >(
+        &self,
+        pattern: &Pattern,
+        max_distance: usize,
+    ) -> HammingSearchIterator<'_, AlphabetType, ApproximatePattern<AlphabetType>, Self::Iterator<'_>>
+    {
+        HammingSearchIterator::new(ApproximatePattern::new(pattern), self.iter(), max_distance)
+    }
+
+    /// Returns an iterator over all alignment ends of the given pattern in this genome with at most `max_distance` edits, in increasing order.
+    ///
+    /// See [approximate](search::approximate) for details and for matching with a custom predicate.
+    fn find_iter_within_edit_distance<
+        Pattern: GenomeSequence<AlphabetType, PatternSubsequence> + ?Sized,
+        PatternSubsequence: GenomeSequence<AlphabetType, PatternSubsequence> + ?Sized,
+    >(
+        &self,
+        pattern: &Pattern,
+        max_distance: usize,
+    ) -> EditSearchIterator<'_, AlphabetType, ApproximatePattern<AlphabetType>, Self::Iterator<'_>>
+    {
+        EditSearchIterator::new(ApproximatePattern::new(pattern), self.iter(), max_distance)
+    }
+
+    /// Returns the number of occurrences of each character in this genome, indexed by [AlphabetCharacter::index].
+    ///
+    /// Implementations with a packed representation may override this with a faster count.
+    fn character_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; usize::from(AlphabetType::SIZE)];
+        for character in self.iter() {
+            counts[usize::from(character.index())] += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of occurrences of each pair of consecutive characters in this genome.
+    ///
+    /// The count of the pair `(first, second)` is stored at index `first.index() * AlphabetType::SIZE + second.index()`.
+    fn dinucleotide_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; usize::from(AlphabetType::SIZE) * usize::from(AlphabetType::SIZE)];
+        for (first, second) in self.iter().zip(self.iter().skip(1)) {
+            counts[composition::dinucleotide_index::<AlphabetType>(first, second)] += 1;
+        }
+        counts
+    }
+
+    /// Returns the GC content of this genome, see [Composition::gc_content].
+    fn gc_content(&self) -> Option<f64>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        composition::gc_content::<AlphabetType>(&self.character_counts())
+    }
+
+    /// Returns the Shannon entropy of the character distribution of this genome in bits, see [Composition::entropy].
+    fn entropy(&self) -> f64 {
+        composition::entropy(&self.character_counts())
+    }
+
+    /// Returns the character and dinucleotide counts of this genome.
+    fn composition(&self) -> Composition<AlphabetType> {
+        Composition::new(self)
+    }
+
+    /// Returns the compositions of consecutive non-overlapping windows of the given length.
+    ///
+    /// The last window is shorter if the length of this genome is not a multiple of the window length.
+    ///
+    /// Panics if the window length is zero.
+    fn windowed_composition(&self, window_len: usize) -> Vec<Composition<AlphabetType>> {
+        assert!(window_len > 0, "the window length must be positive");
+        (0..self.len())
+            .step_by(window_len)
+            .map(|start| Composition::new(&self[start..self.len().min(start + window_len)]))
+            .collect()
+    }
+
+    /// Returns the compositions of the windows of the given length that start at every `step`th offset.
+    ///
+    /// Windows that would reach over the end of this genome are omitted.
+    /// Overlapping windows are computed incrementally.
+    ///
+    /// Panics if the window length or the step are zero.
+    fn sliding_composition(
+        &self,
+        window_len: usize,
+        step: usize,
+    ) -> Vec<Composition<AlphabetType>> {
+        Composition::sliding(self, window_len, step)
+    }
+
+    /// Returns the low-complexity intervals of this genome as found by the [symmetric DUST algorithm](low_complexity), in increasing order.
+    ///
+    /// Only the characters `A`, `C`, `G`, `T` and `U` are scored, ignoring their case.
+    /// Any other character splits the genome into independent parts.
+    /// The parameters are usually [DEFAULT_DUST_THRESHOLD](low_complexity::DEFAULT_DUST_THRESHOLD) and [DEFAULT_DUST_WINDOW_LEN](low_complexity::DEFAULT_DUST_WINDOW_LEN).
+    ///
+    /// Panics if the window length is smaller than three.
+    fn dust_intervals(&self, threshold: usize, window_len: usize) -> Vec<Range<usize>>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        low_complexity::dust_intervals(
+            self.iter()
+                .map(|character| low_complexity::ascii_to_nucleotide(character.clone().into())),
+            threshold,
+            window_len,
+        )
+    }
+
+    /// Returns all maximal reverse-complement palindromes of this genome whose arms have at least the given length, ordered by their ranges.
+    ///
+    /// The arms may be separated by a gap of up to the given length, which allows to find hairpins.
+    /// A palindrome is maximal if its arms cannot be extended outwards, and if its gap cannot be shortened by extending the arms inwards.
+    /// Ambiguous characters do not pair with any character.
+    ///
+    /// Panics if the minimum arm length is zero.
+    fn palindromes(&self, min_arm_len: usize, max_gap_len: usize) -> Vec<Palindrome>
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
+        repeats::palindromes(self, min_arm_len, max_gap_len)
+    }
+
+    /// Returns all maximal tandem repeats of this genome with a period in the given range, ordered by their start and period.
+    ///
+    /// A repeat is seeded by two exact copies of its unit, and then extended in both directions over mismatches, such that its [purity](TandemRepeat::purity) is at least `min_purity`.
+    /// The extension stops once the repeat has accumulated about one period worth of mismatches more than its purity allows.
+    /// Repeats whose unit is itself a repeat with a shorter period, such as `ACAC`, are only reported with the shorter period.
+    /// Ambiguous characters do not match any character.
+    ///
+    /// Panics if the range of periods contains zero.
+    fn tandem_repeats(
+        &self,
+        periods: RangeInclusive<usize>,
+        min_copy_number: f64,
+        min_purity: f64,
+    ) -> Vec<TandemRepeat> {
+        repeats::tandem_repeats(self, periods, min_copy_number, min_purity)
+    }
+
+    /// Returns an owned copy of this genome in which the [DUST intervals](GenomeSequence::dust_intervals) are replaced by `N`.
+    ///
+    /// If the alphabet does not contain `N`, then `Err` is returned.
+    fn convert_with_dust_mask<
+        ResultSequence: OwnedGenomeSequence<AlphabetType, ResultSubsequence>,
+        ResultSubsequence: GenomeSequence<AlphabetType, ResultSubsequence> + ?Sized,
+    >(
+        &self,
+        threshold: usize,
+        window_len: usize,
+    ) -> Result<ResultSequence, AlphabetError>
+    where
+        AlphabetType: NucleotideAlphabet,
+    {
+        let n = AlphabetType::ascii_to_character(b'N')?;
+        let mut intervals = self
+            .dust_intervals(threshold, window_len)
+            .into_iter()
+            .peekable();
+
+        Ok(self
+            .iter()
+            .enumerate()
+            .map(|(index, character)| {
+                while intervals
+                    .next_if(|interval| interval.end <= index)
+                    .is_some()
+                {}
+                if intervals
+                    .peek()
+                    .is_some_and(|interval| interval.contains(&index))
+                {
+                    n.clone()
+                } else {
+                    character.clone()
+                }
+            })
+            .collect())
+    }
+}
+
+/// A strand of a double-stranded genome sequence.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strand {
+    /// The genome sequence as given.
+    Forward,
+    /// The reverse complement of the genome sequence.
+    ReverseComplement,
 }
 
 /// A genome sequence that is owned, i.e. not a reference.
@@ -133,7 +1096,10 @@ pub trait OwnedGenomeSequence<
 {
     /// Returns the reverse complement of this genome.
     /// Panics if this genome is [not valid](GenomeSequence::is_valid).
-    fn clone_as_reverse_complement(&self) -> Self {
+    fn clone_as_reverse_complement(&self) -> Self
+    where
+        AlphabetType: ComplementableAlphabet,
+    {
         self.reverse_complement_iter().collect()
     }
 
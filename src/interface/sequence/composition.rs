@@ -0,0 +1,369 @@
+//! Statistics about the composition of genome sequences.
+
+use crate::interface::alphabet::{Alphabet, AlphabetCharacter, NucleotideAlphabet};
+use crate::interface::sequence::GenomeSequence;
+use std::marker::PhantomData;
+
+/// The character and dinucleotide counts of a genome sequence.
+///
+/// Counts are indexed by [AlphabetCharacter::index].
+/// Dinucleotides are pairs of consecutive characters, and the count of the pair `(first, second)` is stored at index `first.index() * AlphabetType::SIZE + second.index()`.
+/// See [GenomeSequence::composition].
+#[derive(Debug, Eq, PartialEq)]
+pub struct Composition<AlphabetType: Alphabet> {
+    len: usize,
+    character_counts: Vec<usize>,
+    dinucleotide_counts: Vec<usize>,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+impl<AlphabetType: Alphabet> Composition<AlphabetType> {
+    /// Computes the composition of the given genome sequence.
+    pub fn new<
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        sequence: &SequenceType,
+    ) -> Self {
+        Self {
+            len: sequence.len(),
+            character_counts: sequence.character_counts(),
+            dinucleotide_counts: sequence.dinucleotide_counts(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Computes the compositions of the windows of the given length that start at every `step`th offset of the given genome sequence.
+    ///
+    /// Windows that would reach over the end of the sequence are omitted.
+    /// If the step is smaller than the window length, then the compositions are updated incrementally.
+    pub(crate) fn sliding<
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        sequence: &SequenceType,
+        window_len: usize,
+        step: usize,
+    ) -> Vec<Self> {
+        assert!(window_len > 0, "the window length must be positive");
+        assert!(step > 0, "the step must be positive");
+        if window_len > sequence.len() {
+            return Vec::new();
+        }
+
+        let mut current = Self::new(&sequence[0..window_len]);
+        let mut result = vec![current.clone()];
+        let mut start = 0;
+
+        while start + step + window_len <= sequence.len() {
+            let next_start = start + step;
+            if step < window_len {
+                for offset in start..next_start {
+                    current.remove_first(&sequence[offset], &sequence[offset + 1]);
+                }
+                for offset in start + window_len..next_start + window_len {
+                    current.push(&sequence[offset - 1], &sequence[offset]);
+                }
+            } else {
+                current = Self::new(&sequence[next_start..next_start + window_len]);
+            }
+
+            start = next_start;
+            result.push(current.clone());
+        }
+
+        result
+    }
+
+    /// Removes the first character of the window, which is followed by the given successor.
+    fn remove_first(
+        &mut self,
+        character: &AlphabetType::CharacterType,
+        successor: &AlphabetType::CharacterType,
+    ) {
+        self.len -= 1;
+        self.character_counts[usize::from(character.index())] -= 1;
+        self.dinucleotide_counts[dinucleotide_index::<AlphabetType>(character, successor)] -= 1;
+    }
+
+    /// Appends a character to the window, which is preceded by the given predecessor.
+    fn push(
+        &mut self,
+        predecessor: &AlphabetType::CharacterType,
+        character: &AlphabetType::CharacterType,
+    ) {
+        self.len += 1;
+        self.character_counts[usize::from(character.index())] += 1;
+        self.dinucleotide_counts[dinucleotide_index::<AlphabetType>(predecessor, character)] += 1;
+    }
+
+    /// Returns the length of the sequence.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of occurrences of each character, indexed by [AlphabetCharacter::index].
+    pub fn character_counts(&self) -> &[usize] {
+        &self.character_counts
+    }
+
+    /// Returns the number of occurrences of the given character.
+    pub fn character_count(&self, character: &AlphabetType::CharacterType) -> usize {
+        self.character_counts[usize::from(character.index())]
+    }
+
+    /// Returns the number of occurrences of each dinucleotide, indexed as described in the [type documentation](Composition).
+    pub fn dinucleotide_counts(&self) -> &[usize] {
+        &self.dinucleotide_counts
+    }
+
+    /// Returns the number of occurrences of the given character followed by the given second character.
+    pub fn dinucleotide_count(
+        &self,
+        first: &AlphabetType::CharacterType,
+        second: &AlphabetType::CharacterType,
+    ) -> usize {
+        self.dinucleotide_counts[dinucleotide_index::<AlphabetType>(first, second)]
+    }
+
+    /// Returns the Shannon entropy of the character distribution in bits.
+    ///
+    /// The entropy of an empty sequence is zero.
+    pub fn entropy(&self) -> f64 {
+        entropy(&self.character_counts)
+    }
+}
+
+impl<AlphabetType: NucleotideAlphabet> Composition<AlphabetType> {
+    /// Returns the fraction of [strong](AlphabetCharacter::is_strong) characters among all strong and [weak](AlphabetCharacter::is_weak) characters.
+    ///
+    /// Characters that are neither strong nor weak, such as `N`, are ignored.
+    /// If there are no strong or weak characters, then `None` is returned.
+    pub fn gc_content(&self) -> Option<f64> {
+        gc_content::<AlphabetType>(&self.character_counts)
+    }
+
+    /// Returns the fraction of [weak](AlphabetCharacter::is_weak) characters among all strong and weak characters.
+    ///
+    /// Characters that are neither strong nor weak, such as `N`, are ignored.
+    /// If there are no strong or weak characters, then `None` is returned.
+    pub fn at_content(&self) -> Option<f64> {
+        self.gc_content().map(|gc_content| 1.0 - gc_content)
+    }
+
+    /// Returns the ratio of observed to expected CpG dinucleotides.
+    ///
+    /// Cytosines are the unambiguous characters that are [strong](AlphabetCharacter::is_strong) [pyrimidines](AlphabetCharacter::is_pyrimidine), and guanines are the unambiguous strong [purines](AlphabetCharacter::is_purine).
+    /// Hence, soft-masked cytosines count as cytosines, while ambiguous characters like `S` are ignored.
+    /// The expected number of CpG dinucleotides is `count(C) * count(G) / len`.
+    /// If the expected number is zero, then `None` is returned.
+    pub fn cpg_observed_expected(&self) -> Option<f64> {
+        let cytosines: Vec<_> = AlphabetType::iter()
+            .filter(|character| {
+                character.is_strong() && character.is_pyrimidine() && !character.is_ambiguous()
+            })
+            .collect();
+        let guanines: Vec<_> = AlphabetType::iter()
+            .filter(|character| {
+                character.is_strong() && character.is_purine() && !character.is_ambiguous()
+            })
+            .collect();
+
+        let count = |characters: &[AlphabetType::CharacterType]| -> usize {
+            characters
+                .iter()
+                .map(|character| self.character_count(character))
+                .sum()
+        };
+        let expected = count(&cytosines) * count(&guanines);
+        if expected == 0 {
+            return None;
+        }
+
+        let observed: usize = cytosines
+            .iter()
+            .flat_map(|c| guanines.iter().map(move |g| (c, g)))
+            .map(|(c, g)| self.dinucleotide_count(c, g))
+            .sum();
+        Some((observed * self.len) as f64 / expected as f64)
+    }
+}
+
+impl<AlphabetType: Alphabet> Clone for Composition<AlphabetType> {
+    fn clone(&self) -> Self {
+        Self {
+            len: self.len,
+            character_counts: self.character_counts.clone(),
+            dinucleotide_counts: self.dinucleotide_counts.clone(),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Returns the index of the given dinucleotide in a dinucleotide count vector.
+pub(crate) fn dinucleotide_index<AlphabetType: Alphabet>(
+    first: &AlphabetType::CharacterType,
+    second: &AlphabetType::CharacterType,
+) -> usize {
+    usize::from(first.index()) * usize::from(AlphabetType::SIZE) + usize::from(second.index())
+}
+
+/// Returns the GC content of a character count vector, see [Composition::gc_content].
+pub(crate) fn gc_content<AlphabetType: NucleotideAlphabet>(
+    character_counts: &[usize],
+) -> Option<f64> {
+    let (mut strong, mut weak) = (0, 0);
+    for (index, &count) in character_counts.iter().enumerate() {
+        let character = AlphabetType::CharacterType::from_index(index as u8).unwrap();
+        if character.is_strong() {
+            strong += count;
+        } else if character.is_weak() {
+            weak += count;
+        }
+    }
+
+    if strong + weak == 0 {
+        None
+    } else {
+        Some(strong as f64 / (strong + weak) as f64)
+    }
+}
+
+/// Returns the Shannon entropy of a character count vector in bits, see [Composition::entropy].
+pub(crate) fn entropy(character_counts: &[usize]) -> f64 {
+    let len: usize = character_counts.iter().sum();
+    character_counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let probability = count as f64 / len as f64;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::alphabets::dna_alphabet_soft_masked::DnaAlphabetSoftMasked;
+    use crate::implementation::alphabets::dna_iupac_nucleic_acid_alphabet::DnaIupacNucleicAcidAlphabet;
+    use crate::implementation::alphabets::phred_quality_alphabet::Phred33Alphabet;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::composition::Composition;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::Random;
+    use traitsequence::interface::Sequence;
+
+    #[test]
+    fn test_statistics() {
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"ACGCGTTNNA").unwrap();
+        let composition = genome.composition();
+        assert_eq!(composition.len(), 10);
+        assert_eq!(composition.character_counts(), &[2, 2, 2, 2, 2]);
+        assert_eq!(genome.character_counts(), composition.character_counts());
+        assert_eq!(composition.gc_content(), Some(0.5));
+        assert_eq!(genome.gc_content(), Some(0.5));
+        assert_eq!(composition.at_content(), Some(0.5));
+        assert!((composition.entropy() - 5f64.log2()).abs() < 1e-12);
+        assert!((genome.entropy() - 5f64.log2()).abs() < 1e-12);
+        assert_eq!(composition.dinucleotide_counts().iter().sum::<usize>(), 9);
+        // CG occurs twice, C and G twice each, so the ratio is 2 * 10 / (2 * 2).
+        assert_eq!(composition.cpg_observed_expected(), Some(5.0));
+
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"NNAT").unwrap();
+        assert_eq!(genome.gc_content(), Some(0.0));
+        assert_eq!(genome.composition().cpg_observed_expected(), None);
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"NN").unwrap();
+        assert_eq!(genome.gc_content(), None);
+        assert_eq!(genome.entropy(), 0.0);
+        assert_eq!(VectorGenome::<DnaAlphabetOrN>::default().entropy(), 0.0);
+
+        let genome = VectorGenome::<DnaIupacNucleicAcidAlphabet>::from_slice_u8(b"SWNRCA").unwrap();
+        assert_eq!(genome.gc_content(), Some(0.5));
+    }
+
+    #[test]
+    fn test_cpg_observed_expected() {
+        // Soft-masked cytosines and guanines are counted like unmasked ones.
+        let genome = VectorGenome::<DnaAlphabetSoftMasked>::from_slice_u8(b"AcgCgT").unwrap();
+        assert_eq!(genome.composition().cpg_observed_expected(), Some(3.0));
+        // Ambiguous strong characters are neither cytosines nor guanines.
+        let genome = VectorGenome::<DnaIupacNucleicAcidAlphabet>::from_slice_u8(b"CSGCG").unwrap();
+        assert_eq!(genome.composition().cpg_observed_expected(), Some(1.25));
+    }
+
+    #[test]
+    fn test_packed_counts() {
+        let mut random = Random::new(3);
+
+        for len in [0, 1, 21, 22, 31, 32, 33, 100] {
+            let ascii = random.ascii(len, b"ACGNT");
+            let vector = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+            let bit_vector = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+            assert_eq!(vector.character_counts(), bit_vector.character_counts());
+            assert_eq!(
+                vector.composition().dinucleotide_counts(),
+                bit_vector.composition().dinucleotide_counts()
+            );
+            if len > 1 {
+                assert_eq!(
+                    vector[1..].character_counts(),
+                    bit_vector[1..].character_counts()
+                );
+            }
+
+            let ascii: Vec<_> = ascii.into_iter().filter(|&c| c != b'N').collect();
+            let vector = VectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+            let bit_vector = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+            assert_eq!(vector.character_counts(), bit_vector.character_counts());
+
+            // Alphabets with more characters than fit into a word are counted by decoding.
+            let ascii: Vec<_> = (0..len).map(|_| b'!' + random.below(94) as u8).collect();
+            let vector = VectorGenome::<Phred33Alphabet>::from_slice_u8(&ascii).unwrap();
+            let bit_vector = BitVectorGenome::<Phred33Alphabet>::from_slice_u8(&ascii).unwrap();
+            assert_eq!(vector.character_counts(), bit_vector.character_counts());
+        }
+    }
+
+    #[test]
+    fn test_windows() {
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(
+            b"ACGTNNACGGGCGCGATATATCGCGNNNACGTTTTACGCGACGGACT",
+        )
+        .unwrap();
+
+        let windows = genome.windowed_composition(10);
+        assert_eq!(windows.len(), 5);
+        assert_eq!(windows[4].len(), 7);
+        for (index, window) in windows.iter().enumerate() {
+            let end = genome.len().min(index * 10 + 10);
+            assert_eq!(window, &genome[index * 10..end].composition());
+        }
+
+        for (window_len, step) in [
+            (1, 1),
+            (2, 1),
+            (10, 1),
+            (10, 3),
+            (10, 10),
+            (7, 12),
+            (47, 1),
+            (48, 1),
+        ] {
+            let windows = genome.sliding_composition(window_len, step);
+            let expected: Vec<_> = (0..)
+                .map(|index| index * step)
+                .take_while(|start| start + window_len <= genome.len())
+                .map(|start| Composition::new(&genome[start..start + window_len]))
+                .collect();
+            assert_eq!(windows, expected);
+        }
+    }
+}
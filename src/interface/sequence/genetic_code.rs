@@ -0,0 +1,106 @@
+//! Genetic codes for the translation of codons into amino acids.
+
+/// The number of distinct codons.
+const CODON_COUNT: usize = 64;
+
+/// A genetic code, mapping each codon to an amino acid and marking the codons that can initiate translation.
+///
+/// Codons are ordered as in the tables of the NCBI, i.e. with nucleotide order `TCAG` and the first nucleotide being the most significant.
+/// Stop codons are translated to `*`.
+///
+/// ```rust
+/// use compact_genome::interface::sequence::genetic_code::GeneticCode;
+///
+/// let code = GeneticCode::standard();
+/// assert_eq!(code.translate_codon(*b"ATG"), Some(b'M'));
+/// assert!(code.is_stop_codon(*b"TGA"));
+/// assert!(!GeneticCode::vertebrate_mitochondrial().is_stop_codon(*b"TGA"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GeneticCode {
+    amino_acids: [u8; CODON_COUNT],
+    start_codons: [bool; CODON_COUNT],
+}
+
+impl GeneticCode {
+    /// Creates a genetic code from the `AAs` and `Starts` lines of an NCBI genetic code table.
+    ///
+    /// Start codons are marked with `M` in the `Starts` line.
+    pub fn from_ncbi_table(amino_acids: &[u8; CODON_COUNT], starts: &[u8; CODON_COUNT]) -> Self {
+        Self {
+            amino_acids: *amino_acids,
+            start_codons: starts.map(|start| start == b'M'),
+        }
+    }
+
+    /// The standard genetic code (NCBI table 1).
+    pub fn standard() -> Self {
+        Self::from_ncbi_table(
+            b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"---M------**--*----M---------------M----------------------------",
+        )
+    }
+
+    /// The vertebrate mitochondrial genetic code (NCBI table 2).
+    pub fn vertebrate_mitochondrial() -> Self {
+        Self::from_ncbi_table(
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+            b"----------**--------------------MMMM----------**---M------------",
+        )
+    }
+
+    /// The bacterial, archaeal and plant plastid genetic code (NCBI table 11).
+    pub fn bacterial() -> Self {
+        Self::from_ncbi_table(
+            b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"---M------**--*----M------------MMMM---------------M------------",
+        )
+    }
+
+    /// Returns the amino acid encoded by the given codon of ASCII nucleotides, or `None` if the codon contains other characters.
+    ///
+    /// Nucleotides are case-insensitive, and `U` is treated like `T`.
+    pub fn translate_codon(&self, codon: [u8; 3]) -> Option<u8> {
+        codon_index(codon).map(|index| self.amino_acids[index])
+    }
+
+    /// Returns true if the given codon of ASCII nucleotides is a stop codon.
+    pub fn is_stop_codon(&self, codon: [u8; 3]) -> bool {
+        self.translate_codon(codon) == Some(b'*')
+    }
+
+    /// Returns true if the given codon of ASCII nucleotides can initiate translation.
+    pub fn is_start_codon(&self, codon: [u8; 3]) -> bool {
+        codon_index(codon).is_some_and(|index| self.is_start_codon_index(index))
+    }
+
+    /// Returns true if the codon with the given index is a stop codon.
+    pub(crate) fn is_stop_codon_index(&self, codon_index: usize) -> bool {
+        self.amino_acids[codon_index] == b'*'
+    }
+
+    /// Returns true if the codon with the given index can initiate translation.
+    pub(crate) fn is_start_codon_index(&self, codon_index: usize) -> bool {
+        self.start_codons[codon_index]
+    }
+}
+
+/// Converts an ASCII nucleotide into its index in the order `TCAG`.
+pub(crate) fn nucleotide_index(ascii: u8) -> Option<usize> {
+    match ascii {
+        b'T' | b't' | b'U' | b'u' => Some(0),
+        b'C' | b'c' => Some(1),
+        b'A' | b'a' => Some(2),
+        b'G' | b'g' => Some(3),
+        _ => None,
+    }
+}
+
+/// Returns the index of the given codon of ASCII nucleotides in the tables of a [GeneticCode].
+pub(crate) fn codon_index(codon: [u8; 3]) -> Option<usize> {
+    Some(
+        nucleotide_index(codon[0])? * 16
+            + nucleotide_index(codon[1])? * 4
+            + nucleotide_index(codon[2])?,
+    )
+}
@@ -0,0 +1,326 @@
+//! Detection of low-complexity regions with the symmetric DUST algorithm.
+//!
+//! The algorithm is described in Morgulis et al., "A fast and symmetric DUST implementation to mask low-complexity DNA sequences", Journal of Computational Biology, 2006.
+//! This implementation follows the reference implementation `sdust` by Heng Li, scoring each run of unambiguous nucleotides independently.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// The default score threshold of DUST, above which a region is considered to have low complexity.
+pub const DEFAULT_DUST_THRESHOLD: usize = 20;
+
+/// The default window length of DUST.
+pub const DEFAULT_DUST_WINDOW_LEN: usize = 64;
+
+/// The length of the triplets that DUST scores.
+const TRIPLET_LEN: usize = 3;
+
+/// The number of distinct triplets.
+const TRIPLET_COUNT: usize = 1 << (2 * TRIPLET_LEN);
+
+/// A candidate interval whose score is above the threshold, and whose subintervals all have lower scores.
+#[derive(Debug, Clone, Copy)]
+struct PerfectInterval {
+    start: usize,
+    end: usize,
+    score: usize,
+    len: usize,
+}
+
+/// The state of the sliding DUST window.
+struct Dust {
+    threshold: usize,
+    window_len: usize,
+    /// The triplets of the current window.
+    triplets: VecDeque<usize>,
+    /// The triplet counts in the whole window.
+    window_counts: [usize; TRIPLET_COUNT],
+    /// The triplet counts in the suffix of the window that has a score below the threshold.
+    suffix_counts: [usize; TRIPLET_COUNT],
+    window_score: usize,
+    suffix_score: usize,
+    suffix_len: usize,
+    /// The perfect intervals in the current window, ordered by decreasing start.
+    perfect_intervals: Vec<PerfectInterval>,
+    result: Vec<Range<usize>>,
+}
+
+impl Dust {
+    fn new(threshold: usize, window_len: usize) -> Self {
+        Self {
+            threshold,
+            window_len,
+            triplets: VecDeque::new(),
+            window_counts: [0; TRIPLET_COUNT],
+            suffix_counts: [0; TRIPLET_COUNT],
+            window_score: 0,
+            suffix_score: 0,
+            suffix_len: 0,
+            perfect_intervals: Vec::new(),
+            result: Vec::new(),
+        }
+    }
+
+    /// Appends a triplet to the window, removing the first triplet if the window is full.
+    fn shift_window(&mut self, triplet: usize) {
+        if self.triplets.len() + TRIPLET_LEN > self.window_len {
+            let removed = self.triplets.pop_front().unwrap();
+            self.window_counts[removed] -= 1;
+            self.window_score -= self.window_counts[removed];
+            if self.suffix_len > self.triplets.len() {
+                self.suffix_len -= 1;
+                self.suffix_counts[removed] -= 1;
+                self.suffix_score -= self.suffix_counts[removed];
+            }
+        }
+
+        self.triplets.push_back(triplet);
+        self.suffix_len += 1;
+        self.window_score += self.window_counts[triplet];
+        self.window_counts[triplet] += 1;
+        self.suffix_score += self.suffix_counts[triplet];
+        self.suffix_counts[triplet] += 1;
+
+        if self.suffix_counts[triplet] * 10 > 2 * self.threshold {
+            loop {
+                let removed = self.triplets[self.triplets.len() - self.suffix_len];
+                self.suffix_counts[removed] -= 1;
+                self.suffix_score -= self.suffix_counts[removed];
+                self.suffix_len -= 1;
+                if removed == triplet {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves the last perfect interval into the result if it starts before the given window start,
+    /// and discards all perfect intervals that start before the given window start.
+    fn save_masked_intervals(&mut self, window_start: usize) {
+        let Some(last) = self.perfect_intervals.last() else {
+            return;
+        };
+        if last.start >= window_start {
+            return;
+        }
+
+        match self.result.last_mut() {
+            Some(previous) if last.start <= previous.end => {
+                previous.end = previous.end.max(last.end)
+            }
+            _ => self.result.push(last.start..last.end),
+        }
+
+        while self
+            .perfect_intervals
+            .last()
+            .is_some_and(|interval| interval.start < window_start)
+        {
+            self.perfect_intervals.pop();
+        }
+    }
+
+    /// Finds the perfect intervals that end at the end of the current window.
+    fn find_perfect_intervals(&mut self, window_start: usize) {
+        let mut counts = self.suffix_counts;
+        let mut score = self.suffix_score;
+        let (mut max_score, mut max_len) = (0, 0);
+
+        for index in (0..self.triplets.len() - self.suffix_len).rev() {
+            let triplet = self.triplets[index];
+            score += counts[triplet];
+            counts[triplet] += 1;
+            let len = self.triplets.len() - index - 1;
+
+            if score * 10 > self.threshold * len {
+                let mut insertion_index = 0;
+                while let Some(interval) = self
+                    .perfect_intervals
+                    .get(insertion_index)
+                    .filter(|interval| interval.start >= index + window_start)
+                {
+                    if max_score == 0 || interval.score * max_len > max_score * interval.len {
+                        max_score = interval.score;
+                        max_len = interval.len;
+                    }
+                    insertion_index += 1;
+                }
+
+                if max_score == 0 || score * max_len >= max_score * len {
+                    max_score = score;
+                    max_len = len;
+                    self.perfect_intervals.insert(
+                        insertion_index,
+                        PerfectInterval {
+                            start: index + window_start,
+                            end: self.triplets.len() + TRIPLET_LEN - 1 + window_start,
+                            score,
+                            len,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Flushes all perfect intervals and resets the window, as at the end of a run of unambiguous nucleotides.
+    fn finish_run(&mut self, mut window_start: usize) {
+        while !self.perfect_intervals.is_empty() {
+            self.save_masked_intervals(window_start);
+            window_start += 1;
+        }
+
+        self.triplets.clear();
+        self.window_counts = [0; TRIPLET_COUNT];
+        self.suffix_counts = [0; TRIPLET_COUNT];
+        self.window_score = 0;
+        self.suffix_score = 0;
+        self.suffix_len = 0;
+    }
+}
+
+/// Returns the low-complexity intervals of the given sequence of nucleotides, in increasing order.
+///
+/// The nucleotides are given as numbers between 0 and 3, and `None` for any other character.
+/// Other characters split the sequence into independent parts.
+pub(crate) fn dust_intervals(
+    nucleotides: impl IntoIterator<Item = Option<u8>>,
+    threshold: usize,
+    window_len: usize,
+) -> Vec<Range<usize>> {
+    assert!(
+        window_len >= TRIPLET_LEN,
+        "the DUST window must be at least as long as a triplet"
+    );
+    let mut dust = Dust::new(threshold, window_len);
+    let mut run_len = 0;
+    let mut triplet = 0;
+    let mut len = 0;
+
+    for (index, nucleotide) in nucleotides.into_iter().enumerate() {
+        len = index + 1;
+        if let Some(nucleotide) = nucleotide {
+            run_len += 1;
+            triplet = ((triplet << 2) | usize::from(nucleotide)) & (TRIPLET_COUNT - 1);
+            if run_len >= TRIPLET_LEN {
+                let window_start = run_len.saturating_sub(window_len) + (index + 1 - run_len);
+                dust.save_masked_intervals(window_start);
+                dust.shift_window(triplet);
+                if dust.window_score * 10 > dust.suffix_len * threshold {
+                    dust.find_perfect_intervals(window_start);
+                }
+            }
+        } else {
+            dust.finish_run((run_len + 1).saturating_sub(window_len) + (index + 1 - run_len));
+            run_len = 0;
+            triplet = 0;
+        }
+    }
+
+    dust.finish_run((run_len + 1).saturating_sub(window_len) + (len + 1 - run_len));
+    dust.result
+}
+
+/// Converts an ASCII character into a nucleotide for [dust_intervals].
+pub(crate) fn ascii_to_nucleotide(ascii: u8) -> Option<u8> {
+    match ascii {
+        b'A' | b'a' => Some(0),
+        b'C' | b'c' => Some(1),
+        b'G' | b'g' => Some(2),
+        b'T' | b't' | b'U' | b'u' => Some(3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::AlphabetError;
+    use crate::interface::sequence::low_complexity::{
+        DEFAULT_DUST_THRESHOLD, DEFAULT_DUST_WINDOW_LEN,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+
+    /// A sequence without low-complexity regions.
+    const COMPLEX: &[u8] =
+        b"GATCCTTGACGCATGGCTAACGTTCAGAGCTTGTACCGAGTCAACGGTATCGCTAGGACTTCAGTGCCATGA";
+
+    fn dust(ascii: &[u8]) -> Vec<std::ops::Range<usize>> {
+        VectorGenome::<DnaAlphabetOrN>::from_slice_u8(ascii)
+            .unwrap()
+            .dust_intervals(DEFAULT_DUST_THRESHOLD, DEFAULT_DUST_WINDOW_LEN)
+    }
+
+    #[test]
+    fn test_complex_sequence() {
+        assert!(dust(COMPLEX).is_empty());
+        assert!(dust(b"").is_empty());
+        assert!(dust(b"AA").is_empty());
+        assert!(dust(b"NNNN").is_empty());
+    }
+
+    #[test]
+    fn test_homopolymer() {
+        // Five equal triplets score above the default threshold.
+        assert_eq!(dust(b"AAAAAAA"), vec![0..7]);
+        assert!(dust(b"AAAAAA").is_empty());
+
+        let mut ascii = COMPLEX[..40].to_vec();
+        ascii.extend_from_slice(&[b'A'; 30]);
+        ascii.extend_from_slice(&COMPLEX[40..]);
+        let intervals = dust(&ascii);
+        assert_eq!(intervals.len(), 1);
+        assert!(intervals[0].start <= 40 && intervals[0].end >= 70);
+        assert!(intervals[0].start >= 38 && intervals[0].end <= 72);
+    }
+
+    #[test]
+    fn test_long_repeat() {
+        // A dinucleotide repeat that is longer than the window is masked completely.
+        let mut ascii = COMPLEX.to_vec();
+        for _ in 0..100 {
+            ascii.extend_from_slice(b"CA");
+        }
+        ascii.extend_from_slice(COMPLEX);
+        let intervals = dust(&ascii);
+        assert_eq!(intervals.len(), 1);
+        assert!(intervals[0].start <= COMPLEX.len());
+        assert!(intervals[0].end >= COMPLEX.len() + 200);
+        assert!(intervals[0].end <= COMPLEX.len() + 203);
+    }
+
+    #[test]
+    fn test_split_by_n() {
+        let intervals = dust(b"GATCAAAAAAAAAANAAAAAAAAAAGATC");
+        assert_eq!(intervals.len(), 2);
+        assert!(intervals[0].end <= 14);
+        assert!(intervals[1].start >= 15);
+        assert_eq!(dust(b"AAAANAAAA"), vec![]);
+    }
+
+    #[test]
+    fn test_masked_copy() {
+        let ascii = b"GATCCTTGACAAAAAAAAAAGCATGG";
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(ascii).unwrap();
+        let masked: VectorGenome<DnaAlphabetOrN> = genome
+            .convert_with_dust_mask(DEFAULT_DUST_THRESHOLD, DEFAULT_DUST_WINDOW_LEN)
+            .unwrap();
+        assert_eq!(masked.as_string(), "GATCCTTGACNNNNNNNNNNGCATGG");
+
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(ascii).unwrap();
+        assert_eq!(
+            genome.dust_intervals(DEFAULT_DUST_THRESHOLD, DEFAULT_DUST_WINDOW_LEN),
+            vec![10..20]
+        );
+        assert_eq!(
+            genome.convert_with_dust_mask::<VectorGenome<DnaAlphabet>, _>(
+                DEFAULT_DUST_THRESHOLD,
+                DEFAULT_DUST_WINDOW_LEN
+            ),
+            Err(AlphabetError::AsciiNotPartOfAlphabet { ascii: 'N' })
+        );
+    }
+}
@@ -0,0 +1,408 @@
+//! Rolling hashes of nucleotide sequences with the ntHash algorithm.
+//!
+//! The algorithm is described in Mohamadi et al., "ntHash: recursive nucleotide hashing", Bioinformatics, 2016.
+//! The hash values are identical to those of version 1 of the reference implementation, where the canonical hash is the minimum of the forward and the reverse complement hash.
+//!
+//! Nucleotides are recognised by their ASCII representation regardless of their case, and `U` is treated like `T`.
+//! Windows that contain other characters, like the `N` of [DnaAlphabetOrN](crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN), are skipped.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+//! use compact_genome::implementation::vec_sequence::VectorGenome;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//!
+//! let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"ACGTNACGTA").unwrap();
+//! let hashes: Vec<_> = genome.nt_hash_iter(4).collect();
+//!
+//! assert_eq!(hashes.len(), 3);
+//! assert_eq!(hashes[2].position(), 6);
+//! // ACGT is its own reverse complement.
+//! assert_eq!(hashes[0].forward(), hashes[0].reverse());
+//! assert_eq!(hashes[1].forward(), hashes[0].forward());
+//! assert_eq!(hashes[2].canonical(), genome[6..10].nt_hash().unwrap().canonical());
+//! ```
+
+use crate::interface::alphabet::NucleotideAlphabet;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// The seeds of the nucleotides `A`, `C`, `G` and `T`.
+const NUCLEOTIDE_SEEDS: [u64; 4] = [
+    0x3c8bfbb395c60474,
+    0x3193c18562a02b4c,
+    0x20323ed082572324,
+    0x295549f54be24456,
+];
+
+/// The multiplier used to derive additional hash values.
+const MULTI_HASH_SEED: u64 = 0x90b45d39fb6da1fa;
+
+/// The shift used to derive additional hash values.
+const MULTI_HASH_SHIFT: u32 = 27;
+
+/// The ntHash values of a single k-mer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NtHash {
+    position: usize,
+    k: usize,
+    forward: u64,
+    reverse: u64,
+}
+
+impl NtHash {
+    /// The offset of the k-mer in the hashed sequence.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The length of the k-mer.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The hash value of the k-mer.
+    pub fn forward(&self) -> u64 {
+        self.forward
+    }
+
+    /// The hash value of the reverse complement of the k-mer.
+    pub fn reverse(&self) -> u64 {
+        self.reverse
+    }
+
+    /// The hash value that is equal for the k-mer and its reverse complement, i.e. the minimum of the [forward](Self::forward) and the [reverse](Self::reverse) hash value.
+    pub fn canonical(&self) -> u64 {
+        self.forward.min(self.reverse)
+    }
+
+    /// Returns the canonical hash value with the given seed index.
+    ///
+    /// The hash value with index zero is the [canonical](Self::canonical) hash value, and further hash values are derived from it.
+    /// This allows to use multiple hash functions, e.g. for Bloom filters, at the cost of computing only one.
+    pub fn multi_hash(&self, index: usize) -> u64 {
+        if index == 0 {
+            self.canonical()
+        } else {
+            let hash = self
+                .canonical()
+                .wrapping_mul(index as u64 ^ (self.k as u64).wrapping_mul(MULTI_HASH_SEED));
+            hash ^ (hash >> MULTI_HASH_SHIFT)
+        }
+    }
+
+    /// Returns the first `count` canonical hash values, see [multi_hash](Self::multi_hash).
+    pub fn multi_hashes(&self, count: usize) -> impl Iterator<Item = u64> + '_ {
+        (0..count).map(|index| self.multi_hash(index))
+    }
+}
+
+/// An iterator over the ntHash values of all k-mers of a genome sequence that consist only of nucleotides.
+///
+/// Each hash value is computed from the previous one in constant time.
+/// For a k known at compile time, see [NtHashConstIterator].
+pub struct NtHashIterator<
+    AlphabetType: NucleotideAlphabet,
+    CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+> {
+    characters: CharacterIterator,
+    k: usize,
+    rolling: RollingNtHash,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+/// An iterator over the ntHash values of all k-mers of a genome sequence that consist only of nucleotides, where k is known at compile time.
+///
+/// It returns the same hash values as an [NtHashIterator] with the same k.
+pub struct NtHashConstIterator<
+    const K: usize,
+    AlphabetType: NucleotideAlphabet,
+    CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+> {
+    characters: CharacterIterator,
+    rolling: RollingNtHash,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+/// The state of the rolling hash that is shared by [NtHashIterator] and [NtHashConstIterator].
+struct RollingNtHash {
+    position: usize,
+    /// The nucleotides of the current window, which are at most `k`.
+    window: VecDeque<usize>,
+    forward: u64,
+    reverse: u64,
+}
+
+impl<
+        AlphabetType: NucleotideAlphabet,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > NtHashIterator<AlphabetType, CharacterIterator>
+{
+    /// Creates a new ntHash iterator over the k-mers of the given characters.
+    ///
+    /// Panics if `k` is zero.
+    pub(crate) fn new(characters: CharacterIterator, k: usize) -> Self {
+        assert!(k > 0, "ntHash requires k to be positive");
+
+        Self {
+            characters,
+            k,
+            rolling: RollingNtHash::new(k),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: NucleotideAlphabet,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > NtHashConstIterator<K, AlphabetType, CharacterIterator>
+{
+    const ASSERT_K: () = assert!(K > 0, "ntHash requires K to be positive");
+
+    /// Creates a new ntHash iterator over the k-mers of the given characters.
+    pub(crate) fn new(characters: CharacterIterator) -> Self {
+        let () = Self::ASSERT_K;
+
+        Self {
+            characters,
+            rolling: RollingNtHash::new(K),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<
+        AlphabetType: NucleotideAlphabet,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > Iterator for NtHashIterator<AlphabetType, CharacterIterator>
+{
+    type Item = NtHash;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let character = self.characters.next()?;
+            if let Some(hash) = self
+                .rolling
+                .roll(self.k, AlphabetType::character_to_ascii(character))
+            {
+                return Some(hash);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.characters.size_hint().1)
+    }
+}
+
+impl<
+        const K: usize,
+        AlphabetType: NucleotideAlphabet,
+        CharacterIterator: Iterator<Item = AlphabetType::CharacterType>,
+    > Iterator for NtHashConstIterator<K, AlphabetType, CharacterIterator>
+{
+    type Item = NtHash;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let character = self.characters.next()?;
+            if let Some(hash) = self
+                .rolling
+                .roll(K, AlphabetType::character_to_ascii(character))
+            {
+                return Some(hash);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.characters.size_hint().1)
+    }
+}
+
+impl RollingNtHash {
+    fn new(k: usize) -> Self {
+        Self {
+            position: 0,
+            window: VecDeque::with_capacity(k),
+            forward: 0,
+            reverse: 0,
+        }
+    }
+
+    /// Appends the given character to the window, and returns the hash values of the k-mer ending with it, if the k-mer consists only of nucleotides.
+    ///
+    /// The given `k` must be the same in all calls.
+    #[inline]
+    fn roll(&mut self, k: usize, ascii: u8) -> Option<NtHash> {
+        self.position += 1;
+
+        let Some(nucleotide) = ascii_to_nucleotide(ascii) else {
+            self.window.clear();
+            self.forward = 0;
+            self.reverse = 0;
+            return None;
+        };
+
+        let seed = NUCLEOTIDE_SEEDS[nucleotide];
+        let complement_seed = NUCLEOTIDE_SEEDS[3 - nucleotide];
+
+        if self.window.len() == k {
+            let removed = self.window.pop_front().unwrap();
+            self.forward = self.forward.rotate_left(1)
+                ^ NUCLEOTIDE_SEEDS[removed].rotate_left(k as u32)
+                ^ seed;
+            self.reverse = self.reverse.rotate_right(1)
+                ^ NUCLEOTIDE_SEEDS[3 - removed].rotate_right(1)
+                ^ complement_seed.rotate_left(k as u32 - 1);
+        } else {
+            self.forward = self.forward.rotate_left(1) ^ seed;
+            self.reverse ^= complement_seed.rotate_left(self.window.len() as u32);
+        }
+        self.window.push_back(nucleotide);
+
+        (self.window.len() == k).then(|| NtHash {
+            position: self.position - k,
+            k,
+            forward: self.forward,
+            reverse: self.reverse,
+        })
+    }
+}
+
+/// Converts an ASCII character into the index of its nucleotide in the order `ACGT`.
+fn ascii_to_nucleotide(ascii: u8) -> Option<usize> {
+    match ascii {
+        b'A' | b'a' => Some(0),
+        b'C' | b'c' => Some(1),
+        b'G' | b'g' => Some(2),
+        b'T' | b't' | b'U' | b'u' => Some(3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::alphabets::rna_alphabet::RnaAlphabet;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::nt_hash::NUCLEOTIDE_SEEDS;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::random_ascii;
+
+    #[test]
+    fn test_single_nucleotide() {
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"A").unwrap();
+        let hash = genome.nt_hash().unwrap();
+        assert_eq!(hash.forward(), NUCLEOTIDE_SEEDS[0]);
+        assert_eq!(hash.reverse(), NUCLEOTIDE_SEEDS[3]);
+        assert_eq!(hash.canonical(), NUCLEOTIDE_SEEDS[3]);
+        assert_eq!(hash.multi_hash(0), hash.canonical());
+        assert!(VectorGenome::<DnaAlphabet>::from_slice_u8(b"")
+            .unwrap()
+            .nt_hash()
+            .is_none());
+    }
+
+    #[test]
+    fn test_rolling_equals_direct() {
+        let ascii = random_ascii(300, b"ACGT", 7);
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+
+        for k in [1, 5, 31, 64, 65, 100] {
+            let hashes: Vec<_> = genome.nt_hash_iter(k).collect();
+            assert_eq!(hashes.len(), ascii.len() - k + 1);
+            for (offset, hash) in hashes.iter().enumerate() {
+                assert_eq!(hash.position(), offset);
+                assert_eq!(hash.k(), k);
+                let direct = genome[offset..offset + k].nt_hash().unwrap();
+                assert_eq!(
+                    (hash.forward(), hash.reverse()),
+                    (direct.forward(), direct.reverse())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let ascii = random_ascii(200, b"ACGT", 7);
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+        let reverse_complement: VectorGenome<DnaAlphabet> =
+            genome.convert_with_reverse_complement();
+
+        let forward: Vec<_> = genome.nt_hash_iter(21).collect();
+        let reverse: Vec<_> = reverse_complement.nt_hash_iter(21).collect();
+        for (forward, reverse) in forward.iter().zip(reverse.iter().rev()) {
+            assert_eq!(forward.forward(), reverse.reverse());
+            assert_eq!(forward.reverse(), reverse.forward());
+            assert_eq!(forward.canonical(), reverse.canonical());
+            assert!(forward.multi_hashes(4).eq(reverse.multi_hashes(4)));
+        }
+        assert_ne!(forward[0].multi_hash(1), forward[0].multi_hash(2));
+    }
+
+    #[test]
+    fn test_skip_non_nucleotides() {
+        let ascii = random_ascii(200, b"ACGTACGTACGTN", 7);
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let k = 7;
+
+        let expected: Vec<_> = (0..ascii.len() - k + 1)
+            .filter(|&offset| !ascii[offset..offset + k].contains(&b'N'))
+            .collect();
+        let hashes: Vec<_> = genome.nt_hash_iter(k).collect();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            hashes
+                .iter()
+                .map(|hash| hash.position())
+                .collect::<Vec<_>>(),
+            expected
+        );
+        for hash in hashes {
+            let direct = genome[hash.position()..hash.position() + k]
+                .nt_hash()
+                .unwrap();
+            assert_eq!(
+                (hash.forward(), hash.reverse()),
+                (direct.forward(), direct.reverse())
+            );
+        }
+        assert!(genome[..].nt_hash().is_none());
+    }
+
+    #[test]
+    fn test_const_k() {
+        let ascii = random_ascii(300, b"ACGTACGTACGTN", 7);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+
+        fn check<const K: usize>(genome: &BitVectorGenome<DnaAlphabetOrN>) {
+            let hashes: Vec<_> = genome.nt_hash_iter_const::<K>().collect();
+            assert!(!hashes.is_empty());
+            assert_eq!(hashes, genome.nt_hash_iter(K).collect::<Vec<_>>());
+        }
+        check::<1>(&genome);
+        check::<5>(&genome);
+        check::<21>(&genome);
+    }
+
+    #[test]
+    fn test_other_representations() {
+        let kmer = BitArrayKmer::<5, DnaAlphabet>::from_slice_u8(b"ACCGT").unwrap();
+        let rna = VectorGenome::<RnaAlphabet>::from_slice_u8(b"ACCGU").unwrap();
+        let dna = VectorGenome::<DnaAlphabet>::from_slice_u8(b"TTACCGTT").unwrap();
+
+        let hash = kmer.nt_hash().unwrap();
+        assert_eq!(rna.nt_hash(), Some(hash));
+        let rolled = dna.nt_hash_iter(5).nth(2).unwrap();
+        assert_eq!(rolled.position(), 2);
+        assert_eq!(rolled.forward(), hash.forward());
+        assert_eq!(rolled.reverse(), hash.reverse());
+    }
+}
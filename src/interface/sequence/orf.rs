@@ -0,0 +1,305 @@
+//! Search for open reading frames on both strands of a genome sequence.
+//!
+//! An open reading frame (ORF) is a range of codons that begins with a start codon and ends with a stop codon, without any other stop codon in the same frame in between.
+//! The ranges of the ORFs always refer to the forward strand, such that they can be used directly to index the sequence or to create [subsequence handles](crate::interface::sequence_store::HandleWithSubsequence).
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::bit_vec_sequence_store::BitVectorSequenceStore;
+//! use compact_genome::interface::sequence::genetic_code::GeneticCode;
+//! use compact_genome::interface::sequence::orf::OrfFinder;
+//! use compact_genome::interface::sequence::{GenomeSequence, Strand};
+//! use compact_genome::interface::sequence_store::{HandleWithSubsequence, SequenceStore};
+//!
+//! let mut store = BitVectorSequenceStore::<DnaAlphabet>::new();
+//! let handle = store.add_from_slice_u8(b"CCATGAAATTTTAGCC").unwrap();
+//! let orfs = OrfFinder::new(GeneticCode::standard(), 9).find_orfs(store.get(&handle));
+//!
+//! assert_eq!(orfs.len(), 1);
+//! assert_eq!(orfs[0].strand(), Strand::Forward);
+//! let orf_handle = handle.subsequence_handle(orfs[0].range());
+//! assert_eq!(store.get(&orf_handle).as_string(), "ATGAAATTTTAG");
+//! ```
+
+use crate::interface::alphabet::NucleotideAlphabet;
+use crate::interface::sequence::genetic_code::{codon_index, nucleotide_index, GeneticCode};
+use crate::interface::sequence::{GenomeSequence, Strand};
+use std::ops::Range;
+
+/// An open reading frame, including its start and its stop codon.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Orf {
+    range: Range<usize>,
+    strand: Strand,
+}
+
+impl Orf {
+    /// The range of the ORF on the forward strand.
+    /// For ORFs on the reverse complement strand, the start codon is at the end of the range.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The strand of the ORF.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// The length of the ORF in nucleotides.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns true if the ORF has length zero, which never happens for ORFs found by an [OrfFinder].
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+}
+
+/// Finds the open reading frames in all six frames of a genome sequence.
+///
+/// The nucleotides `A`, `C`, `G`, `T` and `U` are recognised regardless of their case.
+/// Codons that contain other characters, such as `N`, are neither start nor stop codons.
+/// By default, such codons are skipped, i.e. an ORF may span them, since they may well encode an amino acid.
+/// Use [with_unknown_codons_ending_orfs](OrfFinder::with_unknown_codons_ending_orfs) to report only ORFs that consist of known codons.
+#[derive(Debug, Clone)]
+pub struct OrfFinder {
+    genetic_code: GeneticCode,
+    start_codons: [bool; 64],
+    min_len: usize,
+    nested: bool,
+    unknown_codons_end_orfs: bool,
+}
+
+impl OrfFinder {
+    /// Creates a new ORF finder with the given genetic code that reports ORFs of at least the given length in nucleotides, including the stop codon.
+    ///
+    /// By default, only `ATG` is used as start codon, and only the longest ORF ending in each stop codon is reported.
+    pub fn new(genetic_code: GeneticCode, min_len: usize) -> Self {
+        let mut start_codons = [false; 64];
+        start_codons[codon_index(*b"ATG").unwrap()] = true;
+
+        Self {
+            genetic_code,
+            start_codons,
+            min_len,
+            nested: false,
+            unknown_codons_end_orfs: false,
+        }
+    }
+
+    /// Uses the given codons of ASCII nucleotides as start codons.
+    ///
+    /// Panics if a codon contains characters other than nucleotides.
+    pub fn with_start_codons(mut self, start_codons: impl IntoIterator<Item = [u8; 3]>) -> Self {
+        self.start_codons = [false; 64];
+        for codon in start_codons {
+            self.start_codons
+                [codon_index(codon).expect("start codons must consist of nucleotides")] = true;
+        }
+        self
+    }
+
+    /// Uses the start codons of the genetic code, including the alternative start codons.
+    pub fn with_genetic_code_start_codons(mut self) -> Self {
+        self.start_codons =
+            std::array::from_fn(|index| self.genetic_code.is_start_codon_index(index));
+        self
+    }
+
+    /// Additionally reports the nested ORFs that begin at later start codons of the same frame and end in the same stop codon.
+    pub fn with_nested(mut self) -> Self {
+        self.nested = true;
+        self
+    }
+
+    /// Discards the ORFs that are open at codons that contain characters other than nucleotides, instead of letting ORFs span such codons.
+    ///
+    /// An ORF may still begin at a start codon after such a codon.
+    pub fn with_unknown_codons_ending_orfs(mut self) -> Self {
+        self.unknown_codons_end_orfs = true;
+        self
+    }
+
+    /// Returns the genetic code of this ORF finder.
+    pub fn genetic_code(&self) -> &GeneticCode {
+        &self.genetic_code
+    }
+
+    /// Returns all ORFs of the given sequence on both strands.
+    /// The ORFs are ordered by their start and end on the forward strand, and forward ORFs come before reverse complement ORFs with the same range.
+    pub fn find_orfs<
+        AlphabetType: NucleotideAlphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    >(
+        &self,
+        sequence: &SequenceType,
+    ) -> Vec<Orf> {
+        let nucleotides: Vec<_> = sequence
+            .iter()
+            .map(|character| nucleotide_index(character.clone().into()))
+            .collect();
+        // In the order TCAG, complementing a nucleotide flips its second bit.
+        let reverse_complement: Vec<_> = nucleotides
+            .iter()
+            .rev()
+            .map(|nucleotide| nucleotide.map(|nucleotide| nucleotide ^ 2))
+            .collect();
+
+        let mut result = Vec::new();
+        self.find_orfs_on_strand(&nucleotides, |range| {
+            result.push(Orf {
+                range,
+                strand: Strand::Forward,
+            })
+        });
+        self.find_orfs_on_strand(&reverse_complement, |range| {
+            result.push(Orf {
+                range: nucleotides.len() - range.end..nucleotides.len() - range.start,
+                strand: Strand::ReverseComplement,
+            })
+        });
+
+        result.sort_by_key(|orf| (orf.range.start, orf.range.end, orf.strand));
+        result
+    }
+
+    /// Reports the ranges of all ORFs in the three frames of the given nucleotide indices.
+    fn find_orfs_on_strand(
+        &self,
+        nucleotides: &[Option<usize>],
+        mut report: impl FnMut(Range<usize>),
+    ) {
+        for frame in 0..3 {
+            let mut starts = Vec::new();
+
+            for offset in (frame..nucleotides.len().saturating_sub(2)).step_by(3) {
+                let Some(codon) = nucleotides[offset..offset + 3]
+                    .iter()
+                    .try_fold(0, |codon, nucleotide| Some(codon * 4 + (*nucleotide)?))
+                else {
+                    if self.unknown_codons_end_orfs {
+                        starts.clear();
+                    }
+                    continue;
+                };
+
+                if self.genetic_code.is_stop_codon_index(codon) {
+                    let end = offset + 3;
+                    for &start in &starts {
+                        if end - start >= self.min_len {
+                            report(start..end);
+                        }
+                    }
+                    starts.clear();
+                } else if self.start_codons[codon] && (self.nested || starts.is_empty()) {
+                    starts.push(offset);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::genetic_code::GeneticCode;
+    use crate::interface::sequence::orf::OrfFinder;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence, Strand};
+
+    fn orfs(finder: &OrfFinder, ascii: &[u8]) -> Vec<(std::ops::Range<usize>, Strand)> {
+        finder
+            .find_orfs(&VectorGenome::<DnaAlphabetOrN>::from_slice_u8(ascii).unwrap())
+            .into_iter()
+            .map(|orf| (orf.range(), orf.strand()))
+            .collect()
+    }
+
+    #[test]
+    fn test_forward_frames() {
+        let finder = OrfFinder::new(GeneticCode::standard(), 0);
+        // Frame 0: ATG AAA TAA, frame 1: ATG GTA G, frame 2 has no start codon.
+        assert_eq!(orfs(&finder, b"ATGAAATAA"), vec![(0..9, Strand::Forward)]);
+        assert_eq!(
+            orfs(&finder, b"CATGCCCTGAGG"),
+            vec![(1..10, Strand::Forward)]
+        );
+        // ORFs without stop codon are not reported.
+        assert!(orfs(&finder, b"ATGAAAAAA").is_empty());
+        // Codons with N are skipped by default.
+        assert_eq!(orfs(&finder, b"ATGNAATAA"), vec![(0..9, Strand::Forward)]);
+        let finder = finder.with_unknown_codons_ending_orfs();
+        assert!(orfs(&finder, b"ATGNAATAA").is_empty());
+        assert_eq!(
+            orfs(&finder, b"ATGNAAATGTAA"),
+            vec![(6..12, Strand::Forward)]
+        );
+        assert!(orfs(&finder, b"").is_empty());
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let finder = OrfFinder::new(GeneticCode::standard(), 0);
+        // The reverse complement of TTATTTCAT is ATGAAATAA.
+        assert_eq!(
+            orfs(&finder, b"GTTATTTCATG"),
+            vec![(1..10, Strand::ReverseComplement)]
+        );
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"GTTATTTCATG").unwrap();
+        let orf = &finder.find_orfs(&genome)[0];
+        assert_eq!(
+            genome[orf.range()]
+                .convert_with_reverse_complement::<VectorGenome<_>, _>()
+                .as_string(),
+            "ATGAAATAA"
+        );
+    }
+
+    #[test]
+    fn test_nested_and_min_len() {
+        let ascii = b"ATGATGCCCATGTAG";
+        let finder = OrfFinder::new(GeneticCode::standard(), 0);
+        assert_eq!(orfs(&finder, ascii), vec![(0..15, Strand::Forward)]);
+        assert_eq!(
+            orfs(&finder.clone().with_nested(), ascii),
+            vec![
+                (0..15, Strand::Forward),
+                (3..15, Strand::Forward),
+                (9..15, Strand::Forward)
+            ]
+        );
+        assert_eq!(
+            orfs(
+                &OrfFinder::new(GeneticCode::standard(), 12).with_nested(),
+                ascii
+            ),
+            vec![(0..15, Strand::Forward), (3..15, Strand::Forward)]
+        );
+        assert!(orfs(&OrfFinder::new(GeneticCode::standard(), 16), ascii).is_empty());
+    }
+
+    #[test]
+    fn test_start_codons_and_genetic_code() {
+        let ascii = b"TTGAAATGA";
+        let finder = OrfFinder::new(GeneticCode::standard(), 0);
+        assert!(orfs(&finder, ascii).is_empty());
+        assert_eq!(
+            orfs(&finder.clone().with_start_codons([*b"TTG"]), ascii),
+            vec![(0..9, Strand::Forward)]
+        );
+        assert_eq!(
+            orfs(&finder.with_genetic_code_start_codons(), ascii),
+            vec![(0..9, Strand::Forward)]
+        );
+
+        // TGA is not a stop codon in vertebrate mitochondria.
+        let finder = OrfFinder::new(GeneticCode::vertebrate_mitochondrial(), 0);
+        assert!(orfs(&finder, b"ATGTGAAAATAA").len() == 1);
+        assert_eq!(orfs(&finder, b"ATGTGAAAATAA")[0].0, 0..12);
+        assert!(GeneticCode::bacterial().is_start_codon(*b"GTG"));
+        assert!(!GeneticCode::standard().is_start_codon(*b"GTG"));
+    }
+}
@@ -0,0 +1,346 @@
+//! Detection of palindromes and tandem repeats in genome sequences.
+//!
+//! See [GenomeSequence::palindromes] and [GenomeSequence::tandem_repeats].
+
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, ComplementableAlphabet, ComplementableAlphabetCharacter,
+};
+use crate::interface::sequence::GenomeSequence;
+use std::ops::{Range, RangeInclusive};
+
+/// A reverse-complement palindrome, i.e. two arms that are reverse complements of each other, separated by an optional gap.
+///
+/// Without gap, the palindrome is equal to its own reverse complement, like many restriction sites.
+/// With gap, it can fold into a hairpin, where the gap forms the loop.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Palindrome {
+    range: Range<usize>,
+    arm_len: usize,
+}
+
+impl Palindrome {
+    /// The range of the whole palindrome, including both arms and the gap.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The length of each arm.
+    pub fn arm_len(&self) -> usize {
+        self.arm_len
+    }
+
+    /// The length of the gap between the arms.
+    pub fn gap_len(&self) -> usize {
+        self.range.len() - 2 * self.arm_len
+    }
+
+    /// The range of the left arm.
+    pub fn left_arm(&self) -> Range<usize> {
+        self.range.start..self.range.start + self.arm_len
+    }
+
+    /// The range of the right arm, which is the reverse complement of the left arm.
+    pub fn right_arm(&self) -> Range<usize> {
+        self.range.end - self.arm_len..self.range.end
+    }
+}
+
+/// A tandem repeat, i.e. multiple adjacent approximate copies of a repeat unit.
+///
+/// The repeat may end with a partial copy of the unit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TandemRepeat {
+    range: Range<usize>,
+    period: usize,
+    purity: f64,
+}
+
+impl TandemRepeat {
+    /// The range of the whole repeat.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The length of the repeat unit.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// The range of the first copy of the repeat unit.
+    pub fn unit(&self) -> Range<usize> {
+        self.range.start..self.range.start + self.period
+    }
+
+    /// The number of copies of the repeat unit, including a trailing partial copy as fraction.
+    pub fn copy_number(&self) -> f64 {
+        self.range.len() as f64 / self.period as f64
+    }
+
+    /// The fraction of characters that are equal to the character one period further.
+    /// This is `1.0` for exact repeats.
+    pub fn purity(&self) -> f64 {
+        self.purity
+    }
+}
+
+/// Returns true if the two characters form an unambiguous base pair.
+fn pairs<AlphabetType: ComplementableAlphabet>(
+    first: &AlphabetType::CharacterType,
+    second: &AlphabetType::CharacterType,
+) -> bool {
+    !first.is_ambiguous() && first.complement() == *second
+}
+
+/// Returns all maximal palindromes of the given sequence, see [GenomeSequence::palindromes].
+pub(crate) fn palindromes<
+    AlphabetType: ComplementableAlphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+>(
+    sequence: &SequenceType,
+    min_arm_len: usize,
+    max_gap_len: usize,
+) -> Vec<Palindrome> {
+    assert!(min_arm_len > 0, "the minimum arm length must be positive");
+    let len = sequence.len();
+    let mut result = Vec::new();
+
+    for gap_start in 0..=len {
+        for gap_len in 0..=max_gap_len.min(len - gap_start) {
+            let gap_end = gap_start + gap_len;
+            // If the gap ends pair, then the palindrome is reported with a shorter gap.
+            if gap_len >= 2 && pairs::<AlphabetType>(&sequence[gap_start], &sequence[gap_end - 1]) {
+                continue;
+            }
+
+            let arm_len = (0..gap_start.min(len - gap_end))
+                .take_while(|&offset| {
+                    pairs::<AlphabetType>(
+                        &sequence[gap_start - 1 - offset],
+                        &sequence[gap_end + offset],
+                    )
+                })
+                .count();
+            if arm_len >= min_arm_len {
+                result.push(Palindrome {
+                    range: gap_start - arm_len..gap_end + arm_len,
+                    arm_len,
+                });
+            }
+        }
+    }
+
+    result.sort_by_key(|palindrome| (palindrome.range.start, palindrome.range.end));
+    result
+}
+
+/// Returns all maximal tandem repeats of the given sequence, see [GenomeSequence::tandem_repeats].
+pub(crate) fn tandem_repeats<
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+>(
+    sequence: &SequenceType,
+    periods: RangeInclusive<usize>,
+    min_copy_number: f64,
+    min_purity: f64,
+) -> Vec<TandemRepeat> {
+    assert!(*periods.start() > 0, "the period must be positive");
+    let mut result = Vec::new();
+
+    for period in periods {
+        if period >= sequence.len() {
+            break;
+        }
+        // The character at each offset matches the character one period further.
+        let matches: Vec<_> = (0..sequence.len() - period)
+            .map(|offset| {
+                let character = &sequence[offset];
+                !character.is_ambiguous() && *character == sequence[offset + period]
+            })
+            .collect();
+        let is_pure_enough =
+            |match_count: usize, len: usize| match_count as f64 >= min_purity * len as f64;
+
+        let mut previous_end = 0;
+        let mut offset = 0;
+        while offset < matches.len() {
+            // Seed the repeat with two exact copies of the unit.
+            let seed_len = matches[offset..]
+                .iter()
+                .take_while(|&&matches| matches)
+                .count();
+            if seed_len < period {
+                offset += seed_len + 1;
+                continue;
+            }
+
+            // Extend the repeat in both directions until the purity drops by more than one period below its best value.
+            // The extension keeps the furthest match at which the purity is still high enough.
+            let mut range = offset..offset + seed_len;
+            let mut match_count = seed_len;
+            let mut extended_match_count = match_count;
+            let mut best_score = match_count as f64 - min_purity * range.len() as f64;
+            for end in range.end + 1..=matches.len() {
+                extended_match_count += usize::from(matches[end - 1]);
+                let score = extended_match_count as f64 - min_purity * (end - range.start) as f64;
+                if score < best_score - period as f64 {
+                    break;
+                }
+                best_score = f64::max(best_score, score);
+                if matches[end - 1] && is_pure_enough(extended_match_count, end - range.start) {
+                    range.end = end;
+                    match_count = extended_match_count;
+                }
+            }
+            let mut extended_match_count = match_count;
+            let mut best_score = match_count as f64 - min_purity * range.len() as f64;
+            for start in (previous_end..range.start).rev() {
+                extended_match_count += usize::from(matches[start]);
+                let score = extended_match_count as f64 - min_purity * (range.end - start) as f64;
+                if score < best_score - period as f64 {
+                    break;
+                }
+                best_score = f64::max(best_score, score);
+                if matches[start] && is_pure_enough(extended_match_count, range.end - start) {
+                    range.start = start;
+                    match_count = extended_match_count;
+                }
+            }
+
+            let repeat = TandemRepeat {
+                range: range.start..range.end + period,
+                period,
+                purity: match_count as f64 / range.len() as f64,
+            };
+            let unit = &sequence[repeat.unit()];
+            // Units that are repeats themselves are reported with their shorter period.
+            let is_primitive = (1..period)
+                .filter(|divisor| period % divisor == 0)
+                .all(|divisor| (divisor..period).any(|index| unit[index] != unit[index - divisor]));
+            if is_primitive && repeat.copy_number() >= min_copy_number {
+                result.push(repeat);
+            }
+
+            previous_end = range.end;
+            offset = range.end;
+        }
+    }
+
+    result.sort_by_key(|repeat| (repeat.range.start, repeat.period));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::ComplementableAlphabetCharacter;
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::Random;
+
+    #[test]
+    fn test_palindromes() {
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"CGAATTCT").unwrap();
+        let palindromes = genome.palindromes(3, 0);
+        assert_eq!(palindromes.len(), 1);
+        assert_eq!(palindromes[0].range(), 1..7);
+        assert_eq!(palindromes[0].arm_len(), 3);
+        assert_eq!(palindromes[0].gap_len(), 0);
+        assert!(genome[palindromes[0].range()].is_self_complemental());
+
+        // A hairpin with a loop of four characters.
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGGTTTTCCGT").unwrap();
+        let palindromes = genome.palindromes(4, 4);
+        assert_eq!(palindromes.len(), 1);
+        assert_eq!(palindromes[0].range(), 0..12);
+        assert_eq!(palindromes[0].left_arm(), 0..4);
+        assert_eq!(palindromes[0].right_arm(), 8..12);
+        assert_eq!(palindromes[0].gap_len(), 4);
+        assert!(genome.palindromes(4, 3).is_empty());
+
+        // Ambiguous characters do not pair.
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"ACNNGT").unwrap();
+        assert!(genome.palindromes(3, 0).is_empty());
+    }
+
+    #[test]
+    fn test_palindromes_against_naive() {
+        let mut random = Random::new(11);
+
+        for _ in 0..20 {
+            let ascii = random.ascii(40, b"ACGT");
+            let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+            let pairs = |a: usize, b: usize| genome[a].complement() == genome[b];
+            let (min_arm_len, max_gap_len) = (2, 3);
+
+            let mut expected = Vec::new();
+            for start in 0..ascii.len() {
+                for end in start + 2 * min_arm_len..=ascii.len() {
+                    for arm_len in min_arm_len..=(end - start) / 2 {
+                        let gap_len = end - start - 2 * arm_len;
+                        let is_palindrome = gap_len <= max_gap_len
+                            && (0..arm_len).all(|offset| pairs(start + offset, end - 1 - offset));
+                        let is_outward_maximal =
+                            start == 0 || end == ascii.len() || !pairs(start - 1, end);
+                        let is_inward_maximal =
+                            gap_len < 2 || !pairs(start + arm_len, end - arm_len - 1);
+                        if is_palindrome && is_outward_maximal && is_inward_maximal {
+                            expected.push((start..end, arm_len));
+                        }
+                    }
+                }
+            }
+
+            let actual: Vec<_> = genome
+                .palindromes(min_arm_len, max_gap_len)
+                .into_iter()
+                .map(|palindrome| (palindrome.range(), palindrome.arm_len()))
+                .collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_tandem_repeats() {
+        let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(b"GATCACACACACGTT").unwrap();
+        let repeats = genome.tandem_repeats(1..=6, 3.0, 1.0);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].range(), 3..12);
+        assert_eq!(repeats[0].period(), 2);
+        assert_eq!(repeats[0].unit(), 3..5);
+        assert_eq!(repeats[0].copy_number(), 4.5);
+        assert_eq!(repeats[0].purity(), 1.0);
+
+        let repeats = genome.tandem_repeats(1..=6, 2.0, 1.0);
+        assert_eq!(repeats.len(), 2);
+        assert_eq!(repeats[1].range(), 13..15);
+        assert_eq!(repeats[1].period(), 1);
+
+        assert!(genome.tandem_repeats(3..=20, 2.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_approximate_tandem_repeats() {
+        // The third copy has a substitution.
+        let genome =
+            VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"ACGTTACGTTACCTTACGTTACGTT").unwrap();
+        let repeats = genome.tandem_repeats(5..=5, 2.0, 0.9);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].range(), 0..25);
+        assert_eq!(repeats[0].purity(), 0.9);
+
+        let repeats = genome.tandem_repeats(5..=5, 2.0, 1.0);
+        assert_eq!(repeats.len(), 2);
+        assert_eq!(repeats[0].range(), 0..12);
+        assert_eq!(repeats[1].range(), 13..25);
+
+        // Ambiguous characters do not match.
+        let genome = VectorGenome::<DnaAlphabetOrN>::from_slice_u8(b"ANNNNNNA").unwrap();
+        assert!(genome.tandem_repeats(1..=3, 2.0, 1.0).is_empty());
+    }
+}
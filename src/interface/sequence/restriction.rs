@@ -0,0 +1,616 @@
+//! Simulation of restriction enzyme digestion.
+//!
+//! A [RestrictionEnzyme] recognises a site given as IUPAC nucleotide codes, and cuts both strands at fixed offsets relative to the site.
+//! Both offsets refer to positions on the forward strand, counted from the first character of the site.
+//! For example, EcoRI recognises `GAATTC` and cuts between `G` and `A` on the forward strand and between `T` and `C` on the reverse strand, i.e. at offsets 1 and 5, leaving a 5' overhang of `AATT`.
+//!
+//! Sites that are not palindromic are searched on both strands.
+//! Digesting a sequence in a [SequenceStore] yields a [Fragment] for each piece, with the [Overhang] at each of its ends.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::vec_sequence_store::VectorSequenceStore;
+//! use compact_genome::interface::sequence::restriction::{
+//!     digest, Overhang, RestrictionEnzyme, Topology,
+//! };
+//! use compact_genome::interface::sequence::GenomeSequence;
+//! use compact_genome::interface::sequence_store::SequenceStore;
+//!
+//! let mut store = VectorSequenceStore::<DnaAlphabet>::new();
+//! let handle = store.add_from_slice_u8(b"CCGAATTCGGATCCAA").unwrap();
+//! let enzymes = [
+//!     RestrictionEnzyme::from_name("EcoRI").unwrap(),
+//!     RestrictionEnzyme::from_name("BamHI").unwrap(),
+//! ];
+//!
+//! let fragments = digest(&enzymes, &mut store, &handle, Topology::Linear);
+//! let sequences: Vec<_> = fragments
+//!     .iter()
+//!     .map(|fragment| store.get(fragment.handle()).as_string())
+//!     .collect();
+//! assert_eq!(sequences, ["CCG", "AATTCG", "GATCCAA"]);
+//! assert_eq!(fragments[1].left_end().unwrap().overhang(), Overhang::FivePrime(4));
+//! ```
+
+use crate::interface::alphabet::{Alphabet, AlphabetError};
+use crate::interface::sequence::search::approximate::IUPAC_NUCLEOTIDE_MASKS;
+use crate::interface::sequence::GenomeSequence;
+use crate::interface::sequence_store::{HandleWithSubsequence, SequenceStore};
+use std::ops::Range;
+use traitsequence::interface::Sequence;
+
+/// Common restriction enzymes with their recognition sites and cut offsets on the forward and reverse strand.
+const COMMON_ENZYMES: &[(&str, &[u8], isize, isize)] = &[
+    ("AluI", b"AGCT", 2, 2),
+    ("ApaI", b"GGGCCC", 5, 1),
+    ("AvaI", b"CYCGRG", 1, 5),
+    ("BamHI", b"GGATCC", 1, 5),
+    ("BglII", b"AGATCT", 1, 5),
+    ("BsaI", b"GGTCTC", 7, 11),
+    ("BsmBI", b"CGTCTC", 7, 11),
+    ("ClaI", b"ATCGAT", 2, 4),
+    ("DpnII", b"GATC", 0, 4),
+    ("EcoRI", b"GAATTC", 1, 5),
+    ("EcoRV", b"GATATC", 3, 3),
+    ("HaeIII", b"GGCC", 2, 2),
+    ("HindIII", b"AAGCTT", 1, 5),
+    ("HinfI", b"GANTC", 1, 4),
+    ("KpnI", b"GGTACC", 5, 1),
+    ("MluI", b"ACGCGT", 1, 5),
+    ("MspI", b"CCGG", 1, 3),
+    ("NcoI", b"CCATGG", 1, 5),
+    ("NdeI", b"CATATG", 2, 4),
+    ("NheI", b"GCTAGC", 1, 5),
+    ("NotI", b"GCGGCCGC", 2, 6),
+    ("PstI", b"CTGCAG", 5, 1),
+    ("SacI", b"GAGCTC", 5, 1),
+    ("SalI", b"GTCGAC", 1, 5),
+    ("SmaI", b"CCCGGG", 3, 3),
+    ("SpeI", b"ACTAGT", 1, 5),
+    ("StyI", b"CCWWGG", 1, 5),
+    ("TaqI", b"TCGA", 1, 3),
+    ("XbaI", b"TCTAGA", 1, 5),
+    ("XhoI", b"CTCGAG", 1, 5),
+];
+
+/// The single-stranded end left by a cut.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overhang {
+    /// Both strands are cut at the same position.
+    Blunt,
+    /// The 5' end of each strand protrudes by the given number of characters.
+    FivePrime(usize),
+    /// The 3' end of each strand protrudes by the given number of characters.
+    ThreePrime(usize),
+}
+
+impl Overhang {
+    /// Returns the overhang of a cut at the given forward and reverse strand positions.
+    fn new(cut: isize, complement_cut: isize) -> Self {
+        match cut.cmp(&complement_cut) {
+            std::cmp::Ordering::Less => Self::FivePrime(cut.abs_diff(complement_cut)),
+            std::cmp::Ordering::Equal => Self::Blunt,
+            std::cmp::Ordering::Greater => Self::ThreePrime(cut.abs_diff(complement_cut)),
+        }
+    }
+}
+
+/// The topology of a double-stranded sequence.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// The sequence has two ends.
+    Linear,
+    /// The end of the sequence is joined to its start, like a plasmid.
+    Circular,
+}
+
+/// A restriction enzyme with its recognition site and cut offsets.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RestrictionEnzyme {
+    name: String,
+    site: Vec<u8>,
+    cut: isize,
+    complement_cut: isize,
+}
+
+impl RestrictionEnzyme {
+    /// Creates a restriction enzyme from its recognition site of IUPAC nucleotide codes and the offsets of its cuts on the forward and reverse strand.
+    ///
+    /// The offsets are counted from the first character of the site and may lie outside of the site.
+    /// If the site contains characters that are no IUPAC nucleotide codes, then `Err` is returned.
+    pub fn new(
+        name: impl Into<String>,
+        site: &[u8],
+        cut: isize,
+        complement_cut: isize,
+    ) -> Result<Self, AlphabetError> {
+        if let Some(&ascii) = site
+            .iter()
+            .find(|&&ascii| IUPAC_NUCLEOTIDE_MASKS[usize::from(ascii)] == 0)
+        {
+            return Err(AlphabetError::AsciiNotPartOfAlphabet {
+                ascii: ascii.into(),
+            });
+        }
+
+        Ok(Self {
+            name: name.into(),
+            site: site.to_ascii_uppercase(),
+            cut,
+            complement_cut,
+        })
+    }
+
+    /// Returns a table of common restriction enzymes, ordered by name.
+    pub fn common() -> Vec<Self> {
+        COMMON_ENZYMES
+            .iter()
+            .map(|&(name, site, cut, complement_cut)| {
+                Self::new(name, site, cut, complement_cut).unwrap()
+            })
+            .collect()
+    }
+
+    /// Returns the [common](RestrictionEnzyme::common) restriction enzyme with the given name, or `None` if there is none.
+    pub fn from_name(name: &str) -> Option<Self> {
+        COMMON_ENZYMES
+            .iter()
+            .find(|(common_name, ..)| *common_name == name)
+            .map(|&(name, site, cut, complement_cut)| {
+                Self::new(name, site, cut, complement_cut).unwrap()
+            })
+    }
+
+    /// The name of the enzyme.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The recognition site of the enzyme as upper case IUPAC nucleotide codes.
+    pub fn site(&self) -> &[u8] {
+        &self.site
+    }
+
+    /// The offset of the cut on the forward strand.
+    pub fn cut(&self) -> isize {
+        self.cut
+    }
+
+    /// The offset of the cut on the reverse strand.
+    pub fn complement_cut(&self) -> isize {
+        self.complement_cut
+    }
+
+    /// The overhang left by the enzyme.
+    pub fn overhang(&self) -> Overhang {
+        Overhang::new(self.cut, self.complement_cut)
+    }
+
+    /// Returns true if the recognition site is equal to its reverse complement.
+    pub fn is_palindromic(&self) -> bool {
+        self.site_masks() == self.reverse_complement_site_masks()
+    }
+
+    /// Returns the nucleotide masks of the recognition site.
+    fn site_masks(&self) -> Vec<u8> {
+        self.site
+            .iter()
+            .map(|&ascii| IUPAC_NUCLEOTIDE_MASKS[usize::from(ascii)])
+            .collect()
+    }
+
+    /// Returns the nucleotide masks of the reverse complement of the recognition site.
+    fn reverse_complement_site_masks(&self) -> Vec<u8> {
+        // Complementing swaps A with T and C with G, i.e. reverses the order of the four mask bits.
+        self.site_masks()
+            .into_iter()
+            .rev()
+            .map(|mask| mask.reverse_bits() >> 4)
+            .collect()
+    }
+}
+
+/// A position at which both strands of a sequence are cut.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CutSite {
+    cut: usize,
+    complement_cut: usize,
+    enzyme: usize,
+    overhang: Overhang,
+}
+
+impl CutSite {
+    /// The position of the cut on the forward strand, i.e. the index of the first character after the cut.
+    pub fn cut(&self) -> usize {
+        self.cut
+    }
+
+    /// The position of the cut on the reverse strand, in forward strand coordinates.
+    pub fn complement_cut(&self) -> usize {
+        self.complement_cut
+    }
+
+    /// The index of the enzyme that makes this cut, in the slice of enzymes given to [cut_sites] or [digest].
+    pub fn enzyme(&self) -> usize {
+        self.enzyme
+    }
+
+    /// The overhang left by this cut.
+    pub fn overhang(&self) -> Overhang {
+        self.overhang
+    }
+}
+
+/// A fragment of a digested sequence.
+///
+/// The handle refers to the forward strand of the fragment, which starts and ends at the forward strand cuts.
+/// The ends of a fragment are `None` if they are the ends of a linear sequence.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fragment<Handle> {
+    handle: Handle,
+    left_end: Option<CutSite>,
+    right_end: Option<CutSite>,
+}
+
+impl<Handle> Fragment<Handle> {
+    /// The handle of the forward strand of the fragment.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// The cut at the start of the fragment.
+    pub fn left_end(&self) -> Option<&CutSite> {
+        self.left_end.as_ref()
+    }
+
+    /// The cut at the end of the fragment.
+    pub fn right_end(&self) -> Option<&CutSite> {
+        self.right_end.as_ref()
+    }
+}
+
+/// Returns true if the site masks match the sequence at the given offset, wrapping around if the offset plus the site length exceeds the length of the sequence.
+///
+/// Ambiguous characters of the sequence only match if all nucleotides they stand for match.
+fn matches_site<
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+>(
+    sequence: &SequenceType,
+    offset: usize,
+    site_masks: &[u8],
+) -> bool {
+    site_masks.iter().enumerate().all(|(index, &site_mask)| {
+        let ascii =
+            AlphabetType::character_to_ascii(sequence[(offset + index) % sequence.len()].clone());
+        let mask = IUPAC_NUCLEOTIDE_MASKS[usize::from(ascii)];
+        mask != 0 && mask & site_mask == mask
+    })
+}
+
+/// Returns the cut sites of the given enzymes in the given sequence, ordered by their forward strand position.
+///
+/// In linear sequences, cuts that lie at or outside of the ends of the sequence are ignored.
+/// In circular sequences, sites may span the end of the sequence.
+/// Cuts that occur at the same positions on both strands are reported only once, for the first enzyme that makes them.
+/// Cuts that share only their forward strand position, e.g. because the enzymes leave different overhangs, are all reported.
+pub fn cut_sites<
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+>(
+    enzymes: &[RestrictionEnzyme],
+    sequence: &SequenceType,
+    topology: Topology,
+) -> Vec<CutSite> {
+    let len = sequence.len();
+    let mut result = Vec::new();
+
+    for (enzyme_index, enzyme) in enzymes.iter().enumerate() {
+        let site_len = enzyme.site.len();
+        let offsets = match topology {
+            Topology::Linear => 0..(len + 1).saturating_sub(site_len),
+            Topology::Circular => 0..len,
+        };
+        let site_masks = enzyme.site_masks();
+        let reverse_complement_site_masks = enzyme.reverse_complement_site_masks();
+        let is_palindromic = site_masks == reverse_complement_site_masks;
+
+        for offset in offsets {
+            let mut cuts = Vec::with_capacity(2);
+            if matches_site(sequence, offset, &site_masks) {
+                cuts.push((enzyme.cut, enzyme.complement_cut));
+            }
+            // On the reverse strand, the cut offsets are mirrored and swapped.
+            if !is_palindromic && matches_site(sequence, offset, &reverse_complement_site_masks) {
+                cuts.push((
+                    site_len as isize - enzyme.complement_cut,
+                    site_len as isize - enzyme.cut,
+                ));
+            }
+
+            for (cut, complement_cut) in cuts {
+                let (cut, complement_cut) =
+                    (offset as isize + cut, offset as isize + complement_cut);
+                let (cut, complement_cut) = match topology {
+                    Topology::Linear => {
+                        let inside = 1..len as isize;
+                        if !inside.contains(&cut) || !inside.contains(&complement_cut) {
+                            continue;
+                        }
+                        (cut as usize, complement_cut as usize)
+                    }
+                    Topology::Circular => (
+                        cut.rem_euclid(len as isize) as usize,
+                        complement_cut.rem_euclid(len as isize) as usize,
+                    ),
+                };
+
+                result.push(CutSite {
+                    cut,
+                    complement_cut,
+                    enzyme: enzyme_index,
+                    overhang: enzyme.overhang(),
+                });
+            }
+        }
+    }
+
+    result.sort_by_key(|cut_site| (cut_site.cut, cut_site.complement_cut, cut_site.enzyme));
+    result.dedup_by_key(|cut_site| (cut_site.cut, cut_site.complement_cut));
+    result
+}
+
+/// Digests the sequence with the given handle with the given enzymes, returning its fragments in the order of the sequence.
+///
+/// Fragments that do not span the end of a circular sequence refer to subsequences of the given handle.
+/// The fragment that spans the end of a circular sequence is added to the store as a new sequence.
+/// If a circular sequence is not cut at all, then the whole sequence is returned as a single fragment without ends.
+/// If multiple cut sites share the same forward strand position, then the fragment ends use the first of them as ordered by [cut_sites].
+pub fn digest<
+    AlphabetType: Alphabet,
+    Store: SequenceStore<AlphabetType, Handle: HandleWithSubsequence<Range<usize>>>,
+>(
+    enzymes: &[RestrictionEnzyme],
+    store: &mut Store,
+    handle: &Store::Handle,
+    topology: Topology,
+) -> Vec<Fragment<Store::Handle>> {
+    let sequence = store.get(handle);
+    let len = sequence.len();
+    let mut cut_sites = cut_sites(enzymes, sequence, topology);
+    // Fragments are delimited by the forward strand cuts, so cut sites at the same forward position delimit the same fragments.
+    cut_sites.dedup_by_key(|cut_site| cut_site.cut);
+
+    let (Some(first), Some(last)) = (cut_sites.first(), cut_sites.last()) else {
+        return vec![Fragment {
+            handle: handle.subsequence_handle(0..len),
+            left_end: None,
+            right_end: None,
+        }];
+    };
+
+    let mut result = Vec::new();
+    if topology == Topology::Linear {
+        result.push(Fragment {
+            handle: handle.subsequence_handle(0..first.cut),
+            left_end: None,
+            right_end: Some(*first),
+        });
+    }
+
+    for (left, right) in cut_sites.iter().zip(cut_sites.iter().skip(1)) {
+        result.push(Fragment {
+            handle: handle.subsequence_handle(left.cut..right.cut),
+            left_end: Some(*left),
+            right_end: Some(*right),
+        });
+    }
+
+    match topology {
+        Topology::Linear => result.push(Fragment {
+            handle: handle.subsequence_handle(last.cut..len),
+            left_end: Some(*last),
+            right_end: None,
+        }),
+        Topology::Circular => {
+            let wrapping_handle = if first.cut == 0 {
+                handle.subsequence_handle(last.cut..len)
+            } else {
+                let characters: Vec<_> = sequence
+                    .iter()
+                    .skip(last.cut)
+                    .chain(sequence.iter().take(first.cut))
+                    .cloned()
+                    .collect();
+                store.add_from_iter(characters)
+            };
+            result.push(Fragment {
+                handle: wrapping_handle,
+                left_end: Some(*last),
+                right_end: Some(*first),
+            });
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::bit_vec_sequence_store::BitVectorSequenceStore;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::alphabet::AlphabetError;
+    use crate::interface::sequence::restriction::{
+        cut_sites, digest, Overhang, RestrictionEnzyme, Topology,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::interface::sequence_store::SequenceStore;
+
+    fn cuts(enzyme: &str, ascii: &[u8], topology: Topology) -> Vec<(usize, usize)> {
+        cut_sites(
+            &[RestrictionEnzyme::from_name(enzyme).unwrap()],
+            &VectorGenome::<DnaAlphabetOrN>::from_slice_u8(ascii).unwrap(),
+            topology,
+        )
+        .into_iter()
+        .map(|cut_site| (cut_site.cut(), cut_site.complement_cut()))
+        .collect()
+    }
+
+    #[test]
+    fn test_enzymes() {
+        let enzymes = RestrictionEnzyme::common();
+        assert!(enzymes
+            .windows(2)
+            .all(|window| window[0].name() < window[1].name()));
+        let ecori = RestrictionEnzyme::from_name("EcoRI").unwrap();
+        assert_eq!(ecori.site(), b"GAATTC");
+        assert_eq!(ecori.overhang(), Overhang::FivePrime(4));
+        assert!(ecori.is_palindromic());
+        assert_eq!(
+            RestrictionEnzyme::from_name("PstI").unwrap().overhang(),
+            Overhang::ThreePrime(4)
+        );
+        assert_eq!(
+            RestrictionEnzyme::from_name("SmaI").unwrap().overhang(),
+            Overhang::Blunt
+        );
+        assert!(RestrictionEnzyme::from_name("HinfI")
+            .unwrap()
+            .is_palindromic());
+        assert!(!RestrictionEnzyme::from_name("BsaI")
+            .unwrap()
+            .is_palindromic());
+        assert!(RestrictionEnzyme::from_name("Unknown").is_none());
+        assert_eq!(
+            RestrictionEnzyme::new("Invalid", b"GAXTC", 1, 4),
+            Err(AlphabetError::AsciiNotPartOfAlphabet { ascii: 'X' })
+        );
+    }
+
+    #[test]
+    fn test_cut_sites() {
+        assert_eq!(cuts("EcoRI", b"AGAATTCA", Topology::Linear), vec![(2, 6)]);
+        // Cuts at the ends of linear sequences are ignored.
+        assert_eq!(cuts("EcoRI", b"GAATTC", Topology::Linear), vec![(1, 5)]);
+        assert!(cuts("BsaI", b"AAGGTCTCAA", Topology::Linear).is_empty());
+        assert!(cuts("DpnII", b"GATCA", Topology::Linear).is_empty());
+        // IUPAC codes in the site match any of their nucleotides, but ambiguous characters in the sequence do not match.
+        assert_eq!(
+            cuts("HinfI", b"AGACTCAGAGTCA", Topology::Linear),
+            vec![(2, 5), (8, 11)]
+        );
+        assert!(cuts("HinfI", b"AGANNCA", Topology::Linear).is_empty());
+        // Non-palindromic sites are found on both strands.
+        assert_eq!(
+            cuts("BsaI", b"GGTCTCAAAAAAAA", Topology::Linear),
+            vec![(7, 11)]
+        );
+        assert_eq!(
+            cuts("BsaI", b"AAAAAAAAAAGAGACCAA", Topology::Linear),
+            vec![(5, 9)]
+        );
+        // Sites may span the end of circular sequences.
+        assert_eq!(
+            cuts("EcoRI", b"ATTCAAAAGA", Topology::Circular),
+            vec![(9, 3)]
+        );
+        assert!(cuts("EcoRI", b"ATTCAAAAGA", Topology::Linear).is_empty());
+    }
+
+    #[test]
+    fn test_shared_forward_cut() {
+        let enzymes = [
+            RestrictionEnzyme::new("Sticky", b"GAATTC", 1, 5).unwrap(),
+            RestrictionEnzyme::new("Blunt", b"GAATTC", 1, 1).unwrap(),
+            RestrictionEnzyme::new("StickyCopy", b"GAATTC", 1, 5).unwrap(),
+        ];
+        let mut store = BitVectorSequenceStore::<DnaAlphabet>::new();
+        let handle = store.add_from_slice_u8(b"AAGAATTCAA").unwrap();
+
+        let sites = cut_sites(&enzymes, store.get(&handle), Topology::Linear);
+        let sites: Vec<_> = sites
+            .iter()
+            .map(|cut_site| {
+                (
+                    cut_site.cut(),
+                    cut_site.complement_cut(),
+                    cut_site.enzyme(),
+                    cut_site.overhang(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            sites,
+            [
+                (3, 3, 1, Overhang::Blunt),
+                (3, 7, 0, Overhang::FivePrime(4))
+            ]
+        );
+
+        let fragments = digest(&enzymes, &mut store, &handle, Topology::Linear);
+        let sequences: Vec<_> = fragments
+            .iter()
+            .map(|fragment| store.get(fragment.handle()).as_string())
+            .collect();
+        assert_eq!(sequences, ["AAG", "AATTCAA"]);
+        assert_eq!(fragments[0].right_end(), fragments[1].left_end());
+    }
+
+    #[test]
+    fn test_digest() {
+        let mut store = BitVectorSequenceStore::<DnaAlphabet>::new();
+        let handle = store.add_from_slice_u8(b"ATTCTTTTGGATCCTTTTGA").unwrap();
+        let enzymes = [
+            RestrictionEnzyme::from_name("EcoRI").unwrap(),
+            RestrictionEnzyme::from_name("BamHI").unwrap(),
+        ];
+
+        let fragments = digest(&enzymes, &mut store, &handle, Topology::Linear);
+        let sequences: Vec<_> = fragments
+            .iter()
+            .map(|fragment| store.get(fragment.handle()).as_string())
+            .collect();
+        assert_eq!(sequences, ["ATTCTTTTG", "GATCCTTTTGA"]);
+        assert!(fragments[0].left_end().is_none());
+        assert_eq!(fragments[0].right_end().unwrap().enzyme(), 1);
+        assert!(fragments[1].right_end().is_none());
+
+        let fragments = digest(&enzymes, &mut store, &handle, Topology::Circular);
+        let sequences: Vec<_> = fragments
+            .iter()
+            .map(|fragment| store.get(fragment.handle()).as_string())
+            .collect();
+        assert_eq!(sequences, ["GATCCTTTTG", "AATTCTTTTG"]);
+        assert_eq!(fragments[0].left_end().unwrap().enzyme(), 1);
+        assert_eq!(fragments[0].right_end().unwrap().enzyme(), 0);
+        assert_eq!(fragments[1].left_end().unwrap().enzyme(), 0);
+        assert_eq!(
+            fragments[1].left_end().unwrap().overhang(),
+            Overhang::FivePrime(4)
+        );
+        assert_eq!(fragments[1].right_end().unwrap().enzyme(), 1);
+
+        // An uncut circular sequence is returned whole.
+        let fragments = digest(
+            &[RestrictionEnzyme::from_name("NotI").unwrap()],
+            &mut store,
+            &handle,
+            Topology::Circular,
+        );
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(
+            store.get(fragments[0].handle()).as_string(),
+            "ATTCTTTTGGATCCTTTTGA"
+        );
+        assert!(fragments[0].left_end().is_none());
+    }
+}
@@ -0,0 +1,754 @@
+//! Sampling schemes that select a subset of the k-mers of a genome sequence, like minimizers and syncmers.
+//!
+//! All schemes rank k-mers or their substrings by a [KmerOrder].
+//! The orders operate on the rank of a k-mer in the lexicographic order of all sequences of the same length, where characters are ordered by their [canonical rank](crate::interface::alphabet::AlphabetCharacter::canonical_rank), see [Kmer::to_canonical_index](crate::interface::k_mer::Kmer::to_canonical_index).
+//! Each scheme has a forward-only variant, which works for any alphabet, and a canonical variant, which requires a [complementable alphabet](ComplementableAlphabet).
+//! If the sampling is canonical, then a k-mer and its reverse complement are ranked equally, such that the same k-mers are sampled from both strands, up to ties.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::bit_array_kmer::BitArrayKmer;
+//! use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+//! use compact_genome::interface::sequence::sampling::LexicographicOrder;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence, Strand};
+//!
+//! let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"TTGACTT").unwrap();
+//! let minimizers: Vec<_> = genome
+//!     .minimizer_iter::<3, BitArrayKmer<3, DnaAlphabet>, _>(3, LexicographicOrder)
+//!     .collect();
+//!
+//! // The windows select GAC, ACT and ACT.
+//! assert_eq!(minimizers.len(), 2);
+//! assert_eq!(minimizers[0].position(), 2);
+//! assert_eq!(minimizers[0].strand(), Strand::Forward);
+//! assert_eq!(minimizers[0].k_mer().as_string(), "GAC");
+//! assert_eq!(minimizers[1].k_mer().as_string(), "ACT");
+//! ```
+//!
+//! ## Limits
+//!
+//! The lexicographic index of the ranked k-mers must fit into a `u128`, i.e. `AlphabetType::SIZE` to the power of their length must be at most `2^128`.
+//! For example, they can have at most 64 characters over the [DNA alphabet](crate::implementation::alphabets::dna_alphabet::DnaAlphabet), and at most 55 characters over the [DNA alphabet including N](crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN).
+//! For minimizers, this is checked at compile time.
+//! Mod-minimizers and syncmers rank shorter substrings whose length is chosen at runtime, hence they check it at runtime.
+//!
+//! ```compile_fail
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::array_kmer::ArrayKmer;
+//! use compact_genome::implementation::vec_sequence::VectorGenome;
+//! use compact_genome::interface::sequence::sampling::LexicographicOrder;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//!
+//! let genome = VectorGenome::<DnaAlphabet>::from_slice_u8(&[b'A'; 100]).unwrap();
+//! genome
+//!     .minimizer_iter::<65, ArrayKmer<65, DnaAlphabet>, _>(10, LexicographicOrder)
+//!     .count();
+//! ```
+
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, ComplementableAlphabet, ComplementableAlphabetCharacter,
+};
+use crate::interface::k_mer::OwnedKmer;
+use crate::interface::sequence::{GenomeSequence, Strand};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// An order of k-mers, used to select k-mers in sampling schemes.
+/// K-mers with a smaller rank are preferred.
+pub trait KmerOrder {
+    /// Returns the rank of the k-mer of length `k` with the given [canonical index](crate::interface::k_mer::Kmer::to_canonical_index).
+    fn rank(&self, index: u128, k: usize) -> u128;
+}
+
+/// Orders k-mers lexicographically.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LexicographicOrder;
+
+impl KmerOrder for LexicographicOrder {
+    fn rank(&self, index: u128, _k: usize) -> u128 {
+        index
+    }
+}
+
+/// Orders k-mers pseudo-randomly by a hash of their lexicographic index.
+///
+/// Random orders avoid the bias of lexicographic orders towards k-mers with long runs of the smallest character.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomOrder {
+    seed: u64,
+}
+
+impl RandomOrder {
+    /// Creates a new random order, where different seeds result in different orders.
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl KmerOrder for RandomOrder {
+    fn rank(&self, index: u128, k: usize) -> u128 {
+        let low = mix(index as u64 ^ self.seed);
+        let high = mix((index >> 64) as u64 ^ low ^ k as u64);
+        (u128::from(high) << 64) | u128::from(low)
+    }
+}
+
+/// The finaliser of the SplitMix64 random number generator.
+pub(crate) fn mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9e3779b97f4a7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+    value ^ (value >> 31)
+}
+
+/// The kind of a syncmer.
+///
+/// A k-mer is a syncmer if its smallest s-mer is at a certain position.
+/// If multiple s-mers are smallest, then the leftmost one is used.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SyncmerKind {
+    /// The smallest s-mer starts at the given offset in the k-mer.
+    Open {
+        /// The offset of the smallest s-mer.
+        offset: usize,
+    },
+    /// The smallest s-mer is at the start or at the end of the k-mer.
+    Closed,
+}
+
+/// A k-mer selected by a sampling scheme.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SampledKmer<KmerType> {
+    position: usize,
+    strand: Strand,
+    k_mer: KmerType,
+}
+
+impl<KmerType> SampledKmer<KmerType> {
+    /// The offset of the k-mer in the sampled sequence.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The strand of the k-mer.
+    /// If the sampling is canonical, then this is the strand on which the k-mer is [canonical](crate::interface::sequence::GenomeSequence::is_canonical), otherwise it is always [Strand::Forward].
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// The k-mer, which is the reverse complement of the sampled sequence if the [strand](Self::strand) is [Strand::ReverseComplement].
+    pub fn k_mer(&self) -> &KmerType {
+        &self.k_mer
+    }
+
+    /// Returns the k-mer, see [k_mer](Self::k_mer).
+    pub fn into_k_mer(self) -> KmerType {
+        self.k_mer
+    }
+}
+
+/// Returns true if the lexicographic index of the sequences of the given length over an alphabet of the given size fits into a `u128`, see [limits](self#limits).
+pub(crate) const fn index_fits(alphabet_size: u8, len: usize) -> bool {
+    let alphabet_size = alphabet_size as u128;
+    // The largest index is `alphabet_size^len - 1 = highest_digit * (alphabet_size - 1) + highest_digit - 1`.
+    let mut highest_digit = 1u128;
+    let mut exponent = 1;
+    while exponent < len {
+        highest_digit = match highest_digit.checked_mul(alphabet_size) {
+            Some(highest_digit) => highest_digit,
+            None => return false,
+        };
+        exponent += 1;
+    }
+
+    match highest_digit.checked_mul(alphabet_size - 1) {
+        Some(maximum) => maximum.checked_add(highest_digit - 1).is_some(),
+        None => false,
+    }
+}
+
+/// Checks at compile time that the lexicographic index of k-mers fits into a `u128`, see [limits](self#limits).
+pub(crate) struct AssertIndexFits<const K: usize, AlphabetType>(PhantomData<AlphabetType>);
+
+impl<const K: usize, AlphabetType: Alphabet> AssertIndexFits<K, AlphabetType> {
+    pub(crate) const ASSERT: () = assert!(
+        index_fits(AlphabetType::SIZE, K),
+        "the lexicographic index of the k-mers does not fit into a u128"
+    );
+}
+
+/// Returns the canonical rank of the given character and of its complement, as required by [RollingRanks].
+pub(crate) fn character_digits<CharacterType: ComplementableAlphabetCharacter>(
+    character: &CharacterType,
+) -> (u8, u8) {
+    (
+        character.canonical_rank(),
+        character.complement().canonical_rank(),
+    )
+}
+
+/// Returns the canonical rank of the given character, as required by [RollingRanks] if it is not canonical.
+/// Since the reverse complement is not ranked, its digit is zero.
+pub(crate) fn forward_character_digits<CharacterType: AlphabetCharacter>(
+    character: &CharacterType,
+) -> (u8, u8) {
+    (character.canonical_rank(), 0)
+}
+
+/// Returns the sampled k-mer at the given position of the given sequence.
+pub(crate) fn sampled_k_mer<
+    const K: usize,
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+>(
+    sequence: &SequenceType,
+    position: usize,
+) -> SampledKmer<KmerType> {
+    SampledKmer {
+        position,
+        strand: Strand::Forward,
+        k_mer: sequence[position..position + K].iter().cloned().collect(),
+    }
+}
+
+/// Returns the sampled k-mer at the given position of the given sequence, on the strand on which it is canonical.
+pub(crate) fn canonical_sampled_k_mer<
+    const K: usize,
+    AlphabetType: ComplementableAlphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+>(
+    sequence: &SequenceType,
+    position: usize,
+) -> SampledKmer<KmerType> {
+    let k_mer = &sequence[position..position + K];
+    if k_mer.is_canonical() {
+        sampled_k_mer(sequence, position)
+    } else {
+        SampledKmer {
+            position,
+            strand: Strand::ReverseComplement,
+            k_mer: k_mer.reverse_complement_iter().collect(),
+        }
+    }
+}
+
+/// An iterator over the ranks of all substrings of a fixed length of a sequence.
+///
+/// The sequence is given as pairs of the canonical rank of a character and the canonical rank of its complement.
+/// If the ranks are not canonical, then the ranks of the complements are ignored.
+struct RollingRanks<Digits, Order> {
+    digits: Digits,
+    len: usize,
+    alphabet_size: u128,
+    /// The alphabet size to the power of `len - 1`.
+    highest_digit: u128,
+    forward: u128,
+    reverse: u128,
+    filled: usize,
+    order: Order,
+    canonical: bool,
+}
+
+impl<Digits: Iterator<Item = (u8, u8)>, Order: KmerOrder> RollingRanks<Digits, Order> {
+    fn new(digits: Digits, len: usize, alphabet_size: u8, order: Order, canonical: bool) -> Self {
+        assert!(len > 0, "cannot rank substrings of length zero");
+        assert!(
+            index_fits(alphabet_size, len),
+            "the lexicographic index of the k-mers does not fit into a u128"
+        );
+        let alphabet_size = u128::from(alphabet_size);
+        let highest_digit = alphabet_size.pow(len as u32 - 1);
+
+        Self {
+            digits,
+            len,
+            alphabet_size,
+            highest_digit,
+            forward: 0,
+            reverse: 0,
+            filled: 0,
+            order,
+            canonical,
+        }
+    }
+}
+
+impl<Digits: Iterator<Item = (u8, u8)>, Order: KmerOrder> Iterator for RollingRanks<Digits, Order> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (digit, complement_digit) = self.digits.next()?;
+            self.forward =
+                (self.forward % self.highest_digit) * self.alphabet_size + u128::from(digit);
+            self.reverse = self.reverse / self.alphabet_size
+                + u128::from(complement_digit) * self.highest_digit;
+
+            if self.filled + 1 < self.len {
+                self.filled += 1;
+                continue;
+            }
+
+            let index = if self.canonical {
+                self.forward.min(self.reverse)
+            } else {
+                self.forward
+            };
+            return Some(self.order.rank(index, self.len));
+        }
+    }
+}
+
+/// The minimum of a sliding window over ranks, where ties are broken by preferring the leftmost position.
+struct SlidingMinimum<Ranks> {
+    ranks: Ranks,
+    window_len: usize,
+    position: usize,
+    /// Candidates for the minimum with increasing ranks, as pairs of rank and position.
+    candidates: VecDeque<(u128, usize)>,
+}
+
+impl<Ranks: Iterator<Item = u128>> SlidingMinimum<Ranks> {
+    fn new(ranks: Ranks, window_len: usize) -> Self {
+        assert!(window_len > 0, "the window must not be empty");
+
+        Self {
+            ranks,
+            window_len,
+            position: 0,
+            candidates: VecDeque::new(),
+        }
+    }
+}
+
+impl<Ranks: Iterator<Item = u128>> Iterator for SlidingMinimum<Ranks> {
+    /// The start of the window and the position of its minimum.
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rank = self.ranks.next()?;
+            let position = self.position;
+            self.position += 1;
+
+            while self
+                .candidates
+                .back()
+                .is_some_and(|&(candidate_rank, _)| candidate_rank > rank)
+            {
+                self.candidates.pop_back();
+            }
+            self.candidates.push_back((rank, position));
+
+            if position + 1 < self.window_len {
+                continue;
+            }
+            let window_start = position + 1 - self.window_len;
+            while self.candidates.front().unwrap().1 < window_start {
+                self.candidates.pop_front();
+            }
+
+            return Some((window_start, self.candidates.front().unwrap().1));
+        }
+    }
+}
+
+/// Returns the positions of the minimizers among the k-mers of the given sequence, in increasing order.
+///
+/// Each window of `w` consecutive k-mers selects its smallest k-mer, and windows that select the same k-mer are reported once.
+pub(crate) fn minimizer_positions(
+    digits: impl Iterator<Item = (u8, u8)>,
+    alphabet_size: u8,
+    k: usize,
+    w: usize,
+    order: impl KmerOrder,
+    canonical: bool,
+) -> impl Iterator<Item = usize> {
+    let ranks = RollingRanks::new(digits, k, alphabet_size, order, canonical);
+    deduplicate(SlidingMinimum::new(ranks, w).map(|(_, minimum)| minimum))
+}
+
+/// Returns the positions of the mod-minimizers among the k-mers of the given sequence, in increasing order.
+///
+/// Mod-minimizers are described in Groot Koerkamp and Pibiri, "The mod-minimizer: a simple and efficient sampling algorithm for long k-mers", WABI, 2024.
+/// Each window of `w` consecutive k-mers selects the position of its smallest t-mer modulo `w`, where `t = r + (k - r) mod w`.
+pub(crate) fn mod_minimizer_positions(
+    digits: impl Iterator<Item = (u8, u8)>,
+    alphabet_size: u8,
+    k: usize,
+    w: usize,
+    r: usize,
+    order: impl KmerOrder,
+    canonical: bool,
+) -> impl Iterator<Item = usize> {
+    assert!(w > 0, "the window must not be empty");
+    let t = if k <= r { k } else { r + (k - r) % w };
+    let ranks = RollingRanks::new(digits, t, alphabet_size, order, canonical);
+    deduplicate(
+        SlidingMinimum::new(ranks, w + k - t)
+            .map(move |(window_start, minimum)| window_start + (minimum - window_start) % w),
+    )
+}
+
+/// Returns the positions of the syncmers among the k-mers of the given sequence, in increasing order.
+///
+/// Syncmers are described in Edgar, "Syncmers are more sensitive than minimizers for selecting conserved k-mers in biological sequences", PeerJ, 2021.
+pub(crate) fn syncmer_positions(
+    digits: impl Iterator<Item = (u8, u8)>,
+    alphabet_size: u8,
+    k: usize,
+    s: usize,
+    kind: SyncmerKind,
+    order: impl KmerOrder,
+    canonical: bool,
+) -> impl Iterator<Item = usize> {
+    assert!(
+        s > 0 && s <= k,
+        "the length of the s-mers must be between one and k"
+    );
+    let ranks = RollingRanks::new(digits, s, alphabet_size, order, canonical);
+    SlidingMinimum::new(ranks, k - s + 1).filter_map(move |(start, minimum)| {
+        let offset = minimum - start;
+        let is_syncmer = match kind {
+            SyncmerKind::Open {
+                offset: open_offset,
+            } => offset == open_offset,
+            SyncmerKind::Closed => offset == 0 || offset == k - s,
+        };
+        is_syncmer.then_some(start)
+    })
+}
+
+/// Removes consecutive duplicates from the given positions.
+fn deduplicate(positions: impl Iterator<Item = usize>) -> impl Iterator<Item = usize> {
+    let mut previous = None;
+    positions.filter(move |&position| previous.replace(position) != Some(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::iupac_amino_acid_alphabet::IupacAminoAcidAlphabet;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::interface::k_mer::Kmer;
+    use crate::interface::sequence::sampling::{
+        index_fits, KmerOrder, LexicographicOrder, RandomOrder, SyncmerKind,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence, Strand};
+    use crate::test_util::random_ascii;
+
+    fn random_genome(len: usize, seed: u64) -> BitVectorGenome<DnaAlphabet> {
+        BitVectorGenome::from_slice_u8(&random_ascii(len, b"ACGT", seed)).unwrap()
+    }
+
+    /// Ranks all k-mers of the genome directly.
+    fn ranks<const K: usize>(
+        genome: &BitVectorGenome<DnaAlphabet>,
+        order: &impl KmerOrder,
+        canonical: bool,
+    ) -> Vec<u128> {
+        genome
+            .cloned_k_mer_iter::<K, BitArrayKmer<K, DnaAlphabet>>()
+            .map(|k_mer| {
+                let mut index = k_mer.to_canonical_index();
+                if canonical {
+                    index = index.min(k_mer.clone_as_reverse_complement().to_canonical_index());
+                }
+                order.rank(index, K)
+            })
+            .collect()
+    }
+
+    /// Returns the position of the leftmost minimum.
+    fn argmin(ranks: &[u128]) -> usize {
+        (0..ranks.len()).min_by_key(|&index| ranks[index]).unwrap()
+    }
+
+    #[test]
+    fn test_minimizers() {
+        let genome = random_genome(500, 1);
+        let w = 8;
+
+        for canonical in [false, true] {
+            for order in [RandomOrder::new(0), RandomOrder::new(1)] {
+                let ranks = ranks::<11>(&genome, &order, canonical);
+                let mut expected: Vec<_> = ranks
+                    .windows(w)
+                    .enumerate()
+                    .map(|(start, window)| start + argmin(window))
+                    .collect();
+                expected.dedup();
+
+                let minimizers: Vec<_> = if canonical {
+                    genome
+                        .canonical_minimizer_iter::<11, BitArrayKmer<11, DnaAlphabet>, _>(w, order)
+                        .collect()
+                } else {
+                    genome
+                        .minimizer_iter::<11, BitArrayKmer<11, DnaAlphabet>, _>(w, order)
+                        .collect()
+                };
+                assert_eq!(
+                    minimizers
+                        .iter()
+                        .map(|minimizer| minimizer.position())
+                        .collect::<Vec<_>>(),
+                    expected
+                );
+
+                for minimizer in minimizers {
+                    let forward = &genome[minimizer.position()..minimizer.position() + 11];
+                    if canonical && !forward.is_canonical() {
+                        assert_eq!(minimizer.strand(), Strand::ReverseComplement);
+                        assert_eq!(
+                            minimizer.k_mer().clone_as_vec(),
+                            forward
+                                .convert_with_reverse_complement::<BitVectorGenome<_>, _>()
+                                .clone_as_vec()
+                        );
+                    } else {
+                        assert_eq!(minimizer.strand(), Strand::Forward);
+                        assert_eq!(minimizer.k_mer().clone_as_vec(), forward.clone_as_vec());
+                    }
+                }
+            }
+        }
+
+        // Sequences with fewer than w k-mers have no minimizers.
+        let genome = random_genome(10, 2);
+        assert_eq!(
+            genome
+                .canonical_minimizer_iter::<3, BitArrayKmer<3, DnaAlphabet>, _>(
+                    9,
+                    LexicographicOrder
+                )
+                .count(),
+            0
+        );
+        assert_eq!(
+            genome
+                .canonical_minimizer_iter::<3, BitArrayKmer<3, DnaAlphabet>, _>(
+                    8,
+                    LexicographicOrder
+                )
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_canonical_minimizers_are_strand_independent() {
+        let genome = random_genome(300, 3);
+        let reverse_complement: BitVectorGenome<DnaAlphabet> =
+            genome.convert_with_reverse_complement();
+        let order = RandomOrder::new(42);
+
+        let forward: Vec<_> = genome
+            .canonical_minimizer_iter::<15, BitArrayKmer<15, DnaAlphabet>, _>(10, order)
+            .map(|minimizer| minimizer.into_k_mer())
+            .collect();
+        let mut reverse: Vec<_> = reverse_complement
+            .canonical_minimizer_iter::<15, BitArrayKmer<15, DnaAlphabet>, _>(10, order)
+            .map(|minimizer| minimizer.into_k_mer())
+            .collect();
+        reverse.reverse();
+        assert_eq!(forward, reverse);
+    }
+
+    #[test]
+    fn test_canonical_rank_order() {
+        crate::impl_generic_alphabet!(
+            "DNA alphabet including N, with N ordered last",
+            NLastAlphabet,
+            NLastCharacter,
+            b"ACGNT",
+            b"TGCNA",
+            CANONICAL_ORDER = b"ACGTN",
+        );
+
+        let ascii = random_ascii(300, b"ACGTN", 6);
+        let genome = BitVectorGenome::<NLastAlphabet>::from_slice_u8(&ascii).unwrap();
+        let w = 4;
+
+        // The orders by index and by canonical rank differ in whether N is smaller than T.
+        let expected = |to_index: fn(&BitArrayKmer<3, NLastAlphabet>) -> u128| {
+            let ranks: Vec<_> = genome
+                .cloned_k_mer_iter::<3, BitArrayKmer<3, NLastAlphabet>>()
+                .map(|k_mer| to_index(&k_mer))
+                .collect();
+            let mut expected: Vec<_> = ranks
+                .windows(w)
+                .enumerate()
+                .map(|(start, window)| start + argmin(window))
+                .collect();
+            expected.dedup();
+            expected
+        };
+        let by_canonical_rank = expected(Kmer::to_canonical_index);
+        assert_ne!(by_canonical_rank, expected(Kmer::to_index));
+
+        let minimizers: Vec<_> = genome
+            .minimizer_iter::<3, BitArrayKmer<3, NLastAlphabet>, _>(w, LexicographicOrder)
+            .map(|minimizer| minimizer.position())
+            .collect();
+        assert_eq!(minimizers, by_canonical_rank);
+    }
+
+    #[test]
+    fn test_mod_minimizers() {
+        let genome = random_genome(500, 4);
+        let (w, r) = (5, 4);
+        // t = 4 + (21 - 4) mod 5 = 6
+        let t = 6;
+
+        let t_mers: Vec<_> = genome
+            .cloned_k_mer_iter::<6, BitArrayKmer<6, DnaAlphabet>>()
+            .map(|t_mer| RandomOrder::new(7).rank(t_mer.to_canonical_index(), t))
+            .collect();
+        let mut expected: Vec<_> = t_mers
+            .windows(w + 21 - t)
+            .enumerate()
+            .map(|(start, window)| start + argmin(window) % w)
+            .collect();
+        expected.dedup();
+        assert!(expected.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let mod_minimizers: Vec<_> = genome
+            .mod_minimizer_iter::<21, BitArrayKmer<21, DnaAlphabet>, _>(w, r, RandomOrder::new(7))
+            .map(|mod_minimizer| mod_minimizer.position())
+            .collect();
+        assert_eq!(mod_minimizers, expected);
+
+        // If k is at most r, then mod-minimizers are minimizers.
+        assert!(genome
+            .canonical_mod_minimizer_iter::<4, BitArrayKmer<4, DnaAlphabet>, _>(
+                w,
+                r,
+                LexicographicOrder
+            )
+            .eq(
+                genome.canonical_minimizer_iter::<4, BitArrayKmer<4, DnaAlphabet>, _>(
+                    w,
+                    LexicographicOrder
+                )
+            ));
+    }
+
+    #[test]
+    fn test_syncmers() {
+        let genome = random_genome(500, 5);
+        let s = 4;
+
+        for canonical in [false, true] {
+            let s_mers = ranks::<4>(&genome, &LexicographicOrder, canonical);
+            let minima: Vec<_> = s_mers.windows(12 - s + 1).map(argmin).collect();
+
+            let syncmers = |kind| -> Vec<_> {
+                if canonical {
+                    genome
+                        .canonical_syncmer_iter::<12, BitArrayKmer<12, DnaAlphabet>, _>(
+                            s,
+                            kind,
+                            LexicographicOrder,
+                        )
+                        .map(|syncmer| syncmer.position())
+                        .collect()
+                } else {
+                    genome
+                        .syncmer_iter::<12, BitArrayKmer<12, DnaAlphabet>, _>(
+                            s,
+                            kind,
+                            LexicographicOrder,
+                        )
+                        .map(|syncmer| syncmer.position())
+                        .collect()
+                }
+            };
+
+            let closed = syncmers(SyncmerKind::Closed);
+            let expected: Vec<_> = (0..minima.len())
+                .filter(|&position| minima[position] == 0 || minima[position] == 12 - s)
+                .collect();
+            assert!(!expected.is_empty());
+            assert_eq!(closed, expected);
+
+            let open = syncmers(SyncmerKind::Open { offset: 2 });
+            let expected: Vec<_> = (0..minima.len())
+                .filter(|&position| minima[position] == 2)
+                .collect();
+            assert!(!expected.is_empty());
+            assert_eq!(open, expected);
+        }
+    }
+
+    #[test]
+    fn test_non_complementable_alphabet() {
+        let genome =
+            BitVectorGenome::<IupacAminoAcidAlphabet>::from_slice_u8(b"MAGICWAVEHELLKIT").unwrap();
+        let ranks: Vec<_> = genome
+            .cloned_k_mer_iter::<3, BitArrayKmer<3, IupacAminoAcidAlphabet>>()
+            .map(|k_mer| k_mer.to_canonical_index())
+            .collect();
+        let mut expected: Vec<_> = ranks
+            .windows(4)
+            .enumerate()
+            .map(|(start, window)| start + argmin(window))
+            .collect();
+        expected.dedup();
+
+        let minimizers: Vec<_> = genome
+            .minimizer_iter::<3, BitArrayKmer<3, IupacAminoAcidAlphabet>, _>(4, LexicographicOrder)
+            .collect();
+        assert_eq!(
+            minimizers
+                .iter()
+                .map(|minimizer| minimizer.position())
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(minimizers
+            .iter()
+            .all(|minimizer| minimizer.strand() == Strand::Forward));
+
+        // If k is at most r, then mod-minimizers are minimizers.
+        assert!(genome
+            .mod_minimizer_iter::<3, BitArrayKmer<3, IupacAminoAcidAlphabet>, _>(
+                4,
+                3,
+                LexicographicOrder
+            )
+            .eq(minimizers));
+        let syncmers: Vec<_> = genome
+            .syncmer_iter::<5, BitArrayKmer<5, IupacAminoAcidAlphabet>, _>(
+                2,
+                SyncmerKind::Closed,
+                LexicographicOrder,
+            )
+            .collect();
+        assert!(!syncmers.is_empty());
+        for syncmer in syncmers {
+            assert_eq!(
+                syncmer.k_mer().as_string(),
+                genome[syncmer.position()..syncmer.position() + 5].as_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_index_fits() {
+        assert!(index_fits(4, 64));
+        assert!(!index_fits(4, 65));
+        assert!(index_fits(5, 55));
+        assert!(!index_fits(5, 56));
+        assert!(index_fits(2, 128));
+        assert!(!index_fits(2, 129));
+    }
+}
@@ -0,0 +1,165 @@
+//! Iterators for exact pattern search in genome sequences.
+
+use crate::interface::alphabet::Alphabet;
+use crate::interface::sequence::{GenomeSequence, Strand};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+pub mod approximate;
+
+/// Returns the range of offsets at which a pattern of length `pattern_len` may occur in a sequence of length `sequence_len`.
+fn candidate_offsets(sequence_len: usize, pattern_len: usize) -> Range<usize> {
+    0..(sequence_len + 1).saturating_sub(pattern_len)
+}
+
+/// An iterator over the offsets of all occurrences of a pattern in a genome sequence, in increasing order.
+///
+/// Occurrences may overlap.
+/// See [GenomeSequence::find_iter].
+pub struct FindIterator<
+    'a,
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+> {
+    sequence: &'a SequenceType,
+    pattern: &'a GenomeSubsequence,
+    offsets: Range<usize>,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+impl<
+        'a,
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    > FindIterator<'a, AlphabetType, SequenceType, GenomeSubsequence>
+{
+    pub(crate) fn new(sequence: &'a SequenceType, pattern: &'a GenomeSubsequence) -> Self {
+        Self {
+            sequence,
+            pattern,
+            offsets: candidate_offsets(sequence.len(), pattern.len()),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    > Iterator for FindIterator<'_, AlphabetType, SequenceType, GenomeSubsequence>
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (sequence, pattern) = (self.sequence, self.pattern);
+        self.offsets
+            .find(|&offset| sequence.matches_at(offset, pattern))
+    }
+}
+
+impl<
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    > DoubleEndedIterator for FindIterator<'_, AlphabetType, SequenceType, GenomeSubsequence>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (sequence, pattern) = (self.sequence, self.pattern);
+        self.offsets
+            .rfind(|&offset| sequence.matches_at(offset, pattern))
+    }
+}
+
+/// An iterator over the offsets of all occurrences of a pattern and its reverse complement in a genome sequence, in increasing order.
+///
+/// Each occurrence is reported together with the [Strand] it was found on.
+/// If the pattern and its reverse complement occur at the same offset, then the forward occurrence is reported first.
+/// See [GenomeSequence::find_iter_both_strands].
+pub struct BothStrandsFindIterator<
+    'a,
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    ReverseComplementPattern: Borrow<GenomeSubsequence>,
+> {
+    sequence: &'a SequenceType,
+    pattern: &'a GenomeSubsequence,
+    reverse_complement_pattern: ReverseComplementPattern,
+    offsets: Range<usize>,
+    pending_reverse_complement: Option<usize>,
+    phantom_data: PhantomData<AlphabetType>,
+}
+
+impl<
+        'a,
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        ReverseComplementPattern: Borrow<GenomeSubsequence>,
+    >
+    BothStrandsFindIterator<
+        'a,
+        AlphabetType,
+        SequenceType,
+        GenomeSubsequence,
+        ReverseComplementPattern,
+    >
+{
+    pub(crate) fn new(
+        sequence: &'a SequenceType,
+        pattern: &'a GenomeSubsequence,
+        reverse_complement_pattern: ReverseComplementPattern,
+    ) -> Self {
+        Self {
+            sequence,
+            pattern,
+            reverse_complement_pattern,
+            offsets: candidate_offsets(sequence.len(), pattern.len()),
+            pending_reverse_complement: None,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<
+        AlphabetType: Alphabet,
+        SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+        ReverseComplementPattern: Borrow<GenomeSubsequence>,
+    > Iterator
+    for BothStrandsFindIterator<
+        '_,
+        AlphabetType,
+        SequenceType,
+        GenomeSubsequence,
+        ReverseComplementPattern,
+    >
+{
+    type Item = (usize, Strand);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(offset) = self.pending_reverse_complement.take() {
+            return Some((offset, Strand::ReverseComplement));
+        }
+
+        for offset in self.offsets.by_ref() {
+            let reverse_complement = self
+                .sequence
+                .matches_at(offset, self.reverse_complement_pattern.borrow());
+            if self.sequence.matches_at(offset, self.pattern) {
+                if reverse_complement {
+                    self.pending_reverse_complement = Some(offset);
+                }
+                return Some((offset, Strand::Forward));
+            } else if reverse_complement {
+                return Some((offset, Strand::ReverseComplement));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,602 @@
+//! Approximate pattern search with bit-parallel algorithms.
+//!
+//! A pattern is preprocessed into an [ApproximatePattern], which stores for each character of the text alphabet a bit vector of the pattern positions it matches.
+//! Patterns of any length are supported, using multiple machine words per bit vector if required.
+//!
+//! Occurrences within a Hamming distance threshold are found with the Shift-And algorithm extended to mismatches by [Wu and Manber](https://doi.org/10.1145/135239.135244).
+//! Occurrences within an edit distance threshold are found with the bit-vector algorithm of [Myers](https://doi.org/10.1145/316542.316550), in its multi-word variant by [Hyyrö](https://doi.org/10.1007/3-540-44888-8_15).
+//!
+//! By default, a pattern character matches a text character if they are equal.
+//! A different matching predicate can be given, for example [iupac_nucleotide_matches] to search IUPAC primers in a DNA sequence.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::alphabets::dna_iupac_nucleic_acid_alphabet::DnaIupacNucleicAcidAlphabet;
+//! use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//! use compact_genome::interface::sequence::search::approximate::{iupac_nucleotide_matches, ApproximatePattern, HammingMatch};
+//!
+//! let text = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"TTACGTACGGTCC").unwrap();
+//! let primer = BitVectorGenome::<DnaIupacNucleicAcidAlphabet>::from_slice_u8(b"ACRTNA").unwrap();
+//! let pattern = ApproximatePattern::<DnaAlphabet>::with_predicate(
+//!     &primer,
+//!     iupac_nucleotide_matches::<DnaIupacNucleicAcidAlphabet, DnaAlphabet>,
+//! );
+//! let matches: Vec<_> = pattern.hamming_iter(&text, 1).collect();
+//! assert_eq!(matches, [HammingMatch { offset: 2, distance: 1 }]);
+//! ```
+
+use crate::interface::alphabet::{Alphabet, AlphabetCharacter};
+use crate::interface::sequence::GenomeSequence;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// An occurrence of a pattern within a Hamming distance threshold.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HammingMatch {
+    /// The offset of the first character of the occurrence.
+    pub offset: usize,
+    /// The amount of mismatches between the pattern and the occurrence.
+    pub distance: usize,
+}
+
+/// An alignment end of a pattern within an edit distance threshold.
+///
+/// An occurrence of the pattern usually causes multiple consecutive alignment ends to be reported.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct EditMatch {
+    /// The offset behind the last character of the alignment in the text.
+    pub end: usize,
+    /// The minimum edit distance between the pattern and any substring of the text that ends at `end`.
+    pub distance: usize,
+}
+
+/// A pattern preprocessed for approximate search in texts over the given alphabet.
+#[derive(Debug, Clone)]
+pub struct ApproximatePattern<TextAlphabet: Alphabet> {
+    /// For each text character, the bit vector of pattern positions that match it, split into `words` machine words.
+    peq: Vec<u64>,
+    len: usize,
+    words: usize,
+    phantom_data: PhantomData<TextAlphabet>,
+}
+
+impl<TextAlphabet: Alphabet> ApproximatePattern<TextAlphabet> {
+    /// Preprocesses the given pattern, such that pattern characters match text characters if they are equal.
+    pub fn new<
+        Pattern: GenomeSequence<TextAlphabet, PatternSubsequence> + ?Sized,
+        PatternSubsequence: GenomeSequence<TextAlphabet, PatternSubsequence> + ?Sized,
+    >(
+        pattern: &Pattern,
+    ) -> Self {
+        Self::with_predicate(pattern, |pattern_character, text_character| {
+            pattern_character == text_character
+        })
+    }
+
+    /// Preprocesses the given pattern, such that a pattern character matches a text character if the given predicate returns true for them.
+    pub fn with_predicate<
+        PatternAlphabet: Alphabet,
+        Pattern: GenomeSequence<PatternAlphabet, PatternSubsequence> + ?Sized,
+        PatternSubsequence: GenomeSequence<PatternAlphabet, PatternSubsequence> + ?Sized,
+    >(
+        pattern: &Pattern,
+        predicate: impl Fn(&PatternAlphabet::CharacterType, &TextAlphabet::CharacterType) -> bool,
+    ) -> Self {
+        let len = pattern.len();
+        let words = len.div_ceil(WORD_BITS);
+        let mut peq = vec![0; usize::from(TextAlphabet::SIZE) * words];
+
+        for text_character in TextAlphabet::iter() {
+            let peq = &mut peq[usize::from(text_character.index()) * words..][..words];
+            for (position, pattern_character) in pattern.iter().enumerate() {
+                if predicate(pattern_character, &text_character) {
+                    peq[position / WORD_BITS] |= 1 << (position % WORD_BITS);
+                }
+            }
+        }
+
+        Self {
+            peq,
+            len,
+            words,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// The length of the pattern.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the pattern is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn peq(&self, character: &TextAlphabet::CharacterType) -> &[u64] {
+        &self.peq[usize::from(character.index()) * self.words..][..self.words]
+    }
+
+    /// Returns an iterator over all occurrences of this pattern in the given text with at most `max_distance` mismatches, in increasing order of offset.
+    ///
+    /// Each occurrence is reported with its minimum distance.
+    pub fn hamming_iter<
+        'a,
+        Text: GenomeSequence<TextAlphabet, TextSubsequence> + ?Sized,
+        TextSubsequence: GenomeSequence<TextAlphabet, TextSubsequence> + ?Sized,
+    >(
+        &'a self,
+        text: &'a Text,
+        max_distance: usize,
+    ) -> HammingSearchIterator<'a, TextAlphabet, &'a Self, Text::Iterator<'a>> {
+        HammingSearchIterator::new(self, text.iter(), max_distance)
+    }
+
+    /// Returns an iterator over all alignment ends of this pattern in the given text with at most `max_distance` edits, in increasing order.
+    ///
+    /// Each alignment end is reported with the minimum edit distance of the pattern to a substring of the text ending there.
+    pub fn edit_iter<
+        'a,
+        Text: GenomeSequence<TextAlphabet, TextSubsequence> + ?Sized,
+        TextSubsequence: GenomeSequence<TextAlphabet, TextSubsequence> + ?Sized,
+    >(
+        &'a self,
+        text: &'a Text,
+        max_distance: usize,
+    ) -> EditSearchIterator<'a, TextAlphabet, &'a Self, Text::Iterator<'a>> {
+        EditSearchIterator::new(self, text.iter(), max_distance)
+    }
+}
+
+/// An iterator over the occurrences of a pattern within a Hamming distance threshold.
+///
+/// See [ApproximatePattern::hamming_iter].
+pub struct HammingSearchIterator<
+    'a,
+    TextAlphabet: Alphabet + 'a,
+    PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+    Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+> {
+    pattern: PatternType,
+    characters: Characters,
+    max_distance: usize,
+    /// The Shift-And state vectors for each amount of mismatches, each of `words` machine words.
+    states: Vec<u64>,
+    position: usize,
+    exhausted: bool,
+    phantom_data: PhantomData<TextAlphabet>,
+}
+
+impl<
+        'a,
+        TextAlphabet: Alphabet + 'a,
+        PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+        Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+    > HammingSearchIterator<'a, TextAlphabet, PatternType, Characters>
+{
+    pub(crate) fn new(pattern: PatternType, characters: Characters, max_distance: usize) -> Self {
+        let approximate_pattern = pattern.borrow();
+        // More mismatches than the pattern length cannot occur.
+        let max_distance = max_distance.min(approximate_pattern.len);
+        let states = vec![0; (max_distance + 1) * approximate_pattern.words];
+        Self {
+            pattern,
+            characters,
+            max_distance,
+            states,
+            position: 0,
+            exhausted: false,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<
+        'a,
+        TextAlphabet: Alphabet + 'a,
+        PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+        Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+    > Iterator for HammingSearchIterator<'a, TextAlphabet, PatternType, Characters>
+{
+    type Item = HammingMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pattern = self.pattern.borrow();
+        let words = pattern.words;
+
+        if pattern.is_empty() {
+            // The empty pattern occurs at each offset, including the offset behind the last character.
+            if self.exhausted {
+                return None;
+            }
+            let offset = self.position;
+            if self.characters.next().is_some() {
+                self.position += 1;
+            } else {
+                self.exhausted = true;
+            }
+            return Some(HammingMatch {
+                offset,
+                distance: 0,
+            });
+        }
+
+        for character in self.characters.by_ref() {
+            self.position += 1;
+            let peq = pattern.peq(character);
+
+            // Update from the highest amount of mismatches downwards, such that the state with one mismatch less is still the previous one.
+            for distance in (0..=self.max_distance).rev() {
+                let mut carry = 1;
+                let mut lower_carry = 1;
+                for (word, &eq) in peq.iter().enumerate() {
+                    let state = self.states[distance * words + word];
+                    let mut next = ((state << 1) | carry) & eq;
+                    carry = state >> (WORD_BITS - 1);
+                    if distance > 0 {
+                        let lower_state = self.states[(distance - 1) * words + word];
+                        next |= (lower_state << 1) | lower_carry;
+                        lower_carry = lower_state >> (WORD_BITS - 1);
+                    }
+                    self.states[distance * words + word] = next;
+                }
+            }
+
+            if self.position >= pattern.len {
+                let last_word = (pattern.len - 1) / WORD_BITS;
+                let last_bit = 1 << ((pattern.len - 1) % WORD_BITS);
+                if let Some(distance) = (0..=self.max_distance)
+                    .find(|distance| self.states[distance * words + last_word] & last_bit != 0)
+                {
+                    return Some(HammingMatch {
+                        offset: self.position - pattern.len,
+                        distance,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the alignment ends of a pattern within an edit distance threshold.
+///
+/// See [ApproximatePattern::edit_iter].
+pub struct EditSearchIterator<
+    'a,
+    TextAlphabet: Alphabet + 'a,
+    PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+    Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+> {
+    pattern: PatternType,
+    characters: Characters,
+    max_distance: usize,
+    /// The positive vertical deltas of the current column.
+    positive_vertical: Vec<u64>,
+    /// The negative vertical deltas of the current column.
+    negative_vertical: Vec<u64>,
+    /// The edit distance of the whole pattern at the current column.
+    score: usize,
+    /// The amount of text characters processed, or `None` if the first column was not reported yet.
+    position: Option<usize>,
+    phantom_data: PhantomData<TextAlphabet>,
+}
+
+impl<
+        'a,
+        TextAlphabet: Alphabet + 'a,
+        PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+        Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+    > EditSearchIterator<'a, TextAlphabet, PatternType, Characters>
+{
+    pub(crate) fn new(pattern: PatternType, characters: Characters, max_distance: usize) -> Self {
+        let approximate_pattern = pattern.borrow();
+        let words = approximate_pattern.words;
+        let score = approximate_pattern.len;
+        Self {
+            pattern,
+            characters,
+            max_distance,
+            positive_vertical: vec![u64::MAX; words],
+            negative_vertical: vec![0; words],
+            score,
+            position: None,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Advances one machine word of a column of the dynamic programming matrix by one text character.
+///
+/// Takes the horizontal delta entering the word at its lowest row and returns the horizontal delta leaving it at `high_bit`.
+fn advance_block(
+    positive_vertical: &mut u64,
+    negative_vertical: &mut u64,
+    mut eq: u64,
+    horizontal_in: i8,
+    high_bit: u64,
+) -> i8 {
+    let xv = eq | *negative_vertical;
+    if horizontal_in < 0 {
+        eq |= 1;
+    }
+    let xh = ((eq & *positive_vertical).wrapping_add(*positive_vertical) ^ *positive_vertical) | eq;
+    let mut positive_horizontal = *negative_vertical | !(xh | *positive_vertical);
+    let mut negative_horizontal = *positive_vertical & xh;
+
+    let horizontal_out = if positive_horizontal & high_bit != 0 {
+        1
+    } else if negative_horizontal & high_bit != 0 {
+        -1
+    } else {
+        0
+    };
+
+    positive_horizontal <<= 1;
+    negative_horizontal <<= 1;
+    match horizontal_in.cmp(&0) {
+        Ordering::Less => negative_horizontal |= 1,
+        Ordering::Greater => positive_horizontal |= 1,
+        Ordering::Equal => {}
+    }
+
+    *positive_vertical = negative_horizontal | !(xv | positive_horizontal);
+    *negative_vertical = positive_horizontal & xv;
+    horizontal_out
+}
+
+impl<
+        'a,
+        TextAlphabet: Alphabet + 'a,
+        PatternType: Borrow<ApproximatePattern<TextAlphabet>>,
+        Characters: Iterator<Item = &'a TextAlphabet::CharacterType>,
+    > Iterator for EditSearchIterator<'a, TextAlphabet, PatternType, Characters>
+{
+    type Item = EditMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(position) = &mut self.position else {
+            // The empty prefix of the text matches if the whole pattern may be deleted.
+            self.position = Some(0);
+            return if self.score <= self.max_distance {
+                Some(EditMatch {
+                    end: 0,
+                    distance: self.score,
+                })
+            } else {
+                self.next()
+            };
+        };
+        let pattern = self.pattern.borrow();
+
+        for character in self.characters.by_ref() {
+            *position += 1;
+            let peq = pattern.peq(character);
+
+            // The first row is zero everywhere, since the occurrence may start anywhere in the text.
+            let mut horizontal = 0;
+            for (word, &eq) in peq.iter().enumerate() {
+                let high_bit = if word + 1 == pattern.words {
+                    1 << ((pattern.len - 1) % WORD_BITS)
+                } else {
+                    1 << (WORD_BITS - 1)
+                };
+                horizontal = advance_block(
+                    &mut self.positive_vertical[word],
+                    &mut self.negative_vertical[word],
+                    eq,
+                    horizontal,
+                    high_bit,
+                );
+            }
+            self.score = self.score.wrapping_add_signed(horizontal.into());
+
+            if self.score <= self.max_distance {
+                return Some(EditMatch {
+                    end: *position,
+                    distance: self.score,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Bit masks of the nucleotides A, C, G and T/U represented by each IUPAC nucleotide code, indexed by ASCII.
+pub(crate) const IUPAC_NUCLEOTIDE_MASKS: [u8; 256] = {
+    let mut masks = [0; 256];
+    let codes: [(u8, u8); 16] = [
+        (b'A', 0b0001),
+        (b'C', 0b0010),
+        (b'G', 0b0100),
+        (b'T', 0b1000),
+        (b'U', 0b1000),
+        (b'R', 0b0101),
+        (b'Y', 0b1010),
+        (b'S', 0b0110),
+        (b'W', 0b1001),
+        (b'K', 0b1100),
+        (b'M', 0b0011),
+        (b'B', 0b1110),
+        (b'D', 0b1101),
+        (b'H', 0b1011),
+        (b'V', 0b0111),
+        (b'N', 0b1111),
+    ];
+
+    let mut index = 0;
+    while index < codes.len() {
+        let (ascii, mask) = codes[index];
+        masks[ascii as usize] = mask;
+        masks[ascii.to_ascii_lowercase() as usize] = mask;
+        index += 1;
+    }
+    masks
+};
+
+/// Returns true if the given characters, interpreted as IUPAC nucleotide codes, have a nucleotide in common.
+///
+/// For example, `R` (A or G) matches `A`, `G` and `N`, but not `C`.
+/// Characters that are no IUPAC nucleotide codes match nothing.
+pub fn iupac_nucleotide_matches<PatternAlphabet: Alphabet, TextAlphabet: Alphabet>(
+    pattern_character: &PatternAlphabet::CharacterType,
+    text_character: &TextAlphabet::CharacterType,
+) -> bool {
+    let pattern_ascii = PatternAlphabet::character_to_ascii(pattern_character.clone());
+    let text_ascii = TextAlphabet::character_to_ascii(text_character.clone());
+    IUPAC_NUCLEOTIDE_MASKS[usize::from(pattern_ascii)]
+        & IUPAC_NUCLEOTIDE_MASKS[usize::from(text_ascii)]
+        != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_iupac_nucleic_acid_alphabet::DnaIupacNucleicAcidAlphabet;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::implementation::vec_sequence::VectorGenome;
+    use crate::interface::sequence::search::approximate::{
+        iupac_nucleotide_matches, ApproximatePattern, EditMatch, HammingMatch,
+    };
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+    use crate::test_util::random_ascii;
+
+    fn naive_hamming(text: &[u8], pattern: &[u8], max_distance: usize) -> Vec<HammingMatch> {
+        (0..(text.len() + 1).saturating_sub(pattern.len()))
+            .map(|offset| HammingMatch {
+                offset,
+                distance: text[offset..offset + pattern.len()]
+                    .iter()
+                    .zip(pattern)
+                    .filter(|(a, b)| a != b)
+                    .count(),
+            })
+            .filter(|found| found.distance <= max_distance)
+            .collect()
+    }
+
+    fn naive_edit(text: &[u8], pattern: &[u8], max_distance: usize) -> Vec<EditMatch> {
+        let mut column: Vec<_> = (0..=pattern.len()).collect();
+        let mut result = Vec::new();
+        for end in 0..=text.len() {
+            if end > 0 {
+                let mut diagonal = column[0];
+                column[0] = 0;
+                for row in 1..=pattern.len() {
+                    let substitution = diagonal + usize::from(pattern[row - 1] != text[end - 1]);
+                    diagonal = column[row];
+                    column[row] = substitution.min(column[row] + 1).min(column[row - 1] + 1);
+                }
+            }
+            if column[pattern.len()] <= max_distance {
+                result.push(EditMatch {
+                    end,
+                    distance: column[pattern.len()],
+                });
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let text_ascii = random_ascii(500, b"ACGT", 3);
+        let text = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&text_ascii).unwrap();
+
+        for (start, len, max_distance) in [
+            (0, 1, 0),
+            (10, 5, 1),
+            (20, 12, 3),
+            (100, 64, 10),
+            (200, 65, 12),
+            (300, 150, 30),
+        ] {
+            // Modify the pattern a bit, such that it does not occur exactly.
+            let mut pattern_ascii = text_ascii[start..start + len].to_vec();
+            pattern_ascii[len / 2] = b'A';
+            pattern_ascii.remove(len / 3);
+            pattern_ascii.push(b'C');
+            let pattern = VectorGenome::<DnaAlphabet>::from_slice_u8(&pattern_ascii).unwrap();
+
+            assert_eq!(
+                text.find_iter_within_hamming_distance(&pattern, max_distance)
+                    .collect::<Vec<_>>(),
+                naive_hamming(&text_ascii, &pattern_ascii, max_distance)
+            );
+            assert_eq!(
+                text.find_iter_within_edit_distance(&pattern, max_distance)
+                    .collect::<Vec<_>>(),
+                naive_edit(&text_ascii, &pattern_ascii, max_distance)
+            );
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let text = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACG").unwrap();
+        let empty = BitVectorGenome::<DnaAlphabet>::default();
+        assert_eq!(
+            text.find_iter_within_hamming_distance(&empty, 0)
+                .map(|found| found.offset)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            text.find_iter_within_edit_distance(&empty, 0)
+                .map(|found| found.end)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+
+        let long = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTT").unwrap();
+        assert_eq!(
+            text.find_iter_within_hamming_distance(&long, 5).next(),
+            None
+        );
+        assert_eq!(
+            text.find_iter_within_edit_distance(&long, 2)
+                .collect::<Vec<_>>(),
+            vec![EditMatch {
+                end: 3,
+                distance: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_iupac() {
+        let text = VectorGenome::<DnaAlphabet>::from_slice_u8(b"GGACGTTAACTTAG").unwrap();
+        let primer = VectorGenome::<DnaIupacNucleicAcidAlphabet>::from_slice_u8(b"AYKTNA").unwrap();
+        let pattern = ApproximatePattern::<DnaAlphabet>::with_predicate(
+            &primer,
+            iupac_nucleotide_matches::<DnaIupacNucleicAcidAlphabet, DnaAlphabet>,
+        );
+        assert_eq!(
+            pattern.hamming_iter(&text, 0).collect::<Vec<_>>(),
+            vec![HammingMatch {
+                offset: 2,
+                distance: 0
+            }]
+        );
+        assert_eq!(
+            pattern
+                .hamming_iter(&text, 1)
+                .map(|found| found.offset)
+                .collect::<Vec<_>>(),
+            vec![2, 8]
+        );
+        assert_eq!(
+            pattern
+                .edit_iter(&text, 0)
+                .map(|found| found.end)
+                .collect::<Vec<_>>(),
+            vec![8]
+        );
+    }
+}
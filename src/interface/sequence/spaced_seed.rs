@@ -0,0 +1,404 @@
+//! Spaced seeds, which extract gapped k-mers from a genome sequence.
+//!
+//! A spaced seed is a pattern like `1101`, where `1` marks a care position and `0` marks a don't-care position.
+//! Applying a seed to a window of the genome of the length of the pattern, called its span, extracts the characters at the care positions into a k-mer, where k is the number of care positions, called the weight of the seed.
+//! Since mismatches at don't-care positions do not change the k-mer, spaced seeds find similar sequences more sensitively than contiguous k-mers, see Ma, Tromp and Li, "PatternHunter: faster and more sensitive homology search", Bioinformatics, 2002.
+//! Multiple seeds of the same weight can be applied at once to increase the sensitivity further.
+//!
+//! If the k-mers are canonical, then each window is compared to its reverse complement, where the seed is applied to the reverse complement of the window.
+//! This way, a window and its reverse complement extract the same k-mer, even if the seed is not symmetric.
+//!
+//! ```rust
+//! use compact_genome::implementation::alphabets::dna_alphabet::DnaAlphabet;
+//! use compact_genome::implementation::bit_array_kmer::BitArrayKmer;
+//! use compact_genome::implementation::bit_vec_sequence::BitVectorGenome;
+//! use compact_genome::interface::sequence::spaced_seed::SpacedSeed;
+//! use compact_genome::interface::sequence::{GenomeSequence, OwnedGenomeSequence};
+//!
+//! let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTT").unwrap();
+//! let seeds = ["1011".parse::<SpacedSeed>().unwrap(), SpacedSeed::contiguous(3)];
+//! let k_mers: Vec<_> = genome
+//!     .spaced_seed_iter::<3, BitArrayKmer<3, DnaAlphabet>>(&seeds)
+//!     .map(|k_mer| (k_mer.position(), k_mer.seed_index(), k_mer.k_mer().as_string()))
+//!     .collect();
+//! assert_eq!(
+//!     k_mers,
+//!     [
+//!         (0, 0, "AGT".to_string()),
+//!         (0, 1, "ACG".to_string()),
+//!         (1, 0, "CTT".to_string()),
+//!         (1, 1, "CGT".to_string()),
+//!         (2, 1, "GTT".to_string()),
+//!     ]
+//! );
+//! ```
+
+use crate::interface::alphabet::{
+    Alphabet, AlphabetCharacter, ComplementableAlphabet, ComplementableAlphabetCharacter,
+};
+use crate::interface::k_mer::OwnedKmer;
+use crate::interface::sequence::{GenomeSequence, Strand};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A spaced seed, i.e. a pattern of care and don't-care positions.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpacedSeed {
+    span: usize,
+    care_positions: Vec<usize>,
+}
+
+/// An error when parsing a spaced seed.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum SpacedSeedError {
+    #[error("found a character that is neither 1 nor 0 in a spaced seed: {character}")]
+    /// The pattern contains a character other than `1` and `0`.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+    },
+
+    #[error("the spaced seed has no care positions")]
+    /// The pattern contains no `1`.
+    NoCarePositions,
+}
+
+impl SpacedSeed {
+    /// Creates a spaced seed from a pattern of `1` for care positions and `0` for don't-care positions.
+    pub fn from_pattern(pattern: &str) -> Result<Self, SpacedSeedError> {
+        let mut care_positions = Vec::new();
+        let mut span = 0;
+        for (position, character) in pattern.chars().enumerate() {
+            match character {
+                '1' => care_positions.push(position),
+                '0' => {}
+                character => return Err(SpacedSeedError::InvalidCharacter { character }),
+            }
+            span += 1;
+        }
+
+        if care_positions.is_empty() {
+            Err(SpacedSeedError::NoCarePositions)
+        } else {
+            Ok(Self {
+                span,
+                care_positions,
+            })
+        }
+    }
+
+    /// Creates the seed of `k` care positions without don't-care positions, which extracts contiguous k-mers.
+    ///
+    /// Panics if `k` is zero.
+    pub fn contiguous(k: usize) -> Self {
+        assert!(k > 0, "a spaced seed needs at least one care position");
+        Self {
+            span: k,
+            care_positions: (0..k).collect(),
+        }
+    }
+
+    /// The length of the pattern.
+    pub fn span(&self) -> usize {
+        self.span
+    }
+
+    /// The number of care positions, i.e. the length of the extracted k-mers.
+    pub fn weight(&self) -> usize {
+        self.care_positions.len()
+    }
+
+    /// The care positions in ascending order.
+    pub fn care_positions(&self) -> &[usize] {
+        &self.care_positions
+    }
+
+    /// Returns the seed with the reversed pattern.
+    pub fn reversed(&self) -> Self {
+        Self {
+            span: self.span,
+            care_positions: self
+                .care_positions
+                .iter()
+                .rev()
+                .map(|position| self.span - 1 - position)
+                .collect(),
+        }
+    }
+
+    /// Returns true if the pattern is a palindrome.
+    /// Then a window and its reverse complement extract k-mers that are the reverse complement of each other.
+    pub fn is_symmetric(&self) -> bool {
+        *self == self.reversed()
+    }
+}
+
+impl FromStr for SpacedSeed {
+    type Err = SpacedSeedError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Self::from_pattern(pattern)
+    }
+}
+
+impl Display for SpacedSeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut care_positions = self.care_positions.iter().peekable();
+        for position in 0..self.span {
+            if care_positions.next_if_eq(&&position).is_some() {
+                write!(f, "1")?;
+            } else {
+                write!(f, "0")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A k-mer extracted by a spaced seed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SpacedKmer<KmerType> {
+    seed_index: usize,
+    position: usize,
+    strand: Strand,
+    k_mer: KmerType,
+}
+
+impl<KmerType> SpacedKmer<KmerType> {
+    /// The index of the seed that extracted the k-mer, in the given slice of seeds.
+    pub fn seed_index(&self) -> usize {
+        self.seed_index
+    }
+
+    /// The offset of the window of the seed in the sequence.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The strand of the k-mer.
+    /// If the k-mers are canonical, then this is the strand whose k-mer is smaller or equal, otherwise it is always [Strand::Forward].
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// The k-mer, which is extracted from the reverse complement of the window if the [strand](Self::strand) is [Strand::ReverseComplement].
+    pub fn k_mer(&self) -> &KmerType {
+        &self.k_mer
+    }
+
+    /// Returns the k-mer, see [k_mer](Self::k_mer).
+    pub fn into_k_mer(self) -> KmerType {
+        self.k_mer
+    }
+}
+
+/// Panics if any of the given seeds does not have weight `k`.
+pub(crate) fn assert_weights(seeds: &[SpacedSeed], k: usize) {
+    for seed in seeds {
+        assert_eq!(
+            seed.weight(),
+            k,
+            "the weight of the spaced seed {seed} differs from the k-mer length"
+        );
+    }
+}
+
+/// Returns the k-mer extracted by the given seed from the window at the given position of the given sequence.
+pub(crate) fn spaced_k_mer<
+    const K: usize,
+    AlphabetType: Alphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+>(
+    sequence: &SequenceType,
+    seed_index: usize,
+    seed: &SpacedSeed,
+    position: usize,
+) -> SpacedKmer<KmerType> {
+    SpacedKmer {
+        seed_index,
+        position,
+        strand: Strand::Forward,
+        k_mer: seed
+            .care_positions
+            .iter()
+            .map(|offset| sequence[position + offset].clone())
+            .collect(),
+    }
+}
+
+/// Returns the smaller of the k-mers extracted by the given seed from the window at the given position of the given sequence and from its reverse complement.
+pub(crate) fn canonical_spaced_k_mer<
+    const K: usize,
+    AlphabetType: ComplementableAlphabet,
+    SequenceType: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    GenomeSubsequence: GenomeSequence<AlphabetType, GenomeSubsequence> + ?Sized,
+    KmerType: OwnedKmer<K, AlphabetType, GenomeSubsequence>,
+>(
+    sequence: &SequenceType,
+    seed_index: usize,
+    seed: &SpacedSeed,
+    position: usize,
+) -> SpacedKmer<KmerType> {
+    let forward = || {
+        seed.care_positions
+            .iter()
+            .map(move |offset| sequence[position + offset].clone())
+    };
+    // Position `i` of the reverse complement of the window is the complement of position `span - 1 - i` of the window.
+    let reverse_complement = || {
+        seed.care_positions
+            .iter()
+            .map(move |offset| sequence[position + seed.span - 1 - offset].complement())
+    };
+
+    let strand = if forward()
+        .map(|character| character.canonical_rank())
+        .cmp(reverse_complement().map(|character| character.canonical_rank()))
+        .is_le()
+    {
+        Strand::Forward
+    } else {
+        Strand::ReverseComplement
+    };
+
+    SpacedKmer {
+        seed_index,
+        position,
+        strand,
+        k_mer: match strand {
+            Strand::Forward => forward().collect(),
+            Strand::ReverseComplement => reverse_complement().collect(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::implementation::alphabets::dna_alphabet::DnaAlphabet;
+    use crate::implementation::alphabets::dna_alphabet_or_n::DnaAlphabetOrN;
+    use crate::implementation::bit_array_kmer::BitArrayKmer;
+    use crate::implementation::bit_vec_sequence::BitVectorGenome;
+    use crate::interface::sequence::spaced_seed::{SpacedSeed, SpacedSeedError};
+    use crate::interface::sequence::{GenomeSequence, OwnedGenomeSequence, Strand};
+    use crate::test_util::random_ascii;
+
+    #[test]
+    fn test_parse() {
+        let seed: SpacedSeed = "1101001".parse().unwrap();
+        assert_eq!(seed.span(), 7);
+        assert_eq!(seed.weight(), 4);
+        assert_eq!(seed.care_positions(), &[0, 1, 3, 6]);
+        assert_eq!(seed.to_string(), "1101001");
+        assert_eq!(seed.reversed().to_string(), "1001011");
+        assert!(!seed.is_symmetric());
+        assert!("11011".parse::<SpacedSeed>().unwrap().is_symmetric());
+        assert_eq!(SpacedSeed::contiguous(3).to_string(), "111");
+
+        assert_eq!(
+            SpacedSeed::from_pattern("11*1"),
+            Err(SpacedSeedError::InvalidCharacter { character: '*' })
+        );
+        assert_eq!(
+            SpacedSeed::from_pattern("000"),
+            Err(SpacedSeedError::NoCarePositions)
+        );
+        assert_eq!(
+            SpacedSeed::from_pattern(""),
+            Err(SpacedSeedError::NoCarePositions)
+        );
+    }
+
+    #[test]
+    fn test_spaced_seed_iter() {
+        let ascii = random_ascii(200, b"ACGTN", 5);
+        let genome = BitVectorGenome::<DnaAlphabetOrN>::from_slice_u8(&ascii).unwrap();
+        let seeds = [
+            SpacedSeed::from_pattern("1101101100101").unwrap(),
+            SpacedSeed::from_pattern("1110010010111").unwrap(),
+            SpacedSeed::contiguous(8),
+        ];
+
+        let k_mers: Vec<_> = genome
+            .spaced_seed_iter::<8, BitArrayKmer<8, DnaAlphabetOrN>>(&seeds)
+            .collect();
+        assert_eq!(k_mers.len(), 2 * (200 - 12) + (200 - 7));
+        for k_mer in &k_mers {
+            let seed = &seeds[k_mer.seed_index()];
+            let expected: Vec<_> = seed
+                .care_positions()
+                .iter()
+                .map(|offset| ascii[k_mer.position() + offset])
+                .collect();
+            assert_eq!(k_mer.k_mer().clone_as_vec(), expected);
+            assert_eq!(k_mer.strand(), Strand::Forward);
+        }
+        assert!(k_mers
+            .windows(2)
+            .all(|pair| (pair[0].position(), pair[0].seed_index())
+                < (pair[1].position(), pair[1].seed_index())));
+
+        // The contiguous seed extracts the usual k-mers.
+        assert!(k_mers
+            .iter()
+            .filter(|k_mer| k_mer.seed_index() == 2)
+            .map(|k_mer| *k_mer.k_mer())
+            .eq(genome.cloned_k_mer_iter::<8, BitArrayKmer<8, DnaAlphabetOrN>>()));
+    }
+
+    #[test]
+    fn test_canonical_spaced_seed_iter() {
+        let ascii = random_ascii(300, b"ACGT", 7);
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(&ascii).unwrap();
+        let reverse_complement = genome.clone_as_reverse_complement();
+        let seeds = [
+            SpacedSeed::from_pattern("110100111").unwrap(),
+            SpacedSeed::from_pattern("10111011").unwrap(),
+        ];
+
+        let k_mers: Vec<_> = genome
+            .canonical_spaced_seed_iter::<6, BitArrayKmer<6, DnaAlphabet>>(&seeds)
+            .collect();
+        let reverse_k_mers: Vec<_> = reverse_complement
+            .canonical_spaced_seed_iter::<6, BitArrayKmer<6, DnaAlphabet>>(&seeds)
+            .collect();
+        assert_eq!(k_mers.len(), reverse_k_mers.len());
+
+        // A window and its reverse complement extract the same canonical k-mer on opposite strands.
+        for k_mer in &k_mers {
+            let span = seeds[k_mer.seed_index()].span();
+            let reverse_k_mer = reverse_k_mers
+                .iter()
+                .find(|reverse_k_mer| {
+                    reverse_k_mer.seed_index() == k_mer.seed_index()
+                        && reverse_k_mer.position() == ascii.len() - span - k_mer.position()
+                })
+                .unwrap();
+            assert_eq!(reverse_k_mer.k_mer(), k_mer.k_mer());
+
+            let forward = &genome
+                .spaced_seed_iter::<6, BitArrayKmer<6, DnaAlphabet>>(
+                    &seeds[k_mer.seed_index()..=k_mer.seed_index()],
+                )
+                .nth(k_mer.position())
+                .unwrap();
+            assert!(k_mer.k_mer() <= forward.k_mer());
+            if k_mer.strand() == Strand::Forward {
+                assert_eq!(k_mer.k_mer(), forward.k_mer());
+            } else {
+                assert_eq!(reverse_k_mer.strand(), Strand::Forward);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weight_mismatch() {
+        let genome = BitVectorGenome::<DnaAlphabet>::from_slice_u8(b"ACGTACGT").unwrap();
+        let seeds = [SpacedSeed::from_pattern("1101").unwrap()];
+        let _ = genome.spaced_seed_iter::<4, BitArrayKmer<4, DnaAlphabet>>(&seeds);
+    }
+}
@@ -7,3 +7,5 @@ pub mod implementation;
 pub mod interface;
 #[cfg(feature = "io")]
 pub mod io;
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,33 @@
+//! Helpers shared between the unit tests.
+
+/// A deterministic pseudo-random number generator, such that tests are reproducible.
+pub(crate) struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns a pseudo-random number below `bound`.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (self.state >> 33) as usize % bound
+    }
+
+    /// Returns a pseudo-random character of `alphabet`.
+    pub(crate) fn character(&mut self, alphabet: &[u8]) -> u8 {
+        alphabet[self.below(alphabet.len())]
+    }
+
+    /// Returns a pseudo-random ASCII sequence of length `len` over `alphabet`.
+    pub(crate) fn ascii(&mut self, len: usize, alphabet: &[u8]) -> Vec<u8> {
+        (0..len).map(|_| self.character(alphabet)).collect()
+    }
+}
+
+/// Returns a pseudo-random ASCII sequence of length `len` over `alphabet`, determined by `seed`.
+pub(crate) fn random_ascii(len: usize, alphabet: &[u8], seed: u64) -> Vec<u8> {
+    Random::new(seed).ascii(len, alphabet)
+}